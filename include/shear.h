@@ -0,0 +1,94 @@
+#ifndef SHEAR_H
+#define SHEAR_H
+
+/* this file is generated by cbindgen. do not edit it by hand. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * the ellipses available to the C api.
+ *
+ * see [`crate::str::ellipsis`] for more information.
+ */
+typedef enum ShearEllipsis {
+  /**
+   * an [`Ascii`][ellipsis::Ascii] ellipsis, `"..."`.
+   */
+  SHEAR_ELLIPSIS_ASCII = 0,
+  /**
+   * a [`Horizontal`][ellipsis::Horizontal] ellipsis, `"…"`.
+   */
+  SHEAR_ELLIPSIS_HORIZONTAL = 1,
+  /**
+   * a verbose [`Contd`][ellipsis::Contd] ellipsis, `"... (contd.)"`.
+   */
+  SHEAR_ELLIPSIS_CONTD = 2,
+} ShearEllipsis;
+
+/**
+ * the status returned by functions in the C api.
+ */
+typedef enum ShearStatus {
+  /**
+   * the string was limited and written to the output buffer.
+   */
+  SHEAR_STATUS_OK = 0,
+  /**
+   * a required pointer was null.
+   */
+  SHEAR_STATUS_NULL_POINTER = 1,
+  /**
+   * the input was not valid utf-8.
+   */
+  SHEAR_STATUS_INVALID_UTF8 = 2,
+  /**
+   * the output buffer was too small. the number of bytes needed is written instead.
+   */
+  SHEAR_STATUS_BUFFER_TOO_SMALL = 3,
+} ShearStatus;
+
+/**
+ * limits a utf-8 string by length, writing the result into `output`.
+ *
+ * the string is limited to `output_len` bytes. on success, the number of bytes written is
+ * stored in `written`. if `output` is too small to hold the ellipsis, the number of bytes needed
+ * is stored in `written` instead.
+ *
+ * see [`Limited::trim_to_length()`] for more information.
+ *
+ * # Safety
+ *
+ * `input` must point to `input_len` readable bytes, `output` must point to `output_len` writable
+ * bytes, and `written` must point to a writable `size_t`. the input and output must not overlap.
+ */
+ShearStatus shear_trim_length(const uint8_t *input,
+                              size_t input_len,
+                              ShearEllipsis ellipsis,
+                              uint8_t *output,
+                              size_t output_len,
+                              size_t *written);
+
+/**
+ * limits a utf-8 string by its visual width, writing the result into `output`.
+ *
+ * the string is limited to `width` columns. on success, the number of bytes written is stored in
+ * `written`. if `output` is too small to hold the limited string, the number of bytes needed is
+ * stored in `written` instead.
+ *
+ * see [`Limited::trim_to_width()`] for more information.
+ *
+ * # Safety
+ *
+ * `input` must point to `input_len` readable bytes, `output` must point to `output_len` writable
+ * bytes, and `written` must point to a writable `size_t`. the input and output must not overlap.
+ */
+ShearStatus shear_trim_width(const uint8_t *input,
+                             size_t input_len,
+                             size_t width,
+                             ShearEllipsis ellipsis,
+                             uint8_t *output,
+                             size_t output_len,
+                             size_t *written);
+
+#endif /* SHEAR_H */
@@ -0,0 +1,117 @@
+use {
+    crate::str::Ellipsis,
+    std::{borrow::Cow, ops::Deref},
+    tap::Pipe,
+};
+
+pub use self::hex::Hex;
+#[cfg(feature = "bytes")]
+pub use self::shared::{Preview, Shared};
+
+mod hex;
+#[cfg(feature = "bytes")]
+mod shared;
+
+/// a trait for "limiting" a byte slice.
+///
+/// this is the byte-oriented counterpart of [`str::Limited`][crate::str::Limited], for
+/// truncating binary payloads, e.g. in logs or previews of a wire format. byte slices are cut at
+/// any offset, without regard for character boundaries.
+///
+/// the [`Ellipsis`] is appended as a marker, as utf-8 bytes. a marker may also be given as bytes
+/// using [`trim_to_length_with()`][Limited::trim_to_length_with].
+///
+/// # examples
+///
+/// ```
+/// use shear::{bytes::Limited, str::ellipsis::Ascii};
+///
+/// let payload = b"\x00\x01\x02\x03\x04\x05\x06\x07";
+///
+/// assert_eq!(payload.trim_to_length::<Ascii>(6), b"\x00\x01\x02...");
+/// assert_eq!(payload.to_vec().trim_to_length::<Ascii>(8), payload);
+/// ```
+pub trait Limited {
+    /// returns bytes limited by length.
+    ///
+    /// if the bytes are longer than `length`, they are cut so that they, and the [`Ellipsis`],
+    /// are no longer than `length`. if the ellipsis is longer than `length` itself, it is cut
+    /// as well, so the returned bytes are never longer than `length`.
+    fn trim_to_length<E: Ellipsis>(&self, length: usize) -> Vec<u8>;
+
+    /// returns bytes limited by length, borrowing them if they fit.
+    ///
+    /// see [`trim_to_length()`][Limited::trim_to_length] for more information.
+    fn trim_to_length_cow<E: Ellipsis>(&self, length: usize) -> Cow<'_, [u8]>;
+
+    /// returns bytes limited by length, using `marker` to indicate truncation.
+    ///
+    /// ```
+    /// use shear::bytes::Limited;
+    ///
+    /// let frame: &[u8] = &[0xde, 0xad, 0xbe, 0xef, 0xca, 0xfe];
+    ///
+    /// assert_eq!(frame.trim_to_length_with(&[0xff], 4), [0xde, 0xad, 0xbe, 0xff]);
+    /// ```
+    fn trim_to_length_with(&self, marker: &[u8], length: usize) -> Vec<u8>;
+
+    /// returns a [`Hex`] preview of the first `length` bytes, for use in e.g. debugging output.
+    ///
+    /// ```
+    /// use shear::{bytes::Limited, str::ellipsis::Horizontal};
+    ///
+    /// let blob = vec![0xca_u8; 4100];
+    ///
+    /// assert_eq!(blob.display_hex::<Horizontal>(4).to_string(), "ca ca ca ca … (+4096 bytes)");
+    /// ```
+    fn display_hex<E: Ellipsis>(&self, length: usize) -> Hex<'_, E>;
+}
+
+impl<S> Limited for S
+where
+    S: Deref,
+    S::Target: AsRef<[u8]>,
+{
+    fn trim_to_length<E: Ellipsis>(&self, length: usize) -> Vec<u8> {
+        self.trim_to_length_cow::<E>(length).into_owned()
+    }
+
+    fn trim_to_length_cow<E: Ellipsis>(&self, length: usize) -> Cow<'_, [u8]> {
+        let value: &'_ [u8] = self.deref().as_ref();
+
+        if value.len() <= length {
+            return Cow::Borrowed(value);
+        }
+
+        let trailing = [E::ellipsis(), E::trailing()].concat();
+        limit(value, E::leading().as_bytes(), trailing.as_bytes(), length).pipe(Cow::Owned)
+    }
+
+    fn trim_to_length_with(&self, marker: &[u8], length: usize) -> Vec<u8> {
+        let value: &'_ [u8] = self.deref().as_ref();
+
+        match value.len() <= length {
+            true => value.to_vec(),
+            false => limit(value, &[], marker, length),
+        }
+    }
+
+    fn display_hex<E: Ellipsis>(&self, length: usize) -> Hex<'_, E> {
+        Hex::new(self.deref().as_ref(), length)
+    }
+}
+
+/// limits `value` to `length` bytes, wrapping the kept prefix in `leading` and `trailing` bytes.
+///
+/// the markers are cut as well, if they are longer than `length` themselves.
+fn limit(value: &[u8], leading: &[u8], trailing: &[u8], length: usize) -> Vec<u8> {
+    let kept = length.saturating_sub(leading.len() + trailing.len());
+
+    leading
+        .iter()
+        .chain(&value[..kept])
+        .chain(trailing)
+        .take(length)
+        .copied()
+        .collect()
+}
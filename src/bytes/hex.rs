@@ -0,0 +1,111 @@
+use {
+    crate::str::Ellipsis,
+    std::{fmt, marker::PhantomData},
+};
+
+/// a preview of a byte slice, rendered as hex when it is displayed.
+///
+/// the first `length` bytes are rendered as pairs of lowercase hex digits, separated by spaces.
+/// if the slice is longer than `length`, the [`Ellipsis`] follows the preview, along with the
+/// number of bytes that were omitted, e.g. `"… (+4096 bytes)"`.
+///
+/// an ascii gutter can be added using [`Hex::ascii()`]. this renders printable ascii bytes as
+/// themselves, and other bytes as `.`, between a pair of `|` characters.
+///
+/// see [`Limited::display_hex()`][super::Limited::display_hex] for more information.
+///
+/// # examples
+///
+/// ```
+/// use shear::{bytes::Hex, str::ellipsis::Horizontal};
+///
+/// let frame = b"GET / HTTP/1.1\r\n";
+///
+/// let preview = Hex::<Horizontal>::new(frame, 4);
+/// assert_eq!(preview.to_string(), "47 45 54 20 … (+12 bytes)");
+///
+/// let preview = Hex::<Horizontal>::new(frame, 4).ascii();
+/// assert_eq!(preview.to_string(), "47 45 54 20 |GET | … (+12 bytes)");
+/// ```
+pub struct Hex<'a, E> {
+    /// the bytes to display.
+    value: &'a [u8],
+    /// the maximum number of bytes to display.
+    length: usize,
+    /// whether an ascii gutter follows the hex digits.
+    ascii: bool,
+    ellipsis: PhantomData<E>,
+}
+
+// === impl hex ===
+
+impl<'a, E> Hex<'a, E> {
+    /// returns a new [`Hex`] preview of the first `length` bytes of `value`.
+    pub fn new(value: &'a [u8], length: usize) -> Self {
+        Self {
+            value,
+            length,
+            ascii: false,
+            ellipsis: PhantomData,
+        }
+    }
+
+    /// adds an ascii gutter after the hex digits.
+    pub fn ascii(self) -> Self {
+        Self {
+            ascii: true,
+            ..self
+        }
+    }
+}
+
+impl<E: Ellipsis> fmt::Display for Hex<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            value,
+            length,
+            ascii,
+            ..
+        } = *self;
+
+        let (shown, omitted) = value.split_at(length.min(value.len()));
+
+        for (i, byte) in shown.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{byte:02x}")?;
+        }
+
+        if ascii && !shown.is_empty() {
+            f.write_str(" |")?;
+            for &byte in shown {
+                let c = match byte.is_ascii_graphic() || byte == b' ' {
+                    true => byte as char,
+                    false => '.',
+                };
+                write!(f, "{c}")?;
+            }
+            f.write_str("|")?;
+        }
+
+        if !omitted.is_empty() {
+            if !shown.is_empty() {
+                f.write_str(" ")?;
+            }
+            let (count, noun) = (
+                omitted.len(),
+                if omitted.len() == 1 { "byte" } else { "bytes" },
+            );
+            write!(f, "{} (+{count} {noun})", E::ellipsis())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<E: Ellipsis> fmt::Debug for Hex<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_string(), f)
+    }
+}
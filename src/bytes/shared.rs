@@ -0,0 +1,74 @@
+use {
+    super::limit,
+    crate::str::Ellipsis,
+    bytes::{buf::Chain, Buf, Bytes, BytesMut},
+};
+
+/// a limited [`Bytes`] buffer, preceded and followed by the parts of an [`Ellipsis`].
+///
+/// see [`Shared`] for more information.
+pub type Preview = Chain<Chain<Bytes, Bytes>, Bytes>;
+
+/// a trait for "limiting" shared byte buffers, without copying their contents.
+///
+/// the retained prefix of the buffer is sliced, sharing the buffer's underlying storage, and is
+/// chained together with the [`Ellipsis`]. the [`Preview`] can be written using the [`Buf`]
+/// trait, e.g. to a socket, or collected into contiguous bytes using
+/// [`Buf::copy_to_bytes()`].
+///
+/// if the ellipsis is longer than `length` itself, the buffer is copied, as if by
+/// [`Limited::trim_to_length()`][super::Limited::trim_to_length].
+///
+/// # examples
+///
+/// ```
+/// use {
+///     bytes::{Buf, Bytes},
+///     shear::{bytes::Shared, str::ellipsis::Ascii},
+/// };
+///
+/// let payload = Bytes::from_static(b"a large payload from the network");
+///
+/// let mut preview = payload.trim_to_length_shared::<Ascii>(10);
+/// assert_eq!(preview.first_ref().last_ref(), "a large".as_bytes());
+/// assert_eq!(preview.copy_to_bytes(10), "a large...".as_bytes());
+/// ```
+pub trait Shared: Sized {
+    /// returns a preview of the buffer, limited by length.
+    fn trim_to_length_shared<E: Ellipsis>(self, length: usize) -> Preview;
+}
+
+// === impl bytes ===
+
+impl Shared for Bytes {
+    fn trim_to_length_shared<E: Ellipsis>(mut self, length: usize) -> Preview {
+        if self.len() <= length {
+            return Bytes::new().chain(self).chain(Bytes::new());
+        }
+
+        let leading = Bytes::from_static(E::leading().as_bytes());
+        let trailing = match E::trailing() {
+            "" => Bytes::from_static(E::ellipsis().as_bytes()),
+            trailing => Bytes::from([E::ellipsis(), trailing].concat()),
+        };
+
+        match length.checked_sub(leading.len() + trailing.len()) {
+            Some(kept) => {
+                self.truncate(kept);
+                leading.chain(self).chain(trailing)
+            }
+            None => {
+                let limited = limit(&self, &leading, &trailing, length);
+                Bytes::new().chain(Bytes::from(limited)).chain(Bytes::new())
+            }
+        }
+    }
+}
+
+// === impl bytesmut ===
+
+impl Shared for BytesMut {
+    fn trim_to_length_shared<E: Ellipsis>(self, length: usize) -> Preview {
+        self.freeze().trim_to_length_shared::<E>(length)
+    }
+}
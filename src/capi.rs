@@ -0,0 +1,145 @@
+use {
+    crate::str::{ellipsis, BufferTooSmall, Ellipsis, Limited},
+    std::slice,
+};
+
+/// the status returned by functions in the C api.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ShearStatus {
+    /// the string was limited and written to the output buffer.
+    Ok = 0,
+    /// a required pointer was null.
+    NullPointer = 1,
+    /// the input was not valid utf-8.
+    InvalidUtf8 = 2,
+    /// the output buffer was too small. the number of bytes needed is written instead.
+    BufferTooSmall = 3,
+}
+
+/// the ellipses available to the C api.
+///
+/// see [`crate::str::ellipsis`] for more information.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ShearEllipsis {
+    /// an [`Ascii`][ellipsis::Ascii] ellipsis, `"..."`.
+    Ascii = 0,
+    /// a [`Horizontal`][ellipsis::Horizontal] ellipsis, `"…"`.
+    Horizontal = 1,
+    /// a verbose [`Contd`][ellipsis::Contd] ellipsis, `"... (contd.)"`.
+    Contd = 2,
+}
+
+/// limits a utf-8 string by length, writing the result into `output`.
+///
+/// the string is limited to `output_len` bytes. on success, the number of bytes written is
+/// stored in `written`. if `output` is too small to hold the ellipsis, the number of bytes needed
+/// is stored in `written` instead.
+///
+/// see [`Limited::trim_to_length()`] for more information.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes, `output` must point to `output_len` writable
+/// bytes, and `written` must point to a writable `size_t`. the input and output must not overlap.
+#[no_mangle]
+pub unsafe extern "C" fn shear_trim_length(
+    input: *const u8,
+    input_len: usize,
+    ellipsis: ShearEllipsis,
+    output: *mut u8,
+    output_len: usize,
+    written: *mut usize,
+) -> ShearStatus {
+    if input.is_null() || output.is_null() || written.is_null() {
+        return ShearStatus::NullPointer;
+    }
+
+    // SAFETY: the caller guarantees that the pointers are valid for the given lengths.
+    let (input, output) = unsafe {
+        (
+            slice::from_raw_parts(input, input_len),
+            slice::from_raw_parts_mut(output, output_len),
+        )
+    };
+    let Ok(value) = std::str::from_utf8(input) else {
+        return ShearStatus::InvalidUtf8;
+    };
+
+    let result = match ellipsis {
+        ShearEllipsis::Ascii => value.trim_to_length_into::<ellipsis::Ascii>(output),
+        ShearEllipsis::Horizontal => value.trim_to_length_into::<ellipsis::Horizontal>(output),
+        ShearEllipsis::Contd => value.trim_to_length_into::<ellipsis::Contd>(output),
+    };
+
+    // SAFETY: the caller guarantees that `written` is valid for writes.
+    match result {
+        Ok(n) => {
+            unsafe { written.write(n) };
+            ShearStatus::Ok
+        }
+        Err(BufferTooSmall { needed }) => {
+            unsafe { written.write(needed) };
+            ShearStatus::BufferTooSmall
+        }
+    }
+}
+
+/// limits a utf-8 string by its visual width, writing the result into `output`.
+///
+/// the string is limited to `width` columns. on success, the number of bytes written is stored in
+/// `written`. if `output` is too small to hold the limited string, the number of bytes needed is
+/// stored in `written` instead.
+///
+/// see [`Limited::trim_to_width()`] for more information.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes, `output` must point to `output_len` writable
+/// bytes, and `written` must point to a writable `size_t`. the input and output must not overlap.
+#[no_mangle]
+pub unsafe extern "C" fn shear_trim_width(
+    input: *const u8,
+    input_len: usize,
+    width: usize,
+    ellipsis: ShearEllipsis,
+    output: *mut u8,
+    output_len: usize,
+    written: *mut usize,
+) -> ShearStatus {
+    if input.is_null() || output.is_null() || written.is_null() {
+        return ShearStatus::NullPointer;
+    }
+
+    // SAFETY: the caller guarantees that the pointers are valid for the given lengths.
+    let (input, output) = unsafe {
+        (
+            slice::from_raw_parts(input, input_len),
+            slice::from_raw_parts_mut(output, output_len),
+        )
+    };
+    let Ok(value) = std::str::from_utf8(input) else {
+        return ShearStatus::InvalidUtf8;
+    };
+
+    /// helper fn: limits the value using the given ellipsis.
+    fn trim<E: Ellipsis>(value: &str, width: usize) -> String {
+        value.trim_to_width::<E>(width)
+    }
+    let limited = match ellipsis {
+        ShearEllipsis::Ascii => trim::<ellipsis::Ascii>(value, width),
+        ShearEllipsis::Horizontal => trim::<ellipsis::Horizontal>(value, width),
+        ShearEllipsis::Contd => trim::<ellipsis::Contd>(value, width),
+    };
+
+    // SAFETY: the caller guarantees that `written` is valid for writes.
+    unsafe { written.write(limited.len()) };
+    match output.get_mut(..limited.len()) {
+        Some(output) => {
+            output.copy_from_slice(limited.as_bytes());
+            ShearStatus::Ok
+        }
+        None => ShearStatus::BufferTooSmall,
+    }
+}
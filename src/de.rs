@@ -0,0 +1,391 @@
+use {
+    crate::str::{Ellipsis, Limited},
+    serde::de::{self, DeserializeSeed},
+    std::{borrow::Cow, fmt, marker::PhantomData},
+};
+
+/// a [`serde`] deserializer that limits the length of every string it decodes.
+///
+/// this wraps another [`Deserializer`][de::Deserializer], trimming each string value to at most
+/// `length` bytes, including the [`Ellipsis`] used to indicate that it was trimmed. strings
+/// nested inside of sequences, maps, options, and enums are limited as well.
+///
+/// identifiers, such as the names of struct fields and enum variants, are left untouched so that
+/// they can still be matched.
+///
+/// strings borrowed from the input, or passed to the visitor as a temporary `&str`, are trimmed
+/// before they are copied into an owned value. formats that can only produce owned [`String`]s
+/// will have allocated the full string before it reaches this deserializer.
+///
+/// # examples
+///
+/// ```
+/// use shear::{de::Deserializer, str::ellipsis::Ascii};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Comment {
+///     author: String,
+///     body: String,
+/// }
+///
+/// let json = r#"{ "author": "katelyn", "body": "a very long comment, indeed" }"#;
+/// let mut json = serde_json::Deserializer::from_str(json);
+/// let comment = Comment::deserialize(Deserializer::<_, Ascii>::new(&mut json, 12)).unwrap();
+///
+/// assert_eq!(comment.author, "katelyn");
+/// assert_eq!(comment.body, "a very lo...");
+/// ```
+pub struct Deserializer<D, E> {
+    /// the inner deserializer.
+    inner: D,
+    /// the maximum length of a string, in bytes.
+    length: usize,
+    ellipsis: PhantomData<E>,
+}
+
+/// a wrapper around the [`serde`] machinery driven by a [`Deserializer`].
+///
+/// this is a visitor, seed, or access type, which limits the strings that pass through it.
+struct Wrapped<T, E> {
+    /// the wrapped visitor, seed, or access type.
+    inner: T,
+    /// the maximum length of a string, in bytes.
+    length: usize,
+    ellipsis: PhantomData<E>,
+}
+
+// === impl deserializer ===
+
+impl<D, E> Deserializer<D, E> {
+    /// returns a new [`Deserializer`], limiting strings to `length` bytes.
+    pub fn new(inner: D, length: usize) -> Self {
+        Self {
+            inner,
+            length,
+            ellipsis: PhantomData,
+        }
+    }
+
+    /// returns the inner deserializer.
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+
+    /// wraps a visitor, limiting the strings it is given.
+    fn wrap<T>(&self, inner: T) -> Wrapped<T, E> {
+        Wrapped::new(inner, self.length)
+    }
+}
+
+/// forwards deserializer methods to the inner deserializer, wrapping the visitor.
+macro_rules! forward {
+    ($($method:ident($($arg:ident: $ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method<V: de::Visitor<'de>>(
+                self,
+                $($arg: $ty,)*
+                visitor: V,
+            ) -> Result<V::Value, Self::Error> {
+                let visitor = self.wrap(visitor);
+                self.inner.$method($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+impl<'de, D, E> de::Deserializer<'de> for Deserializer<D, E>
+where
+    D: de::Deserializer<'de>,
+    E: Ellipsis,
+{
+    type Error = D::Error;
+
+    forward! {
+        deserialize_any(),
+        deserialize_bool(),
+        deserialize_i8(),
+        deserialize_i16(),
+        deserialize_i32(),
+        deserialize_i64(),
+        deserialize_i128(),
+        deserialize_u8(),
+        deserialize_u16(),
+        deserialize_u32(),
+        deserialize_u64(),
+        deserialize_u128(),
+        deserialize_f32(),
+        deserialize_f64(),
+        deserialize_char(),
+        deserialize_str(),
+        deserialize_string(),
+        deserialize_bytes(),
+        deserialize_byte_buf(),
+        deserialize_option(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str),
+        deserialize_seq(),
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(),
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+    }
+
+    fn deserialize_identifier<V: de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        // identifiers are matched against field and variant names, so they are not limited.
+        self.inner.deserialize_identifier(visitor)
+    }
+
+    fn deserialize_ignored_any<V: de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.inner.deserialize_ignored_any(visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+// === impl wrapped ===
+
+impl<T, E> Wrapped<T, E> {
+    /// returns a new [`Wrapped`] value, limiting strings to `length` bytes.
+    fn new(inner: T, length: usize) -> Self {
+        Self {
+            inner,
+            length,
+            ellipsis: PhantomData,
+        }
+    }
+
+    /// wraps another value, using the same limit.
+    fn wrap<U>(&self, inner: U) -> Wrapped<U, E> {
+        Wrapped::new(inner, self.length)
+    }
+
+    /// wraps a deserializer, using the same limit.
+    fn deserializer<D>(&self, inner: D) -> Deserializer<D, E> {
+        Deserializer::new(inner, self.length)
+    }
+}
+
+/// forwards visitor methods to the inner visitor.
+macro_rules! visit {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<Err: de::Error>(self, v: $ty) -> Result<Self::Value, Err> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V, E> de::Visitor<'de> for Wrapped<V, E>
+where
+    V: de::Visitor<'de>,
+    E: Ellipsis,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    visit! {
+        visit_bool(bool),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f32(f32),
+        visit_f64(f64),
+        visit_char(char),
+        visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]),
+        visit_byte_buf(Vec<u8>),
+    }
+
+    fn visit_str<Err: de::Error>(self, v: &str) -> Result<Self::Value, Err> {
+        match v.trim_to_length_cow::<E>(self.length) {
+            Cow::Borrowed(v) => self.inner.visit_str(v),
+            Cow::Owned(v) => self.inner.visit_string(v),
+        }
+    }
+
+    fn visit_borrowed_str<Err: de::Error>(self, v: &'de str) -> Result<Self::Value, Err> {
+        match v.len() <= self.length {
+            true => self.inner.visit_borrowed_str(v),
+            false => self.inner.visit_string(v.trim_to_length::<E>(self.length)),
+        }
+    }
+
+    fn visit_string<Err: de::Error>(self, v: String) -> Result<Self::Value, Err> {
+        match v.len() <= self.length {
+            true => self.inner.visit_string(v),
+            false => self.inner.visit_string(v.trim_to_length::<E>(self.length)),
+        }
+    }
+
+    fn visit_none<Err: de::Error>(self) -> Result<Self::Value, Err> {
+        self.inner.visit_none()
+    }
+
+    fn visit_some<D: de::Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+        let d = self.deserializer(d);
+        self.inner.visit_some(d)
+    }
+
+    fn visit_unit<Err: de::Error>(self) -> Result<Self::Value, Err> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_newtype_struct<D: de::Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+        let d = self.deserializer(d);
+        self.inner.visit_newtype_struct(d)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        let seq = self.wrap(seq);
+        self.inner.visit_seq(seq)
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let map = self.wrap(map);
+        self.inner.visit_map(map)
+    }
+
+    fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let data = self.wrap(data);
+        self.inner.visit_enum(data)
+    }
+}
+
+impl<'de, T, E> DeserializeSeed<'de> for Wrapped<T, E>
+where
+    T: DeserializeSeed<'de>,
+    E: Ellipsis,
+{
+    type Value = T::Value;
+
+    fn deserialize<D: de::Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+        let d = self.deserializer(d);
+        self.inner.deserialize(d)
+    }
+}
+
+impl<'de, A, E> de::SeqAccess<'de> for Wrapped<A, E>
+where
+    A: de::SeqAccess<'de>,
+    E: Ellipsis,
+{
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        let seed = self.wrap(seed);
+        self.inner.next_element_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A, E> de::MapAccess<'de> for Wrapped<A, E>
+where
+    A: de::MapAccess<'de>,
+    E: Ellipsis,
+{
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let seed = self.wrap(seed);
+        self.inner.next_key_seed(seed)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let seed = self.wrap(seed);
+        self.inner.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A, E> de::EnumAccess<'de> for Wrapped<A, E>
+where
+    A: de::EnumAccess<'de>,
+    E: Ellipsis,
+{
+    type Error = A::Error;
+    type Variant = Wrapped<A::Variant, E>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let seed = self.wrap(seed);
+        let (value, variant) = self.inner.variant_seed(seed)?;
+        Ok((value, Wrapped::new(variant, self.length)))
+    }
+}
+
+impl<'de, A, E> de::VariantAccess<'de> for Wrapped<A, E>
+where
+    A: de::VariantAccess<'de>,
+    E: Ellipsis,
+{
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        let seed = self.wrap(seed);
+        self.inner.newtype_variant_seed(seed)
+    }
+
+    fn tuple_variant<V: de::Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = self.wrap(visitor);
+        self.inner.tuple_variant(len, visitor)
+    }
+
+    fn struct_variant<V: de::Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = self.wrap(visitor);
+        self.inner.struct_variant(fields, visitor)
+    }
+}
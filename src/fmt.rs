@@ -0,0 +1,442 @@
+use {
+    crate::str::{ellipsis::Ascii, Ellipsis, Limited},
+    std::{
+        borrow::Cow,
+        fmt::{self, Display, Write},
+        marker::PhantomData,
+    },
+};
+
+/// returns a [`Display`] value rendered as a string, limited by length.
+///
+/// unlike calling [`ToString::to_string()`] and limiting the result, this stops formatting the
+/// value once it is known that it will not fit, without materializing the full string.
+///
+/// # examples
+///
+/// ```
+/// use shear::{fmt::trim_display, str::ellipsis};
+///
+/// let value = std::iter::repeat("spam").take(1_000).collect::<Vec<_>>().join(", ");
+/// let limited = trim_display::<ellipsis::Ascii>(&value, 16);
+///
+/// assert_eq!(limited, "spam, spam, s...");
+/// ```
+///
+/// # panics
+///
+/// this panics if the [`Display`] implementation returns an error, like
+/// [`ToString::to_string()`].
+pub fn trim_display<E: Ellipsis>(value: &impl Display, length: usize) -> String {
+    let mut s = String::new();
+    write_display::<E, _>(&mut s, value, length)
+        .expect("a Display implementation returned an error unexpectedly");
+    s
+}
+
+/// creates a [`String`] using interpolation of runtime expressions, limited by length.
+///
+/// this accepts a length, followed by the same arguments as [`format!`]. the value is formatted
+/// directly into a limited writer, so the full string is never materialized. see
+/// [`trim_display()`] for more information.
+///
+/// by default, an [`Ascii`][crate::str::ellipsis::Ascii] ellipsis is used. another [`Ellipsis`]
+/// can be provided in angle brackets before the length.
+///
+/// # examples
+///
+/// ```
+/// use shear::{format_limited, str::ellipsis::Horizontal};
+///
+/// let (user, action) = ("katelyn", "trimmed a very long string");
+///
+/// let limited = format_limited!(24, "user {} {}", user, action);
+/// assert_eq!(limited, "user katelyn trimmed ...");
+///
+/// let limited = format_limited!(<Horizontal> 24, "user {user} {action}");
+/// assert_eq!(limited, "user katelyn trimmed …");
+/// ```
+#[macro_export]
+macro_rules! format_limited {
+    (<$ellipsis:ty> $length:expr, $($arg:tt)+) => {
+        $crate::fmt::trim_display::<$ellipsis>(&::std::format_args!($($arg)+), $length)
+    };
+    ($length:expr, $($arg:tt)+) => {
+        $crate::format_limited!(<$crate::str::ellipsis::Ascii> $length, $($arg)+)
+    };
+}
+
+/// writes a [`Display`] value to the given writer, limited by length.
+///
+/// see [`trim_display()`] for more information.
+pub fn write_display<E: Ellipsis, W: Write>(
+    out: &mut W,
+    value: &impl Display,
+    length: usize,
+) -> fmt::Result {
+    let mut writer = LimitedWriter::<_, E>::new(out, length);
+
+    match write!(writer, "{value}") {
+        Ok(()) => {}
+        Err(_) if writer.is_trimmed() => {} // we stopped formatting the value ourselves.
+        Err(error) => return Err(error),
+    }
+
+    writer.finish().map(drop)
+}
+
+/// renders a sequence of styled segments within `width` columns.
+///
+/// segments are written in order, each wrapped in its own [`Style`]. if the segments do not fit,
+/// the last segment that partially fits is trimmed, and an [`Ellipsis`] is written in that
+/// segment's style. segments after it are omitted. if the ellipsis itself is wider than `width`,
+/// it is omitted as well.
+///
+/// this returns the number of columns that were consumed, which is at most `width`. see
+/// [`render_limited!`][crate::render_limited] for a more convenient way to call this.
+///
+/// # examples
+///
+/// ```
+/// use shear::{fmt::render_limited, str::ellipsis::Horizontal};
+///
+/// let bold = ("<b>", "</b>");
+/// let mut cell = String::new();
+/// let segments = [("name", &bold as _), (": ", &() as _), ("a long value", &bold as _)];
+/// let used = render_limited::<Horizontal, _>(&mut cell, 12, &segments).unwrap();
+///
+/// assert_eq!(cell, "<b>name</b>: <b>a lon…</b>");
+/// assert_eq!(used, 12);
+/// ```
+pub fn render_limited<E: Ellipsis, W: Write>(
+    out: &mut W,
+    width: usize,
+    segments: &[(&str, &dyn Style)],
+) -> Result<usize, fmt::Error> {
+    use crate::str::width::str_width;
+
+    let total = segments
+        .iter()
+        .map(|(text, _)| str_width(text))
+        .sum::<usize>();
+    if total <= width {
+        for (text, style) in segments {
+            style.write_styled(out, text)?;
+        }
+        return Ok(total);
+    }
+
+    let ellipsis = (str_width(E::ellipsis()) <= width)
+        .then(E::ellipsis)
+        .unwrap_or_default();
+    let mut remaining = width - str_width(ellipsis);
+    for (text, style) in segments {
+        let size = str_width(text);
+        if size <= remaining {
+            style.write_styled(out, text)?;
+            remaining -= size;
+            continue;
+        }
+
+        // this is the segment that does not fit, so trim it and write the ellipsis.
+        let (kept, _) = text.split_at_width(remaining);
+        remaining -= str_width(kept);
+        let limited = [kept, ellipsis].concat();
+        if !limited.is_empty() {
+            style.write_styled(out, &limited)?;
+        }
+        break;
+    }
+
+    Ok(width - remaining)
+}
+
+/// renders styled segments into a writer, limited by width.
+///
+/// this accepts a writer and a width, followed by a list of `(text, style)` pairs. each style may
+/// be of a different type, so long as it implements [`Style`]. this evaluates to the number of
+/// columns consumed. see [`render_limited()`][crate::fmt::render_limited] for more information.
+///
+/// by default, an [`Ascii`][crate::str::ellipsis::Ascii] ellipsis is used. another [`Ellipsis`]
+/// can be provided in angle brackets before the writer.
+///
+/// # examples
+///
+/// ```
+/// use shear::{render_limited, str::ellipsis::Horizontal};
+///
+/// let (red, reset) = ("\x1b[31m", "\x1b[0m");
+/// let mut cell = String::new();
+///
+/// let used = render_limited!(<Horizontal> &mut cell, 10, ("error", (red, reset)), (": disk full", ()));
+/// assert_eq!(cell, "\x1b[31merror\x1b[0m: di…");
+/// assert_eq!(used.unwrap(), 10);
+/// ```
+#[macro_export]
+macro_rules! render_limited {
+    (<$ellipsis:ty> $out:expr, $width:expr, $(($text:expr, $style:expr)),+ $(,)?) => {
+        $crate::fmt::render_limited::<$ellipsis, _>(
+            $out,
+            $width,
+            &[$((
+                ::core::convert::AsRef::<str>::as_ref(&$text),
+                &$style as &dyn $crate::fmt::Style,
+            )),+],
+        )
+    };
+    ($out:expr, $width:expr, $($segment:tt)+) => {
+        $crate::render_limited!(<$crate::str::ellipsis::Ascii> $out, $width, $($segment)+)
+    };
+}
+
+/// the style of a segment of text.
+///
+/// see [`render_limited()`] for more information.
+///
+/// `()` writes text without any styling. a pair of strings, such as ansi escape sequences or
+/// markup tags, is written before and after the text.
+pub trait Style {
+    /// writes `text` to `out`, in this style.
+    fn write_styled(&self, out: &mut dyn Write, text: &str) -> fmt::Result;
+}
+
+/// a value that can render itself within a budget of columns.
+///
+/// implementors choose their own elision strategy, e.g. dropping optional parts before
+/// truncating. composite values can divide their budget between their parts, and delegate to
+/// each part's [`FitWithin`] implementation in turn.
+///
+/// strings are limited using [`Limited::trim_to_width()`], with an [`Ascii`] ellipsis.
+///
+/// # examples
+///
+/// ```
+/// use {shear::fmt::{fit_within, FitWithin}, std::fmt};
+///
+/// /// a status line, with a label and a message.
+/// struct Status<'a> {
+///     label: &'a str,
+///     message: &'a str,
+/// }
+///
+/// impl FitWithin for Status<'_> {
+///     fn fit(&self, budget: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         // give the label up to half of the budget, and the rest to the message.
+///         let label = self.label.len().min(budget / 2);
+///         self.label.fit(label, f)?;
+///         f.write_str(" ")?;
+///         self.message.fit(budget.saturating_sub(label + 1), f)
+///     }
+/// }
+///
+/// let status = Status { label: "warning", message: "the disk is almost full" };
+///
+/// assert_eq!(fit_within(&status, 40).to_string(), "warning the disk is almost full");
+/// assert_eq!(fit_within(&status, 20).to_string(), "warning the disk ...");
+/// assert_eq!(fit_within(&status, 12).to_string(), "war... th...");
+/// ```
+pub trait FitWithin {
+    /// writes this value to `f`, within `budget` columns.
+    fn fit(&self, budget: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// returns a [`Display`] value that renders `value` within `budget` columns.
+///
+/// see [`FitWithin`] for more information.
+pub fn fit_within<T: FitWithin + ?Sized>(value: &T, budget: usize) -> Within<'_, T> {
+    Within { value, budget }
+}
+
+/// a value that is rendered within a budget when it is displayed.
+///
+/// see [`fit_within()`] for more information.
+pub struct Within<'a, T: ?Sized> {
+    /// the value to display.
+    value: &'a T,
+    /// the number of columns the value may occupy.
+    budget: usize,
+}
+
+/// a [`Write`] implementation that is limited by length.
+///
+/// this forwards its contents to an inner writer, until it runs out of space. at that point, an
+/// [`Ellipsis`] is written, and subsequent writes will return an error so that formatting stops.
+///
+/// contents that may need to be replaced by the ellipsis are held back until it is known whether
+/// they fit. call [`LimitedWriter::finish()`] once all contents have been written to flush them.
+/// the contents are never cut between a character and the combining marks that follow it, even
+/// if they are written separately.
+pub struct LimitedWriter<W, E> {
+    /// the inner writer.
+    inner: W,
+    /// the number of bytes written to the inner writer.
+    written: usize,
+    /// the maximum number of bytes to write.
+    length: usize,
+    /// contents held back, which may or may not fit.
+    pending: String,
+    /// true if the contents did not fit and an ellipsis was written.
+    trimmed: bool,
+    ellipsis: PhantomData<E>,
+}
+
+// === impl limitedwriter ===
+
+impl<W: Write, E: Ellipsis> LimitedWriter<W, E> {
+    /// returns a new [`LimitedWriter`].
+    pub fn new(inner: W, length: usize) -> Self {
+        Self {
+            inner,
+            written: 0,
+            length,
+            pending: String::new(),
+            trimmed: false,
+            ellipsis: PhantomData,
+        }
+    }
+
+    /// returns true if the contents did not fit, and were trimmed.
+    pub fn is_trimmed(&self) -> bool {
+        self.trimmed
+    }
+
+    /// finishes writing, returning the inner writer.
+    ///
+    /// this flushes any contents that were being held back.
+    pub fn finish(self) -> Result<W, fmt::Error> {
+        let Self {
+            mut inner,
+            pending,
+            trimmed,
+            ..
+        } = self;
+
+        if !trimmed {
+            inner.write_str(&pending)?;
+        }
+
+        Ok(inner)
+    }
+
+    /// the number of bytes that can be written before an ellipsis might be needed.
+    fn content_length(&self) -> usize {
+        self.length.saturating_sub(E::ellipsis().len())
+    }
+}
+
+impl<W: Write, E: Ellipsis> Write for LimitedWriter<W, E> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        use crate::str::split::{cluster_floor, without_last_cluster};
+
+        if self.trimmed {
+            return Err(fmt::Error);
+        }
+
+        self.pending.push_str(s);
+        let room = self.content_length().saturating_sub(self.written);
+
+        // if the contents do not fit, cut them at the last cluster boundary that fits alongside
+        // the ellipsis, as `trim_to_length()` does.
+        if self.written + self.pending.len() > self.length {
+            self.trimmed = true;
+            let kept = cluster_floor(&self.pending, room);
+            self.inner.write_str(&self.pending[..kept])?;
+            self.inner.write_str(E::ellipsis())?;
+            return Err(fmt::Error);
+        }
+
+        // otherwise, write what we know will be kept directly to the inner writer, and hold back
+        // the rest. the last cluster is held back too, since later contents may extend it.
+        let end = match self.pending.len() <= room {
+            true => without_last_cluster(&self.pending),
+            false => cluster_floor(&self.pending, room),
+        };
+        self.inner.write_str(&self.pending[..end])?;
+        self.written += end;
+        self.pending.drain(..end);
+
+        Ok(())
+    }
+}
+
+// === impl within ===
+
+impl<T: FitWithin + ?Sized> Display for Within<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { value, budget } = self;
+
+        value.fit(*budget, f)
+    }
+}
+
+// === impl fitwithin ===
+
+impl FitWithin for str {
+    fn fit(&self, budget: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.trim_to_width_cow::<Ascii>(budget))
+    }
+}
+
+impl FitWithin for String {
+    fn fit(&self, budget: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fit(budget, f)
+    }
+}
+
+impl FitWithin for Cow<'_, str> {
+    fn fit(&self, budget: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_ref().fit(budget, f)
+    }
+}
+
+/// a missing value renders nothing.
+impl<T: FitWithin> FitWithin for Option<T> {
+    fn fit(&self, budget: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Some(value) => value.fit(budget, f),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: FitWithin + ?Sized> FitWithin for &T {
+    fn fit(&self, budget: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fit(budget, f)
+    }
+}
+
+impl<T: FitWithin + ?Sized> FitWithin for Box<T> {
+    fn fit(&self, budget: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fit(budget, f)
+    }
+}
+
+impl<T: FitWithin + ?Sized> FitWithin for std::rc::Rc<T> {
+    fn fit(&self, budget: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fit(budget, f)
+    }
+}
+
+impl<T: FitWithin + ?Sized> FitWithin for std::sync::Arc<T> {
+    fn fit(&self, budget: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fit(budget, f)
+    }
+}
+
+// === impl style ===
+
+impl Style for () {
+    fn write_styled(&self, out: &mut dyn Write, text: &str) -> fmt::Result {
+        out.write_str(text)
+    }
+}
+
+impl<P: AsRef<str>, S: AsRef<str>> Style for (P, S) {
+    fn write_styled(&self, out: &mut dyn Write, text: &str) -> fmt::Result {
+        let (prefix, suffix) = self;
+
+        out.write_str(prefix.as_ref())?;
+        out.write_str(text)?;
+        out.write_str(suffix.as_ref())
+    }
+}
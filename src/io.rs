@@ -0,0 +1,344 @@
+use {
+    crate::str::{Ellipsis, Limited},
+    std::{
+        io::{self, BufRead},
+        marker::PhantomData,
+    },
+};
+
+/// a "tee" writer.
+///
+/// this forwards everything written to it to a primary writer, while also keeping a limited
+/// preview of the written contents. the preview will be no longer than `budget` bytes, including
+/// the [`Ellipsis`] used to indicate that the stream was trimmed.
+///
+/// this can be used to persist the full output of something, while only logging a preview of
+/// it, without needing to make a second pass over the data.
+///
+/// # examples
+///
+/// ```
+/// use {shear::{io::Tee, str::ellipsis}, std::io::Write};
+///
+/// let mut tee = Tee::<_, ellipsis::Ascii>::new(Vec::new(), 8);
+/// tee.write_all(b"hello, world!").unwrap();
+///
+/// let (full, preview) = tee.into_parts();
+/// assert_eq!(full, b"hello, world!");
+/// assert_eq!(preview, "hello...");
+/// ```
+pub struct Tee<W, E> {
+    /// the primary writer.
+    inner: W,
+    /// the leading bytes of the stream.
+    preview: Vec<u8>,
+    /// the maximum length of the preview, in bytes.
+    budget: usize,
+    /// true if more than `budget` bytes have been written.
+    overflowed: bool,
+    ellipsis: PhantomData<E>,
+}
+
+/// an iterator over the lines of a reader, limited by height.
+///
+/// this reads lines from a [`BufRead`] one at a time, limiting each line to a width if one is
+/// given using [`TrimLines::width()`]. at most `height` lines are yielded. if the reader holds
+/// more lines than that, the last line is replaced with the [`Ellipsis`], and no more input is
+/// read.
+///
+/// [`TrimLines::next_line()`] reuses the same buffers for each line, and does not allocate once
+/// they are large enough. the [`Iterator`] implementation yields owned lines.
+///
+/// lines are split on `\n` and `\r\n`. a line that is not valid utf-8 is yielded as an error.
+///
+/// # examples
+///
+/// ```
+/// use shear::{io::TrimLines, str::ellipsis};
+///
+/// let input = "one\na very long line\nthree\nfour\n".as_bytes();
+/// let mut lines = TrimLines::<_, ellipsis::Ascii>::new(input, 3).width(8);
+///
+/// while let Some(line) = lines.next_line() {
+///     println!("{}", line.unwrap());
+/// }
+/// # let lines = TrimLines::<_, ellipsis::Ascii>::new(input, 3).width(8);
+/// # let lines = lines.collect::<Result<Vec<_>, _>>().unwrap();
+/// # assert_eq!(lines, ["one", "a ver...", "..."]);
+/// ```
+pub struct TrimLines<R, E> {
+    /// the reader.
+    reader: R,
+    /// the maximum number of lines to yield.
+    height: usize,
+    /// the maximum width of a line, if any.
+    width: Option<usize>,
+    /// the number of lines yielded so far.
+    yielded: usize,
+    /// true once the last line has been yielded.
+    finished: bool,
+    /// a scratch buffer, holding the line most recently read.
+    scratch: String,
+    /// the line most recently yielded.
+    line: String,
+    /// the line ending of the first line, see [`trim_lines()`].
+    ending: &'static str,
+    ellipsis: PhantomData<E>,
+}
+
+/// reads the lines of a reader, limited by height.
+///
+/// this behaves like [`trim_to_height()`][Limited::trim_to_height], but input is read lazily.
+/// once `height` lines have been read, the reader is only checked for more input, to decide
+/// whether an [`Ellipsis`] is needed, and the rest of the input is never consumed. this allows
+/// the first lines of a very large file to be shown without reading all of it.
+///
+/// lines are split on `\n` and `\r\n`, and joined with `\r\n` if the first line ends with
+/// `\r\n`, or with `\n` otherwise. unlike [`trim_to_height()`][Limited::trim_to_height], other
+/// [`Terminators`][crate::str::Terminators], such as `\u{2028}`, do not end a line. see
+/// [`TrimLines`] to limit each line to a width, or to visit each line without joining them.
+///
+/// # errors
+///
+/// this returns an error if reading fails, or if a line is not valid utf-8.
+///
+/// # examples
+///
+/// ```
+/// use shear::{io::trim_lines, str::ellipsis::Ascii};
+///
+/// let log = "starting\nlistening\nconnected\ndisconnected\n".as_bytes();
+///
+/// assert_eq!(trim_lines::<Ascii>(log, 3).unwrap(), "starting\nlistening\n...");
+/// ```
+pub fn trim_lines<E: Ellipsis>(reader: impl BufRead, height: usize) -> io::Result<String> {
+    let mut lines = TrimLines::<_, E>::new(reader, height);
+    let mut limited = String::new();
+
+    // the line ending is known once the first line has been read.
+    let mut first = true;
+    loop {
+        let ending = lines.ending;
+        let Some(line) = lines.next_line() else {
+            break;
+        };
+        if !std::mem::take(&mut first) {
+            limited.push_str(ending);
+        }
+        limited.push_str(line?);
+    }
+
+    Ok(limited)
+}
+
+// === impl tee ===
+
+impl<W, E> Tee<W, E> {
+    /// returns a new [`Tee`], whose preview will be limited to `budget` bytes.
+    pub fn new(inner: W, budget: usize) -> Self {
+        Self {
+            inner,
+            preview: Vec::with_capacity(budget),
+            budget,
+            overflowed: false,
+            ellipsis: PhantomData,
+        }
+    }
+
+    /// returns a reference to the primary writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// returns a mutable reference to the primary writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// returns true if the stream has been longer than the preview budget.
+    pub fn is_trimmed(&self) -> bool {
+        self.overflowed
+    }
+}
+
+impl<W, E: Ellipsis> Tee<W, E> {
+    /// returns the limited preview of the contents written so far.
+    ///
+    /// invalid utf-8 is replaced with `U+FFFD`, see [`String::from_utf8_lossy()`].
+    pub fn preview(&self) -> String {
+        let Self {
+            preview,
+            budget,
+            overflowed,
+            ..
+        } = self;
+
+        let value = Self::decode(preview);
+
+        if *overflowed {
+            // the stream did not fit, so find the prefix that fits alongside the ellipsis.
+            let ellipsis = E::ellipsis();
+            let mut end = budget.saturating_sub(ellipsis.len()).min(value.len());
+            while !value.is_char_boundary(end) {
+                end -= 1;
+            }
+            let mut limited = String::with_capacity(end + ellipsis.len());
+            limited.push_str(&value[..end]);
+            limited.push_str(ellipsis);
+            limited
+        } else {
+            value.trim_to_length::<E>(*budget)
+        }
+    }
+
+    /// consumes the tee, returning the primary writer and the limited preview.
+    pub fn into_parts(self) -> (W, String) {
+        let preview = self.preview();
+        (self.inner, preview)
+    }
+
+    /// decodes the preview buffer.
+    ///
+    /// an incomplete character at the end of the buffer is discarded, rather than being replaced.
+    fn decode(bytes: &[u8]) -> String {
+        let end = match std::str::from_utf8(bytes) {
+            Ok(_) => bytes.len(),
+            Err(error) if error.error_len().is_none() => error.valid_up_to(),
+            Err(_) => bytes.len(),
+        };
+
+        String::from_utf8_lossy(&bytes[..end]).into_owned()
+    }
+}
+
+impl<W: io::Write, E> io::Write for Tee<W, E> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let Self {
+            inner,
+            preview,
+            budget,
+            overflowed,
+            ..
+        } = self;
+
+        let written = inner.write(buf)?;
+        let buf = &buf[..written];
+
+        let room = budget.saturating_sub(preview.len());
+        if buf.len() > room {
+            *overflowed = true;
+        }
+        preview.extend_from_slice(&buf[..room.min(buf.len())]);
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+// === impl trimlines ===
+
+impl<R: BufRead, E: Ellipsis> TrimLines<R, E> {
+    /// returns a new [`TrimLines`] iterator, reading at most `height` lines from `reader`.
+    pub fn new(reader: R, height: usize) -> Self {
+        Self {
+            reader,
+            height,
+            width: None,
+            yielded: 0,
+            finished: false,
+            scratch: String::new(),
+            line: String::new(),
+            ending: "\n",
+            ellipsis: PhantomData,
+        }
+    }
+
+    /// limits each line to `width` columns.
+    ///
+    /// see [`Limited::trim_to_width()`] for more information.
+    pub fn width(self, width: usize) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
+    }
+
+    /// returns the next line, reusing this iterator's buffers.
+    pub fn next_line(&mut self) -> Option<io::Result<&str>> {
+        match self.advance() {
+            Ok(true) => Some(Ok(&self.line)),
+            Ok(false) => None,
+            Err(error) => Some(Err(error)),
+        }
+    }
+
+    /// reads the next line into the line buffer, returning false if there are no more lines.
+    fn advance(&mut self) -> io::Result<bool> {
+        let Self {
+            reader,
+            height,
+            width,
+            yielded,
+            finished,
+            scratch,
+            line,
+            ending,
+            ..
+        } = self;
+
+        if *finished {
+            return Ok(false);
+        }
+        line.clear();
+
+        // if there is no room for any lines, the ellipsis is yielded if there is any input.
+        if *height == 0 {
+            *finished = true;
+            if reader.fill_buf()?.is_empty() {
+                return Ok(false);
+            }
+            line.push_str(E::ellipsis());
+            return Ok(true);
+        }
+
+        scratch.clear();
+        if reader.read_line(scratch)? == 0 {
+            *finished = true;
+            return Ok(false);
+        }
+
+        if *yielded == 0 && scratch.ends_with("\r\n") {
+            *ending = "\r\n";
+        }
+
+        // if this is the last line we have room for, yield the ellipsis if any input follows it.
+        *yielded += 1;
+        if *yielded == *height {
+            *finished = true;
+            if !reader.fill_buf()?.is_empty() {
+                line.push_str(E::ellipsis());
+                return Ok(true);
+            }
+        }
+
+        let value = scratch.strip_suffix('\n').unwrap_or(scratch);
+        let value = value.strip_suffix('\r').unwrap_or(value);
+        match width {
+            Some(width) => value.extend_trimmed_to_width::<E>(line, *width),
+            None => line.push_str(value),
+        }
+
+        Ok(true)
+    }
+}
+
+impl<R: BufRead, E: Ellipsis> Iterator for TrimLines<R, E> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_line().map(|line| line.map(str::to_owned))
+    }
+}
@@ -1,8 +1,24 @@
 use {
+    self::split::{Split, State},
     std::iter::Peekable,
     tap::{Pipe, TapOptional},
 };
 
+pub use self::{
+    retention::Retention,
+    split::{Head, Overflow},
+    weighted::Weighted,
+};
+
+#[cfg(feature = "str")]
+pub use self::height::Height;
+
+#[cfg(feature = "str")]
+mod height;
+mod retention;
+mod split;
+mod weighted;
+
 /// a trait for "limiting" an iterator.
 ///
 /// [`limited()`][Limited::limited] will transform an iterator, returning a [`LimitedIter<I>`] that
@@ -43,8 +59,22 @@ pub trait Limited: Iterator + Sized {
 /// a "limited" iterator.
 ///
 /// see [`Limited::limited()`] for more information.
+///
+/// # single pass
+///
+/// a limited iterator consumes each item of its inner iterator at most once, and never requires
+/// its items or its inner iterator to be [`Clone`]. this holds in each phase, including when the
+/// tail of the sequence is collected to find out whether it fits. once it is known that the inner
+/// iterator must be truncated, no further items are pulled from it.
+///
+/// this means that sources which cannot be rewound, such as channel receivers or lines read from
+/// a socket, can be limited safely. at most `size + 1` items are pulled from the inner iterator,
+/// if each item has a size of at least one. the adapters in this module, such as [`Weighted`] and
+/// [`Retention`], uphold the same contract.
 pub struct LimitedIter<I: Iterator> {
     inner: Inner<I>,
+    /// true if the iterator has been truncated, and is emitting [`Limited::contd()`].
+    truncated: bool,
 }
 
 /// the inner finite state machine for a [`LimitedIter<I>`].
@@ -83,7 +113,193 @@ enum Inner<I: Iterator> {
 impl<I: Iterator + Limited> LimitedIter<I> {
     /// returns a new [`LimitedIter`].
     pub fn new(iter: I, size: usize) -> Self {
-        Inner::new(iter, size).pipe(|inner| Self { inner })
+        Self::with_contd(iter, size, I::contd())
+    }
+
+    /// returns a new [`LimitedIter`], using the given continuation sequence.
+    ///
+    /// this behaves like [`LimitedIter::new()`], but `contd` is yielded to indicate truncation,
+    /// rather than [`Limited::contd()`]. this allows the continuation sequence to be chosen at
+    /// runtime.
+    ///
+    /// ```
+    /// use shear::iter::{Limited, LimitedIter};
+    /// # struct Chars<'a>(std::str::Chars<'a>);
+    /// # impl Iterator for Chars<'_> {
+    /// #     type Item = char;
+    /// #     fn next(&mut self) -> Option<char> { self.0.next() }
+    /// # }
+    /// # impl Limited for Chars<'_> {
+    /// #     type Contd = std::str::Chars<'static>;
+    /// #     fn contd() -> Self::Contd { "...".chars() }
+    /// # }
+    ///
+    /// let marker = String::from("[more]");
+    /// let iter = LimitedIter::with_contd(Chars("abcdefghijkl".chars()), 10, marker.chars());
+    ///
+    /// assert_eq!(iter.collect::<String>(), "abcd[more]");
+    /// ```
+    pub fn with_contd(iter: I, size: usize, contd: impl IntoIterator<Item = I::Item>) -> Self {
+        let inner = Inner::new(iter, size, contd);
+        let truncated = matches!(inner, Inner::Tail { .. });
+        Self { inner, truncated }
+    }
+
+    /// returns a new [`LimitedIter`] that yields the inner iterator's items as-is if they fit.
+    ///
+    /// this behaves like [`LimitedIter::with_contd()`], except when `size` only has room for the
+    /// continuation sequence. rather than yielding `contd` straight away, the inner iterator's
+    /// items are collected while they fit within `size`, and yielded if there are none left.
+    #[cfg(feature = "str")]
+    pub(crate) fn fitting(iter: I, size: usize, contd: impl IntoIterator<Item = I::Item>) -> Self {
+        let contd = contd.into_iter().collect::<Vec<_>>();
+        if size > contd.iter().map(I::element_size).sum::<usize>() {
+            return Self::with_contd(iter, size, contd);
+        }
+
+        match Self::collect_tail(&mut iter.peekable(), size) {
+            Some(tail) => Self {
+                inner: Inner::tail(tail),
+                truncated: false,
+            },
+            None => Self {
+                inner: Inner::tail(contd),
+                truncated: true,
+            },
+        }
+    }
+
+    /// returns a reference to the next item, without advancing the iterator.
+    ///
+    /// this may be the first item of [`Limited::contd()`], if the iterator has been truncated.
+    /// use [`LimitedIter::is_truncated()`] after peeking to find out whether the next item is
+    /// part of this continuation sequence.
+    ///
+    /// ```
+    /// use shear::iter::Limited;
+    /// # struct Chars<'a>(std::str::Chars<'a>);
+    /// # impl Iterator for Chars<'_> {
+    /// #     type Item = char;
+    /// #     fn next(&mut self) -> Option<char> { self.0.next() }
+    /// # }
+    /// # impl Limited for Chars<'_> {
+    /// #     type Contd = std::str::Chars<'static>;
+    /// #     fn contd() -> Self::Contd { "...".chars() }
+    /// # }
+    ///
+    /// let mut iter = Chars("abcdef".chars()).limited(5);
+    /// assert_eq!(iter.next(), Some('a'));
+    /// assert_eq!(iter.next(), Some('b'));
+    ///
+    /// assert!(!iter.is_truncated());
+    /// assert_eq!(iter.peek(), Some(&'.'));
+    /// assert!(iter.is_truncated());
+    /// ```
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        use Inner::*;
+
+        self.truncate_if_full();
+
+        match &mut self.inner {
+            Running { iter, .. } => iter.peek(),
+            Tail { iter } => iter.peek(),
+            Finished => None,
+        }
+    }
+
+    /// splits this iterator into a [`Head`] of kept items, and an [`Overflow`] of elided items.
+    ///
+    /// the head yields the items that this iterator would yield, without the continuation
+    /// sequence of [`Limited::contd()`]. the overflow yields the items that would have been
+    /// replaced by the continuation sequence. neither iterator collects the inner iterator's
+    /// items up front, but the head's items are buffered if the overflow is advanced first.
+    ///
+    /// items that have already been discarded, e.g. if this iterator was truncated by calling
+    /// [`LimitedIter::peek()`], are not included in the overflow.
+    ///
+    /// ```
+    /// use shear::iter::Limited;
+    /// # struct Chars<'a>(std::str::Chars<'a>);
+    /// # impl Iterator for Chars<'_> {
+    /// #     type Item = char;
+    /// #     fn next(&mut self) -> Option<char> { self.0.next() }
+    /// # }
+    /// # impl Limited for Chars<'_> {
+    /// #     type Contd = std::str::Chars<'static>;
+    /// #     fn contd() -> Self::Contd { "...".chars() }
+    /// # }
+    ///
+    /// let (head, overflow) = Chars("abcdefgh".chars()).limited(5).split();
+    ///
+    /// assert_eq!(head.collect::<String>(), "ab");
+    /// assert_eq!(overflow.collect::<String>(), "cdefgh");
+    /// ```
+    pub fn split(self) -> (Head<I>, Overflow<I>) {
+        let Self { inner, truncated } = self;
+
+        match inner {
+            Inner::Running {
+                iter,
+                remaining,
+                contd,
+            } => {
+                let space = contd.iter().map(I::element_size).sum::<usize>() + remaining;
+                Split::pair(
+                    Some(iter),
+                    remaining,
+                    space,
+                    State::Running,
+                    Default::default(),
+                )
+            }
+            Inner::Tail { iter } if !truncated => {
+                Split::pair(None, 0, 0, State::Fits, iter.collect())
+            }
+            Inner::Finished if !truncated => {
+                Split::pair(None, 0, 0, State::Fits, Default::default())
+            }
+            Inner::Tail { .. } | Inner::Finished => {
+                Split::pair(None, 0, 0, State::Truncated, Default::default())
+            }
+        }
+    }
+
+    /// moves to the tail of the sequence, if the next item does not fit.
+    ///
+    /// this does nothing if the iterator is not running, or if the next item fits.
+    fn truncate_if_full(&mut self) {
+        let Self { inner, truncated } = self;
+
+        let Inner::Running {
+            iter,
+            remaining,
+            contd,
+        } = inner
+        else {
+            return;
+        };
+
+        let fits = iter
+            .peek()
+            .map(I::element_size) // how much space does the next item take..
+            .map(|len| len <= *remaining); // ..and does it fit?
+        if fits != Some(false) {
+            return;
+        }
+
+        // the next item exists, but we have to determine whether to truncate.
+        let space = {
+            let c = contd.iter().map(I::element_size).sum::<usize>();
+            c + *remaining
+        };
+
+        *inner = match Self::collect_tail(iter, space) {
+            Some(tail) => Inner::tail(tail),
+            None => {
+                *truncated = true;
+                std::mem::take(contd).pipe(Inner::tail)
+            }
+        };
     }
 }
 
@@ -92,7 +308,7 @@ impl<I: Iterator + Limited> Iterator for LimitedIter<I> {
     fn next(&mut self) -> Option<Self::Item> {
         use Inner::*;
 
-        let Self { inner } = self;
+        let Self { inner, .. } = self;
 
         /// helper macro:
         ///
@@ -108,9 +324,7 @@ impl<I: Iterator + Limited> Iterator for LimitedIter<I> {
 
         match inner {
             Running {
-                contd,
-                iter,
-                remaining,
+                iter, remaining, ..
             } => {
                 match iter
                     .peek()
@@ -124,15 +338,7 @@ impl<I: Iterator + Limited> Iterator for LimitedIter<I> {
                     }
                     // the next item exists, but we have to determine whether to truncate.
                     Some(None) => {
-                        let space = {
-                            let c = contd.iter().map(I::element_size).sum::<usize>();
-                            c + *remaining
-                        };
-
-                        *inner = Self::collect_tail(iter, space)
-                            .unwrap_or_else(|| std::mem::take(contd))
-                            .pipe(Inner::tail);
-
+                        self.truncate_if_full();
                         self.next()
                     }
                     // the inner iterator has finished.
@@ -179,19 +385,27 @@ impl<I: Iterator> LimitedIter<I> {
         matches!(
             self,
             Self {
-                inner: Inner::Finished
+                inner: Inner::Finished,
+                ..
             }
         )
     }
+
+    /// returns true if this iterator has been truncated.
+    ///
+    /// once true, the remaining items are those of [`Limited::contd()`].
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
 }
 
 // === impl inner ===
 
 impl<I: Iterator + Limited> Inner<I> {
     /// returns a new [`Inner`].
-    fn new(iter: I, total: usize) -> Self {
+    fn new(iter: I, total: usize, contd: impl IntoIterator<Item = I::Item>) -> Self {
         // collect the continuation sequence, and find out how large it is.
-        let contd = I::contd().into_iter().collect::<Vec<_>>();
+        let contd = contd.into_iter().collect::<Vec<_>>();
         let contd_size = contd.iter().map(I::element_size).sum();
 
         match total.checked_sub(contd_size) {
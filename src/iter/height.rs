@@ -0,0 +1,141 @@
+use {
+    super::{Limited, LimitedIter},
+    crate::str::{DynamicEllipsis, Ellipsis, Lines},
+    std::{borrow::Cow, marker::PhantomData},
+};
+
+/// an iterator of strings, that can be limited by height.
+///
+/// each item counts for as many lines as it spans, see [`Lines`]. an item that is empty, or
+/// holds a single line, counts as one line. this can be used to give custom sources of lines,
+/// e.g. chunks of a rope or rows of a database query, the same height semantics as
+/// [`Limited::trim_to_height()`][crate::str::Limited::trim_to_height].
+///
+/// if the iterator is truncated, the [`Ellipsis`] is yielded as the last item.
+///
+/// # examples
+///
+/// ```
+/// use shear::{iter::{Height, Limited}, str::ellipsis};
+///
+/// let rows = ["one", "two\nthree", "four", "five"];
+/// let limited = Height::<_, ellipsis::Ascii>::new(rows.into_iter())
+///     .limited(4)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(limited, ["one", "two\nthree", "..."]);
+/// ```
+///
+/// the last item can instead be a marker that counts the omitted lines, using
+/// [`Height::limited_dynamic()`].
+pub struct Height<I, E> {
+    iter: I,
+    ellipses: PhantomData<E>,
+}
+
+// === impl height ===
+
+impl<I, E> Height<I, E> {
+    /// returns a new [`Height`] iterator.
+    pub fn new(iter: I) -> Self {
+        Self {
+            iter,
+            ellipses: PhantomData,
+        }
+    }
+}
+
+impl<'a, I> Height<I, ()>
+where
+    I: Iterator<Item = &'a str>,
+{
+    /// limits the iterator by height, using a [`DynamicEllipsis`] to indicate truncation.
+    ///
+    /// if the iterator is truncated, the last item is the ellipsis, which is given the number of
+    /// lines that were dropped. this consumes the whole iterator, so that the omitted lines can
+    /// be counted.
+    ///
+    /// ```
+    /// use shear::iter::Height;
+    ///
+    /// let rows = ["one", "two\nthree", "four", "five"];
+    /// let limited = Height::new(rows.into_iter())
+    ///     .limited_dynamic(3, &|n| format!("… (+{n} lines)"))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(limited, ["one", "… (+4 lines)"]);
+    /// ```
+    pub fn limited_dynamic<D: DynamicEllipsis + ?Sized>(
+        self,
+        height: usize,
+        ellipsis: &D,
+    ) -> impl Iterator<Item = Cow<'a, str>> {
+        let items = self.iter.collect::<Vec<_>>();
+        let total = items.iter().map(size).sum::<usize>();
+
+        // if the items do not fit, keep those that fit alongside the ellipsis.
+        let (mut kept, mut used) = (items.len(), total);
+        if total > height {
+            let budget = height.saturating_sub(1);
+            (kept, used) = (0, 0);
+            for item in &items {
+                if used + size(item) > budget {
+                    break;
+                }
+                (kept, used) = (kept + 1, used + size(item));
+            }
+        }
+
+        let marker = (total > height).then(|| ellipsis.ellipsis(total - used));
+        items
+            .into_iter()
+            .take(kept)
+            .map(Cow::Borrowed)
+            .chain(marker.map(Cow::Owned))
+    }
+}
+
+/// string iterators can be limited by height with an [`Ellipsis`].
+impl<'a, I, E> Limited for Height<I, E>
+where
+    I: Iterator<Item = &'a str> + Sized,
+    E: Ellipsis,
+{
+    fn limited(self, size: usize) -> LimitedIter<Self> {
+        LimitedIter::new(self, size)
+    }
+
+    type Contd = std::iter::Once<&'a str>;
+
+    fn contd() -> Self::Contd {
+        std::iter::once(E::ellipsis())
+    }
+
+    fn element_size(item: &&'a str) -> usize {
+        size(item)
+    }
+}
+
+impl<'a, I, E> Iterator for Height<I, E>
+where
+    I: Iterator<Item = &'a str> + Sized,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { iter, .. } = self;
+
+        iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Self { iter, .. } = self;
+
+        iter.size_hint()
+    }
+}
+
+/// counts the number of lines that an item spans, counting empty items as one line.
+fn size(item: &&str) -> usize {
+    Lines::new(item).count().max(1)
+}
@@ -0,0 +1,100 @@
+use {
+    super::Limited,
+    std::{collections::VecDeque, marker::PhantomData},
+};
+
+/// a queue of items, limited by their total size.
+///
+/// this is a "retention" counterpart to [`Limited::limited()`]. rather than keeping the first
+/// items of an iterator that fit, a [`Retention`] keeps the most recent items that fit, dropping
+/// the oldest items from the front of the queue as new items are pushed onto the back. this is
+/// useful for e.g. bounded in-memory log buffers.
+///
+/// items are measured using [`Limited::element_size()`] of `L`. the number of items that have
+/// been dropped is tracked, see [`Retention::elided()`].
+///
+/// # examples
+///
+/// ```
+/// use {
+///     shear::iter::{Retention, Weighted},
+///     std::iter::Empty,
+/// };
+///
+/// let mut logs = Retention::<Weighted<Empty<_>, Empty<_>>>::new(10);
+/// logs.extend([("starting", 8), ("ready", 5), ("request", 7)]);
+///
+/// let kept = logs.iter().map(|(line, _)| *line).collect::<Vec<_>>();
+/// assert_eq!(kept, ["request"]);
+/// assert_eq!(logs.elided(), 2);
+/// ```
+pub struct Retention<L: Limited> {
+    /// the retained items.
+    items: VecDeque<L::Item>,
+    /// the total size of the retained items.
+    size: usize,
+    /// the maximum total size of the retained items.
+    capacity: usize,
+    /// the number of items that have been dropped.
+    elided: usize,
+    measure: PhantomData<L>,
+}
+
+// === impl retention ===
+
+impl<L: Limited> Retention<L> {
+    /// returns a new, empty [`Retention`], holding items up to a total size of `capacity`.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            items: VecDeque::new(),
+            size: 0,
+            capacity,
+            elided: 0,
+            measure: PhantomData,
+        }
+    }
+
+    /// pushes an item onto the back of the queue, dropping items from the front until it fits.
+    ///
+    /// an item that is larger than the capacity is dropped immediately, along with every other
+    /// item in the queue.
+    pub fn push_back(&mut self, item: L::Item) {
+        let size = L::element_size(&item);
+        self.items.push_back(item);
+        self.size += size;
+
+        while self.size > self.capacity {
+            let Some(dropped) = self.items.pop_front() else {
+                break;
+            };
+            self.size -= L::element_size(&dropped);
+            self.elided += 1;
+        }
+    }
+
+    /// returns an iterator over the retained items, from oldest to newest.
+    pub fn iter(&self) -> std::collections::vec_deque::Iter<'_, L::Item> {
+        self.items.iter()
+    }
+
+    /// returns the total size of the retained items.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// returns the number of items that have been dropped.
+    pub fn elided(&self) -> usize {
+        self.elided
+    }
+
+    /// returns the retained items.
+    pub fn into_inner(self) -> VecDeque<L::Item> {
+        self.items
+    }
+}
+
+impl<L: Limited> Extend<L::Item> for Retention<L> {
+    fn extend<T: IntoIterator<Item = L::Item>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push_back(item))
+    }
+}
@@ -0,0 +1,166 @@
+use {
+    super::Limited,
+    std::{cell::RefCell, collections::VecDeque, iter::Peekable, rc::Rc},
+    tap::Pipe,
+};
+
+/// an iterator over the items kept by a [`LimitedIter`][super::LimitedIter].
+///
+/// see [`LimitedIter::split()`][super::LimitedIter::split] for more information.
+pub struct Head<I: Iterator> {
+    split: Rc<RefCell<Split<I>>>,
+}
+
+/// an iterator over the items elided by a [`LimitedIter`][super::LimitedIter].
+///
+/// see [`LimitedIter::split()`][super::LimitedIter::split] for more information.
+pub struct Overflow<I: Iterator> {
+    split: Rc<RefCell<Split<I>>>,
+}
+
+/// the state shared by a [`Head`] and [`Overflow`] pair.
+pub(super) struct Split<I: Iterator> {
+    /// the inner iterator.
+    iter: Option<Peekable<I>>,
+    /// the remaining space for items in the head.
+    remaining: usize,
+    /// the remaining space, including the space reserved for [`Limited::contd()`].
+    space: usize,
+    /// whether we know if the iterator has been truncated.
+    state: State,
+    /// items taken from the inner iterator while deciding whether to truncate.
+    lookahead: VecDeque<I::Item>,
+    /// head items taken from the inner iterator while advancing the overflow.
+    head: VecDeque<I::Item>,
+}
+
+/// whether a [`Split`] has been truncated.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub(super) enum State {
+    /// the head is still running.
+    Running,
+    /// every item fits in the head.
+    Fits,
+    /// the iterator has been truncated.
+    Truncated,
+}
+
+// === impl head ===
+
+impl<I: Iterator + Limited> Head<I> {
+    /// returns true if the iterator has been truncated, i.e. the overflow is not empty.
+    ///
+    /// this is only known once the head has been exhausted.
+    pub fn is_truncated(&self) -> bool {
+        self.split.borrow().state == State::Truncated
+    }
+}
+
+impl<I: Iterator + Limited> Iterator for Head<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut split = self.split.borrow_mut();
+
+        split.head.pop_front().or_else(|| split.advance_head())
+    }
+}
+
+// === impl overflow ===
+
+impl<I: Iterator + Limited> Iterator for Overflow<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut split = self.split.borrow_mut();
+
+        // if the head has not been exhausted yet, buffer its items until we know whether the
+        // iterator has been truncated.
+        while split.state == State::Running {
+            match split.advance_head() {
+                Some(item) => split.head.push_back(item),
+                None => break,
+            }
+        }
+
+        match split.state {
+            State::Running | State::Fits => None,
+            State::Truncated => split
+                .lookahead
+                .pop_front()
+                .or_else(|| split.iter.as_mut().and_then(Iterator::next)),
+        }
+    }
+}
+
+// === impl split ===
+
+impl<I: Iterator + Limited> Split<I> {
+    /// returns a new [`Head`] and [`Overflow`] pair.
+    pub(super) fn pair(
+        iter: Option<Peekable<I>>,
+        remaining: usize,
+        space: usize,
+        state: State,
+        lookahead: VecDeque<I::Item>,
+    ) -> (Head<I>, Overflow<I>) {
+        let split = Self {
+            iter,
+            remaining,
+            space,
+            state,
+            lookahead,
+            head: VecDeque::new(),
+        }
+        .pipe(RefCell::new)
+        .pipe(Rc::new);
+
+        let head = Head {
+            split: split.clone(),
+        };
+        let overflow = Overflow { split };
+
+        (head, overflow)
+    }
+
+    /// returns the next item in the head, taken from the inner iterator.
+    fn advance_head(&mut self) -> Option<I::Item> {
+        match self.state {
+            State::Running => {}
+            State::Fits => return self.lookahead.pop_front(),
+            State::Truncated => return None,
+        }
+
+        let Some(iter) = self.iter.as_mut() else {
+            self.state = State::Fits;
+            return None;
+        };
+
+        match iter.peek().map(I::element_size) {
+            // the inner iterator has finished.
+            None => {
+                self.state = State::Fits;
+                None
+            }
+            // the next item fits.
+            Some(size) if size <= self.remaining => {
+                self.remaining -= size;
+                self.space -= size;
+                iter.next()
+            }
+            // the next item does not fit, find out whether the rest would fit in its place.
+            Some(_) => {
+                while let Some(size) = iter.peek().map(I::element_size) {
+                    if size > self.space {
+                        self.state = State::Truncated;
+                        return None;
+                    }
+                    self.space -= size;
+                    self.lookahead.extend(iter.next());
+                }
+                self.state = State::Fits;
+                self.lookahead.pop_front()
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use {super::Limited, std::marker::PhantomData};
+
+/// an iterator of `(item, weight)` pairs, that can be limited by weight.
+///
+/// this can be used to limit an iterator whose items' sizes are known in advance, e.g. if they
+/// were loaded from a database or computed elsewhere, without needing to implement
+/// [`Limited::element_size()`].
+///
+/// the continuation sequence is provided by `C`, which must implement [`Default`]. use
+/// [`std::iter::Empty`] if no continuation sequence is needed.
+///
+/// # examples
+///
+/// ```
+/// use shear::iter::{Limited, Weighted};
+///
+/// let rows = [("a", 3), ("b", 4), ("c", 2), ("d", 5)];
+/// let limited = Weighted::<_, std::iter::Empty<_>>::new(rows.into_iter())
+///     .limited(10)
+///     .map(|(row, _)| row)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(limited, ["a", "b", "c"]);
+/// ```
+pub struct Weighted<I, C> {
+    iter: I,
+    contd: PhantomData<C>,
+}
+
+// === impl weighted ===
+
+impl<I, C> Weighted<I, C> {
+    /// returns a new [`Weighted`] iterator.
+    pub fn new(iter: I) -> Self {
+        Self {
+            iter,
+            contd: PhantomData,
+        }
+    }
+}
+
+/// iterators of `(item, weight)` pairs are limited according to each item's weight.
+impl<I, T, C> Limited for Weighted<I, C>
+where
+    I: Iterator<Item = (T, usize)>,
+    C: IntoIterator<Item = (T, usize)> + Default,
+{
+    type Contd = C;
+
+    fn contd() -> Self::Contd {
+        C::default()
+    }
+
+    /// returns the weight of an item.
+    fn element_size((_, weight): &(T, usize)) -> usize {
+        *weight
+    }
+}
+
+impl<I, C> Iterator for Weighted<I, C>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { iter, .. } = self;
+
+        iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Self { iter, .. } = self;
+
+        iter.size_hint()
+    }
+}
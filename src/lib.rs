@@ -36,3 +36,70 @@ pub mod iter;
 /// see [`Limited`][self::str::Limited] for more information.
 #[cfg(feature = "str")]
 pub mod str;
+
+/// byte slice limiting.
+///
+/// see [`Limited`][self::bytes::Limited] for more information. if the `bytes` feature is enabled,
+/// shared buffers can be limited without copying, see `Shared`.
+#[cfg(feature = "str")]
+pub mod bytes;
+
+/// C api bindings.
+///
+/// the library is also built as a `cdylib` and a `staticlib`, so that C callers can link
+/// against it. these functions are declared in the `include/shear.h` header, which is generated
+/// by `cbindgen` using `just header`.
+///
+/// see [`shear_trim_length()`][self::capi::shear_trim_length] and
+/// [`shear_trim_width()`][self::capi::shear_trim_width] for more information.
+#[cfg(feature = "capi")]
+pub mod capi;
+
+/// [`serde`] deserialization facilities.
+///
+/// see [`Deserializer`][self::de::Deserializer] for more information.
+#[cfg(feature = "serde")]
+pub mod de;
+
+/// [`std::fmt`] facilities.
+///
+/// see [`trim_display()`][self::fmt::trim_display] for more information.
+#[cfg(feature = "str")]
+pub mod fmt;
+
+/// [`std::io`] facilities.
+///
+/// see [`Tee`][self::io::Tee] and [`trim_lines()`][self::io::trim_lines] for more information.
+#[cfg(feature = "str")]
+pub mod io;
+
+/// measurement of strings.
+///
+/// see [`display_width()`][self::measure::display_width] for more information.
+#[cfg(feature = "str")]
+pub mod measure;
+
+/// pagination of strings.
+///
+/// see [`Pages`][self::page::Pages] for more information.
+#[cfg(feature = "str")]
+pub mod page;
+
+/// shortening of paths.
+///
+/// see [`trim_to_width()`][self::path::trim_to_width] for more information.
+#[cfg(feature = "str")]
+pub mod path;
+
+/// tables of limited cells.
+///
+/// see [`Table`][self::table::Table] for more information.
+#[cfg(feature = "str")]
+pub mod table;
+
+/// javascript bindings.
+///
+/// see [`trim_to_length()`][self::wasm::trim_to_length] and
+/// [`trim_to_width()`][self::wasm::trim_to_width] for more information.
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -0,0 +1,74 @@
+use crate::str::Lines;
+
+/// the version of unicode used to measure the width of characters.
+///
+/// if the `pinned-width` feature is enabled, this is always `(15, 1, 0)`. otherwise, this is the
+/// version of unicode implemented by the `unicode-width` crate.
+///
+/// see [`OUTPUT_VERSION`][crate::str::OUTPUT_VERSION] for more information.
+pub const UNICODE_VERSION: (u8, u8, u8) = crate::str::width::UNICODE_VERSION;
+
+/// returns the visual width of a string, in columns.
+///
+/// this is measured in the same way as [`Limited::trim_to_width()`][crate::str::Limited::trim_to_width],
+/// which sums the unicode width of each character. control characters are zero columns wide.
+///
+/// ```
+/// use shear::measure::display_width;
+///
+/// assert_eq!(display_width("abc"), 3);
+/// assert_eq!(display_width("ａｂｃ"), 6);
+/// assert_eq!(display_width("e\u{301}"), 1);
+/// ```
+pub fn display_width(value: &str) -> usize {
+    crate::str::width::str_width(value)
+}
+
+/// returns the height of a string, in lines.
+///
+/// this is measured in the same way as [`Limited::trim_to_height()`][crate::str::Limited::trim_to_height].
+/// see [`Lines`] for more information.
+///
+/// ```
+/// use shear::measure::height;
+///
+/// assert_eq!(height(""), 0);
+/// assert_eq!(height("one\ntwo\n"), 2);
+/// assert_eq!(height("one\r\ntwo\u{2028}three"), 3);
+/// ```
+pub fn height(value: &str) -> usize {
+    Lines::new(value).count()
+}
+
+/// returns the length of a string, in utf-16 code units.
+///
+/// this is the length of the string in e.g. javascript, where `String.prototype.length` counts
+/// utf-16 code units.
+///
+/// ```
+/// use shear::measure::utf16_len;
+///
+/// assert_eq!(utf16_len("abc"), 3);
+/// assert_eq!(utf16_len("🦀"), 2);
+/// ```
+pub fn utf16_len(value: &str) -> usize {
+    value.chars().map(char::len_utf16).sum()
+}
+
+/// returns the number of extended grapheme clusters in a string.
+///
+/// this is measured in the same way as [`Limited::trim_to_graphemes()`][crate::str::Limited::trim_to_graphemes].
+/// this requires the `unicode-segmentation` feature.
+///
+/// ```
+/// use shear::measure::grapheme_count;
+///
+/// assert_eq!(grapheme_count("abc"), 3);
+/// assert_eq!(grapheme_count("👍🏽e\u{301}"), 2);
+/// ```
+#[cfg(feature = "unicode-segmentation")]
+pub fn grapheme_count(value: &str) -> usize {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    value.graphemes(true).count()
+}
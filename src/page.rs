@@ -0,0 +1,155 @@
+use {
+    crate::str::{Ellipsis, Limited, Lines},
+    std::marker::PhantomData,
+};
+
+/// an iterator over the pages of a string.
+///
+/// each page is at most `height` lines tall. if a width is given using
+/// [`Pages::width()`], each line is limited to that many columns. if a footer is enabled using
+/// [`Pages::footer()`], the last line of each page is a `"— page i of n —"` footer.
+///
+/// # examples
+///
+/// ```
+/// use shear::{page::Pages, str::ellipsis::Ascii};
+///
+/// let text = "one\ntwo\nthree\nfour\nfive";
+/// let pages = Pages::<Ascii>::new(text, 2).collect::<Vec<_>>();
+///
+/// assert_eq!(pages, ["one\ntwo", "three\nfour", "five"]);
+/// ```
+///
+/// ```
+/// use shear::{page::Pages, str::ellipsis::Ascii};
+///
+/// let text = "one\ntwo\nthree\nfour\nfive";
+/// let pages = Pages::<Ascii>::new(text, 3).footer().collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     pages,
+///     [
+///         "one\ntwo\n— page 1 of 3 —",
+///         "three\nfour\n— page 2 of 3 —",
+///         "five\n— page 3 of 3 —",
+///     ]
+/// );
+/// ```
+pub struct Pages<'a, E> {
+    /// the remaining lines of the text.
+    lines: Lines<'a>,
+    /// the number of lines of text in the string.
+    total: usize,
+    /// the maximum number of lines in a page.
+    height: usize,
+    /// the maximum width of a line, if any.
+    width: Option<usize>,
+    /// whether each page should end with a footer.
+    footer: bool,
+    /// the number of pages yielded so far.
+    page: usize,
+    ellipsis: PhantomData<E>,
+}
+
+// === impl pages ===
+
+impl<'a, E: Ellipsis> Pages<'a, E> {
+    /// returns a new [`Pages`] iterator, splitting `text` into pages of `height` lines.
+    ///
+    /// a height of zero has no room for any lines, so no pages are yielded.
+    pub fn new(text: &'a str, height: usize) -> Self {
+        Self {
+            lines: Lines::new(text),
+            total: Lines::new(text).count(),
+            height,
+            width: None,
+            footer: false,
+            page: 0,
+            ellipsis: PhantomData,
+        }
+    }
+
+    /// limits each line of a page to `width` columns, including the footer.
+    ///
+    /// see [`Limited::trim_to_width()`] for more information.
+    pub fn width(self, width: usize) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
+    }
+
+    /// ends each page with a `"— page i of n —"` footer.
+    ///
+    /// the footer counts towards the height of each page. if the height only has room for the
+    /// footer, each page holds one line of text followed by the footer, so that every line is
+    /// shown.
+    pub fn footer(self) -> Self {
+        Self {
+            footer: true,
+            ..self
+        }
+    }
+
+    /// returns the total number of pages.
+    pub fn count_pages(&self) -> usize {
+        match self.lines_per_page() {
+            0 => 0,
+            lines => self.total.div_ceil(lines),
+        }
+    }
+
+    /// the number of lines of text on each page.
+    ///
+    /// each page holds at least one line of text, even if there is only room for the footer.
+    /// a height of zero holds no lines.
+    fn lines_per_page(&self) -> usize {
+        match self.height {
+            0 => 0,
+            height => height.saturating_sub(usize::from(self.footer)).max(1),
+        }
+    }
+
+    /// pushes a line onto the page, limiting it to the width if needed.
+    ///
+    /// `first` indicates whether this is the first line of the page. lines may be empty, so this
+    /// cannot be determined by whether the page is empty.
+    fn push_line(&self, page: &mut String, line: &str, first: bool) {
+        if !first {
+            page.push('\n');
+        }
+        match self.width {
+            Some(width) => page.push_str(&line.trim_to_width::<E>(width)),
+            None => page.push_str(line),
+        }
+    }
+}
+
+impl<E: Ellipsis> Iterator for Pages<'_, E> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut page = String::new();
+        let mut lines = 0;
+
+        while lines < self.lines_per_page() {
+            let Some(line) = self.lines.next() else {
+                break;
+            };
+            self.push_line(&mut page, line, lines == 0);
+            lines += 1;
+        }
+
+        if lines == 0 {
+            return None; // there are no more lines.
+        }
+
+        self.page += 1;
+        if self.footer {
+            let footer = format!("— page {} of {} —", self.page, self.count_pages());
+            self.push_line(&mut page, &footer, false);
+        }
+
+        Some(page)
+    }
+}
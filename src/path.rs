@@ -0,0 +1,181 @@
+use {
+    crate::str::{width::str_width, Ellipsis, Limited},
+    std::path::{is_separator, Path},
+};
+
+/// returns a path, shortened to fit within `length` bytes.
+///
+/// see [`trim_to_width()`] for more information.
+///
+/// ```
+/// use shear::{path, str::ellipsis::Ascii};
+///
+/// let p = "/home/katelyn/src/shear/src/lib.rs";
+///
+/// assert_eq!(path::trim_to_length::<Ascii>(p, 28), "/home/.../shear/src/lib.rs");
+/// ```
+pub fn trim_to_length<E: Ellipsis>(path: impl AsRef<Path>, length: usize) -> String {
+    let path = path.as_ref().to_string_lossy();
+    shorten::<E>(&path, length, str::len, |path| {
+        path.trim_to_length_keep_end::<E>(length)
+    })
+}
+
+/// returns a path, shortened to fit within `width` columns.
+///
+/// components are elided from the middle of the path, keeping its first and last components,
+/// so that both where the path begins and what it names remain visible. as many of the last
+/// components are kept as will fit, and the elided components are replaced by a single
+/// [`Ellipsis`]. the separators of the path are preserved.
+///
+/// if the first and last components do not fit alongside the ellipsis, the end of the path is
+/// kept, as if by [`trim_to_width_keep_end()`][Limited::trim_to_width_keep_end]. paths that are
+/// not valid unicode are converted lossily, see [`Path::to_string_lossy()`].
+///
+/// # examples
+///
+/// ```
+/// use shear::{path, str::ellipsis::Horizontal};
+///
+/// let p = "/home/katelyn/src/shear/src/lib.rs";
+///
+/// assert_eq!(path::trim_to_width::<Horizontal>(p, 40), p);
+/// assert_eq!(path::trim_to_width::<Horizontal>(p, 24), "/home/…/shear/src/lib.rs");
+/// assert_eq!(path::trim_to_width::<Horizontal>(p, 16), "/home/…/lib.rs");
+/// assert_eq!(path::trim_to_width::<Horizontal>(p, 8), "…/lib.rs");
+/// ```
+pub fn trim_to_width<E: Ellipsis>(path: impl AsRef<Path>, width: usize) -> String {
+    let path = path.as_ref().to_string_lossy();
+    shorten::<E>(&path, width, str_width, |path| {
+        path.trim_to_width_keep_end::<E>(width)
+    })
+}
+
+/// returns a file name, trimmed to fit within `length` bytes while keeping its extension.
+///
+/// see [`trim_file_name_to_width()`] for more information.
+///
+/// ```
+/// use shear::{path, str::ellipsis::Ascii};
+///
+/// assert_eq!(path::trim_file_name_to_length::<Ascii>("quarterly-report.pdf", 14), "quarter....pdf");
+/// ```
+pub fn trim_file_name_to_length<E: Ellipsis>(name: &str, length: usize) -> String {
+    trim_file_name::<E>(name, length, str::len, |stem, length| {
+        stem.trim_to_length::<E>(length)
+    })
+}
+
+/// returns a file name, trimmed to fit within `width` columns while keeping its extension.
+///
+/// the stem of the file name is trimmed, and the [`Ellipsis`] is placed before the extension,
+/// so that the type of the file remains visible. the extension is the text following the last
+/// `.` in the name, as [`Path::extension()`] finds it, so `.bashrc` has no extension and
+/// `archive.tar.gz` has the extension `gz`.
+///
+/// names without an extension, or whose extension does not fit alongside the ellipsis and at
+/// least one character of the stem, are trimmed as if by
+/// [`trim_to_width()`][Limited::trim_to_width].
+///
+/// # examples
+///
+/// ```
+/// use shear::{path, str::ellipsis::Horizontal};
+///
+/// let name = "extremely-long-report-name-2024.pdf";
+///
+/// assert_eq!(path::trim_file_name_to_width::<Horizontal>(name, 40), name);
+/// assert_eq!(path::trim_file_name_to_width::<Horizontal>(name, 20), "extremely-long-….pdf");
+/// assert_eq!(path::trim_file_name_to_width::<Horizontal>(".bashrc_local", 6), ".bash…");
+/// ```
+pub fn trim_file_name_to_width<E: Ellipsis>(name: &str, width: usize) -> String {
+    trim_file_name::<E>(name, width, str_width, |stem, width| {
+        stem.trim_to_width::<E>(width)
+    })
+}
+
+/// trims a file name to fit within `budget`, keeping its extension.
+///
+/// `measure` returns the size of a string, and `trim` limits a string to a budget.
+fn trim_file_name<E: Ellipsis>(
+    name: &str,
+    budget: usize,
+    measure: impl Fn(&str) -> usize,
+    trim: impl Fn(&str, usize) -> String,
+) -> String {
+    if measure(name) <= budget {
+        return name.to_owned();
+    }
+
+    // find the extension, including its leading `.`, ignoring the leading `.` of a hidden file.
+    let (stem, extension) = match name.rfind('.') {
+        Some(i) if i > 0 => name.split_at(i),
+        _ => return trim(name, budget),
+    };
+
+    // leave room for the extension, and at least one character of the stem.
+    let room = budget.saturating_sub(measure(extension));
+    let first = stem
+        .chars()
+        .next()
+        .map_or(0, |c| measure(&name[..c.len_utf8()]));
+    if room < measure(E::ellipsis()) + first {
+        return trim(name, budget);
+    }
+
+    trim(stem, room) + extension
+}
+
+/// shortens `path` to fit within `budget`, eliding components from its middle.
+///
+/// `measure` returns the size of a string, and `fallback` limits a path whose first and last
+/// components do not fit.
+fn shorten<E: Ellipsis>(
+    path: &str,
+    budget: usize,
+    measure: impl Fn(&str) -> usize,
+    fallback: impl FnOnce(&str) -> String,
+) -> String {
+    if measure(path) <= budget {
+        return path.to_owned();
+    }
+
+    // find the components of the path, ignoring empty components between repeated separators.
+    let components = components(path);
+    let [(_, head), _, _, ..] = components[..] else {
+        return fallback(path); // there are no components in the middle to elide.
+    };
+
+    let (prefix, rest) = path.split_at(head);
+    let separator = rest.chars().next().map_or("/", |c| &rest[..c.len_utf8()]);
+
+    // keep as many of the last components as will fit, eliding at least one component.
+    for &(start, _) in &components[2..] {
+        let shortened = [prefix, separator, E::ellipsis(), separator, &path[start..]].concat();
+        if measure(&shortened) <= budget {
+            return shortened;
+        }
+    }
+
+    fallback(path)
+}
+
+/// returns the byte ranges of each non-empty component of `path`.
+fn components(path: &str) -> Vec<(usize, usize)> {
+    let mut components = Vec::new();
+    let mut start = 0;
+
+    for (i, c) in path.char_indices() {
+        if is_separator(c) {
+            if i > start {
+                components.push((start, i));
+            }
+            start = i + c.len_utf8();
+        }
+    }
+    if path.len() > start {
+        components.push((start, path.len()));
+    }
+
+    components
+}
@@ -1,4 +1,12 @@
-pub use self::ellipsis::Ellipsis;
+use std::{
+    borrow::Cow,
+    ops::{Deref, RangeInclusive},
+};
+
+pub use self::{
+    ellipsis::{DynamicEllipsis, Ellipsis, Marker},
+    split::{SplitToLength, SplitToWidth},
+};
 
 #[cfg(doc)]
 use self::ellipsis::{Ascii, Contd, Horizontal};
@@ -11,21 +19,144 @@ use self::ellipsis::{Ascii, Contd, Horizontal};
 /// see [`Limited`] for more information.
 pub mod ellipsis;
 
+/// units by which strings can be limited.
+///
+/// see [`Units`][units::Units] for more information.
+pub mod units;
+
+/// chains of fallback trimming strategies.
+///
+/// see [`Fallback`][fallback::Fallback] for more information.
+pub mod fallback;
+
+/// progressive shortening of strings.
+///
+/// see [`Shorten`][shorten::Shorten] for more information.
+pub mod shorten;
+
+/// bidirectional text facilities.
+///
+/// see [`Limited::trim_to_width_bidi()`] for more information.
+pub mod bidi;
+
+/// joining of limited segments.
+///
+/// see [`join()`][join::join] for more information.
+pub mod join;
+
+/// limiting of strings by height, with a count of the omitted lines.
+///
+/// see [`Counted`][counted::Counted] for more information.
+pub mod counted;
+
+/// limiting of unified diffs by height.
+///
+/// see [`trim_to_height()`][diff::trim_to_height] for more information.
+pub mod diff;
+
+/// limiting of source code by height, with line numbers.
+///
+/// see [`Snippet`][snippet::Snippet] for more information.
+pub mod snippet;
+
+/// shortening of urls.
+///
+/// see [`trim_to_width()`][url::trim_to_width] for more information.
+pub mod url;
+
+/// limiting of characters, along with their byte offsets.
+///
+/// see [`LengthIndices`][indices::LengthIndices] for more information.
+pub mod indices;
+
+/// limiting of possibly invalid utf-8.
+///
+/// see [`trim_to_length()`][lossy::trim_to_length] for more information.
+pub mod lossy;
+
+/// unicode normalization of strings.
+///
+/// see [`Form`][normalize::Form] for more information.
+#[cfg(feature = "unicode-normalization")]
+pub mod normalize;
+
+/// resumable limiting of strings.
+///
+/// see [`Token`][resume::Token] for more information.
+pub mod resume;
+
+#[cfg(feature = "ansi")]
+mod ansi;
+/// budget accounting audits.
+///
+/// if the `audit` feature is enabled, trimming methods check that their output satisfies the
+/// budget they were given, and panic with a diagnostic if it does not.
+#[cfg(feature = "audit")]
+mod audit;
+mod brackets;
+mod dynamic;
+mod fit;
+mod inspect;
+mod limits;
+mod measured;
+mod orphans;
+mod placement;
+mod report;
+mod scroll;
+pub(crate) mod split;
+mod trim_point;
+#[cfg(feature = "unicode-segmentation")]
+mod trim_to_graphemes;
+mod trim_to_height;
 mod trim_to_length;
 mod trim_to_width;
+mod trimmed;
+/// measurement of visual width.
+///
+/// every facility in this crate measures width using these functions, so that each agrees with
+/// the others. if the `pinned-width` feature is enabled, widths are read from a pinned table,
+/// rather than from the `unicode-width` crate.
+pub(crate) mod width;
+mod within;
+mod word;
+mod zero_width;
+
+pub use self::{
+    fit::Align,
+    inspect::Kept,
+    limits::Limits,
+    measured::MeasuredStr,
+    report::TrimReport,
+    trim_to_height::{Lines, Terminators},
+    trimmed::Trimmed,
+};
+
+/// the version of this crate's trimming output.
+///
+/// for a given input, budget, and [`Ellipsis`], the output of each method of [`Limited`] is the
+/// same on every platform. this version is incremented whenever a release changes the output of
+/// any method, so that e.g. snapshot tests containing trimmed output can detect why they changed.
+/// the changes made in each version are listed in the changelog.
+///
+/// widths are measured using the `unicode-width` crate, whose tables may change as new versions
+/// of unicode are released. enable the `pinned-width` feature to measure widths using a table
+/// that is pinned to [`UNICODE_VERSION`][crate::measure::UNICODE_VERSION] instead, so that output
+/// does not change when dependencies are updated.
+pub const OUTPUT_VERSION: u32 = 3;
 
 /// a trait for limiting strings.
 ///
 /// use [`trim_to_length()`][Limited::trim_to_length] to limit a string based on its length in
 /// bytes. use [`trim_to_width()`][Limited::trim_to_width] to limit a string based on its visual
-/// unicode width.
+/// unicode width. use [`trim_to_height()`][Limited::trim_to_height] to limit a string based on
+/// its number of lines.
 ///
 /// # ellipses
 ///
 /// this trait allows for callers to specify the [`Ellipsis`] that is used to truncate a string.
 /// an [`Ascii`] ellipsis `"..."`, a [`Horizontal`] unicode ellipsis `"…"`, and a
-/// verbose [`Contd`] ellipsis `"... (contd.)"` are provided, but you may provide your own ellipsis
-/// to suit your own needs.
+/// verbose [`Contd`] ellipsis `"... (contd.)"` are provided, along with a handful of other styles
+/// in the [`ellipsis`] module, but you may provide your own ellipsis to suit your own needs.
 ///
 /// # examples
 ///
@@ -71,48 +202,1970 @@ pub trait Limited {
     fn trim_to_length<E: Ellipsis>(&self, length: usize) -> String;
 
     /// returns a string limited by width.
+    ///
+    /// strings that fit within the width are returned unaltered, even if the width only has room
+    /// for the [`Ellipsis`].
     fn trim_to_width<E: Ellipsis>(&self, length: usize) -> String;
+
+    /// returns a string containing ansi escape sequences, limited by width.
+    ///
+    /// this behaves like [`trim_to_width()`][Limited::trim_to_width], but ansi escape sequences,
+    /// e.g. the control sequences that color text in a terminal, are treated as zero columns wide
+    /// and are never cut. escape sequences in the part of the string that is cut are kept after
+    /// the [`Ellipsis`], so that e.g. a trailing reset still applies.
+    ///
+    /// this requires the `ansi` feature.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "\x1b[31merror:\x1b[0m something went wrong";
+    /// let limited = s.trim_to_width_ansi::<ellipsis::Ascii>(12);
+    ///
+    /// assert_eq!(limited, "\x1b[31merror:\x1b[0m so...");
+    /// ```
+    #[cfg(feature = "ansi")]
+    fn trim_to_width_ansi<E: Ellipsis>(&self, width: usize) -> String;
+
+    /// returns a string whose content is limited by length, followed by an ellipsis.
+    ///
+    /// unlike [`trim_to_length()`][Limited::trim_to_length], the ellipsis does not count towards
+    /// the budget. if the string is longer than `length` bytes, it is cut at the last character
+    /// boundary that fits, and the [`Ellipsis`] is appended on top. this is useful for layouts
+    /// that reserve room for the ellipsis separately.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis::Ascii, Limited};
+    ///
+    /// let s = "a very long string value";
+    ///
+    /// assert_eq!(s.trim_content_to_length::<Ascii>(10), "a very lon...");
+    /// assert_eq!(s.trim_content_to_length::<Ascii>(24), s);
+    /// ```
+    fn trim_content_to_length<E: Ellipsis>(&self, length: usize) -> String;
+
+    /// returns a string whose content is limited by width, followed by an ellipsis.
+    ///
+    /// a character is never separated from the zero-width characters, such as combining marks,
+    /// that follow it. see [`trim_content_to_length()`][Limited::trim_content_to_length] for more
+    /// information.
+    fn trim_content_to_width<E: Ellipsis>(&self, width: usize) -> String;
+
+    /// returns a string limited by length, and whether it was truncated.
+    ///
+    /// this behaves like [`trim_to_length()`][Limited::trim_to_length], but also reports whether
+    /// the string was shortened, e.g. so that a caller can offer a "show more" affordance.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "a very long string value";
+    ///
+    /// assert_eq!(s.trim_to_length_checked::<ellipsis::Ascii>(10), ("a very ...".to_owned(), true));
+    /// assert_eq!(s.trim_to_length_checked::<ellipsis::Ascii>(32), (s.to_owned(), false));
+    /// ```
+    fn trim_to_length_checked<E: Ellipsis>(&self, length: usize) -> (String, bool);
+
+    /// returns a string limited by width, and whether it was truncated.
+    ///
+    /// see [`trim_to_length_checked()`][Limited::trim_to_length_checked] for more information.
+    fn trim_to_width_checked<E: Ellipsis>(&self, width: usize) -> (String, bool);
+
+    /// returns a string limited by height, and whether it was truncated.
+    ///
+    /// see [`trim_to_length_checked()`][Limited::trim_to_length_checked] for more information.
+    fn trim_to_height_checked<E: Ellipsis>(&self, height: usize) -> (String, bool);
+
+    /// returns a string limited by length, calling `f` for each kept character.
+    ///
+    /// this behaves like [`trim_to_length()`][Limited::trim_to_length], but each kept character
+    /// is reported to `f` as a [`Kept`] unit, along with its byte offset, running position, and
+    /// size. this allows parallel data (e.g. style runs, or cell maps) to be built while the
+    /// string is limited, rather than by walking the output afterwards. characters of the
+    /// [`Ellipsis`] are not reported.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis::Ascii, Limited};
+    ///
+    /// let mut offsets = Vec::new();
+    /// let limited = "héllo world".trim_to_length_inspect::<Ascii>(8, |kept| {
+    ///     offsets.push((kept.unit, kept.offset, kept.position, kept.size));
+    /// });
+    ///
+    /// assert_eq!(limited, "héll...");
+    /// assert_eq!(offsets[1], ("é", 1, 1, 2));
+    /// assert_eq!(offsets[2], ("l", 3, 3, 1));
+    /// ```
+    fn trim_to_length_inspect<'a, E: Ellipsis>(
+        &'a self,
+        length: usize,
+        f: impl FnMut(Kept<'a>),
+    ) -> String;
+
+    /// returns a string limited by width, calling `f` for each kept character.
+    ///
+    /// see [`trim_to_length_inspect()`][Limited::trim_to_length_inspect] for more information.
+    fn trim_to_width_inspect<'a, E: Ellipsis>(
+        &'a self,
+        width: usize,
+        f: impl FnMut(Kept<'a>),
+    ) -> String;
+
+    /// returns a string limited by height, calling `f` for each kept line.
+    ///
+    /// see [`trim_to_length_inspect()`][Limited::trim_to_length_inspect] for more information.
+    fn trim_to_height_inspect<'a, E: Ellipsis>(
+        &'a self,
+        height: usize,
+        f: impl FnMut(Kept<'a>),
+    ) -> String;
+
+    /// returns the byte offset at which a string would be cut to fit within `length` bytes.
+    ///
+    /// this returns `None` if the string fits. otherwise, it returns the offset at which
+    /// [`trim_to_length()`][Limited::trim_to_length] would cut the string, before appending an
+    /// [`Ellipsis`]. this does not allocate, so callers can slice the original string themselves,
+    /// or highlight the point of truncation.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis::Ascii, Limited};
+    ///
+    /// let s = "a very long string value";
+    ///
+    /// assert_eq!(s.trim_point_for_length::<Ascii>(10), Some(7));
+    /// assert_eq!(s.trim_point_for_length::<Ascii>(32), None);
+    ///
+    /// let cut = s.trim_point_for_length::<Ascii>(10).unwrap_or(s.len());
+    /// assert_eq!(&s[..cut], "a very ");
+    /// ```
+    fn trim_point_for_length<E: Ellipsis>(&self, length: usize) -> Option<usize>;
+
+    /// returns the byte offset at which a string would be cut to fit within `width` columns.
+    ///
+    /// see [`trim_point_for_length()`][Limited::trim_point_for_length] for more information.
+    fn trim_point_for_width<E: Ellipsis>(&self, width: usize) -> Option<usize>;
+
+    /// returns a string limited by length, along with a report of what was discarded.
+    ///
+    /// the [`TrimReport`] holds the number of bytes, characters, and lines that were dropped, and
+    /// the width of the limited string. this can be used to display notices like "(1.2 KiB
+    /// omitted)".
+    ///
+    /// see [`TrimReport`] for an example.
+    fn trim_to_length_report<E: Ellipsis>(&self, length: usize) -> TrimReport;
+
+    /// returns a string limited by width, along with a report of what was discarded.
+    ///
+    /// see [`trim_to_length_report()`][Limited::trim_to_length_report] for more information.
+    fn trim_to_width_report<E: Ellipsis>(&self, width: usize) -> TrimReport;
+
+    /// returns a string limited by height, along with a report of what was discarded.
+    ///
+    /// the line breaks preceding the first discarded line are not counted as discarded.
+    ///
+    /// see [`trim_to_length_report()`][Limited::trim_to_length_report] for more information.
+    fn trim_to_height_report<E: Ellipsis>(&self, height: usize) -> TrimReport;
+
+    /// returns a string with invisible zero-width characters removed.
+    ///
+    /// this removes zero width spaces, word joiners, and byte order marks. zero width joiners and
+    /// non-joiners are removed unless they sit between two visible characters, where they affect
+    /// how those characters are displayed, e.g. in an emoji sequence like `"👩‍💻"`.
+    ///
+    /// trimming methods never leave one of these characters immediately before an [`Ellipsis`].
+    /// strip them beforehand if they should not count against the budget either.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "\u{FEFF}zero\u{200B}width\u{200D} space";
+    ///
+    /// assert_eq!(s.strip_zero_width(), "zerowidth space");
+    /// assert_eq!(s.trim_to_length::<ellipsis::Ascii>(13), "\u{FEFF}zero...");
+    /// ```
+    fn strip_zero_width(&self) -> Cow<'_, str>;
+
+    /// returns a string in the given unicode normalization form.
+    ///
+    /// strings that look identical may be encoded differently, and would otherwise be measured
+    /// and truncated differently. normalize a string before limiting it so that they are not. the
+    /// string is borrowed if it is already normalized.
+    ///
+    /// this requires the `unicode-normalization` feature.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, normalize::Form, Limited};
+    ///
+    /// let (composed, decomposed) = ("caf\u{E9} au lait", "cafe\u{301} au lait");
+    ///
+    /// assert_eq!(composed.trim_to_length::<ellipsis::Ascii>(8), "caf\u{E9}...");
+    /// assert_eq!(decomposed.trim_to_length::<ellipsis::Ascii>(8), "caf...");
+    /// assert_eq!(
+    ///     decomposed.normalized(Form::Nfc).trim_to_length::<ellipsis::Ascii>(8),
+    ///     "caf\u{E9}...",
+    /// );
+    /// ```
+    #[cfg(feature = "unicode-normalization")]
+    fn normalized(&self, form: normalize::Form) -> Cow<'_, str>;
+
+    /// returns a string limited by length, borrowing the string if it fits.
+    ///
+    /// this behaves like [`trim_to_length()`][Limited::trim_to_length], but does not allocate if
+    /// the string is no longer than `length` bytes.
+    ///
+    /// ```
+    /// use {shear::str::{ellipsis, Limited}, std::borrow::Cow};
+    ///
+    /// let s = "a string value";
+    ///
+    /// assert!(matches!(s.trim_to_length_cow::<ellipsis::Ascii>(16), Cow::Borrowed("a string value")));
+    /// assert!(matches!(s.trim_to_length_cow::<ellipsis::Ascii>(8), Cow::Owned(_)));
+    /// ```
+    fn trim_to_length_cow<E: Ellipsis>(&self, length: usize) -> Cow<'_, str>;
+
+    /// returns a string limited by width, borrowing the string if it fits.
+    ///
+    /// this behaves like [`trim_to_width()`][Limited::trim_to_width], but does not allocate if
+    /// the string is no wider than `width` columns.
+    fn trim_to_width_cow<E: Ellipsis>(&self, width: usize) -> Cow<'_, str>;
+
+    /// returns a string limited by height.
+    ///
+    /// if the string has more than `height` lines, the last line is replaced by the [`Ellipsis`].
+    ///
+    /// `\n`, `\r\n`, U+0085 NEXT LINE, U+2028 LINE SEPARATOR, and U+2029 PARAGRAPH SEPARATOR
+    /// are all recognized as line breaks. lines are joined with `\r\n` in the returned string if
+    /// the first line break is `\r\n`, so that windows line endings are preserved. otherwise,
+    /// lines are joined with `\n`.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "first\nsecond\u{2028}third\nfourth";
+    /// let limited = s.trim_to_height::<ellipsis::Ascii>(3);
+    ///
+    /// assert_eq!(limited, "first\nsecond\n...");
+    ///
+    /// let s = "first\r\nsecond\r\nthird";
+    /// let limited = s.trim_to_height::<ellipsis::Ascii>(2);
+    ///
+    /// assert_eq!(limited, "first\r\n...");
+    /// ```
+    fn trim_to_height<E: Ellipsis>(&self, height: usize) -> String;
+
+    /// returns a string limited by height, recognizing the given line breaks.
+    ///
+    /// this behaves like [`trim_to_height()`][Limited::trim_to_height], but lines are split at
+    /// the given [`Terminators`]. for example, [`Terminators::Ascii`] splits lines as
+    /// [`str::lines()`] does, and [`Terminators::All`] also splits lines at a lone `\r`.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited, Terminators};
+    ///
+    /// let s = "one\rtwo\rthree";
+    ///
+    /// assert_eq!(s.trim_to_height_terminated::<ellipsis::Ascii>(2, Terminators::Unicode), s);
+    /// assert_eq!(s.trim_to_height_terminated::<ellipsis::Ascii>(2, Terminators::All), "one\n...");
+    /// ```
+    fn trim_to_height_terminated<E: Ellipsis>(
+        &self,
+        height: usize,
+        terminators: Terminators,
+    ) -> String;
+
+    /// returns a string limited by its number of characters.
+    ///
+    /// this counts unicode scalar values, i.e. [`char`]s, rather than bytes or columns. this is
+    /// useful for limits specified in characters, such as those of form fields or text messages.
+    /// the [`Ellipsis`] is also measured in characters.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "crème brûlée, s'il vous plaît";
+    /// let limited = s.trim_to_chars::<ellipsis::Horizontal>(12);
+    ///
+    /// assert_eq!(limited, "crème brûlé…");
+    /// assert_eq!(limited.chars().count(), 12);
+    /// ```
+    fn trim_to_chars<E: Ellipsis>(&self, count: usize) -> String;
+
+    /// returns a string limited by its length in utf-16 code units.
+    ///
+    /// javascript, java, windows, and many databases measure strings in utf-16 code units. a
+    /// character outside of the basic multilingual plane, such as most emoji, is two code units
+    /// long, and is never split. the [`Ellipsis`] is also measured in utf-16 code units.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "🦀 rust 🦀";
+    /// let limited = s.trim_to_utf16_units::<ellipsis::Ascii>(8);
+    ///
+    /// assert_eq!(limited, "🦀 ru...");
+    /// assert_eq!(limited.encode_utf16().count(), 8);
+    /// ```
+    fn trim_to_utf16_units<E: Ellipsis>(&self, count: usize) -> String;
+
+    /// returns a string limited by its number of extended grapheme clusters.
+    ///
+    /// a grapheme cluster is what a reader perceives as a single character, e.g. an emoji built
+    /// from several code points, or a letter followed by combining marks. these are never split.
+    /// the [`Ellipsis`] is also measured in grapheme clusters.
+    ///
+    /// this requires the `unicode-segmentation` feature.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "👩‍👩‍👧‍👦 family, 👍🏽 thumbs up";
+    /// let limited = s.trim_to_graphemes::<ellipsis::Horizontal>(5);
+    ///
+    /// assert_eq!(limited, "👩‍👩‍👧‍👦 fa…");
+    /// ```
+    #[cfg(feature = "unicode-segmentation")]
+    fn trim_to_graphemes<E: Ellipsis>(&self, count: usize) -> String;
+
+    /// returns a string limited by length, cut at a grapheme cluster boundary.
+    ///
+    /// this behaves like [`trim_to_length()`][Limited::trim_to_length], but the cut is moved back
+    /// to the nearest extended grapheme cluster boundary. both keep combining marks and
+    /// zero-width joiner sequences intact, but this also follows the rest of the unicode
+    /// segmentation rules, e.g. keeping the regional indicators of a flag together.
+    ///
+    /// this requires the `unicode-segmentation` feature.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "ab🇯🇵cd";
+    ///
+    /// assert_eq!(s.trim_to_length::<ellipsis::Ascii>(9), "ab🇯...");
+    /// assert_eq!(s.trim_to_length_at_grapheme::<ellipsis::Ascii>(9), "ab...");
+    /// ```
+    #[cfg(feature = "unicode-segmentation")]
+    fn trim_to_length_at_grapheme<E: Ellipsis>(&self, length: usize) -> String;
+
+    /// returns a string limited by height, appending the ellipsis to the last line.
+    ///
+    /// unlike [`trim_to_height()`][Limited::trim_to_height], the [`Ellipsis`] does not consume a
+    /// line of its own. if the string has more than `height` lines, the first `height` lines are
+    /// kept, and the ellipsis is appended to the end of the last one.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "first\nsecond\nthird";
+    /// let limited = s.trim_to_height_inline::<ellipsis::Horizontal>(2);
+    ///
+    /// assert_eq!(limited, "first\nsecond…");
+    /// ```
+    fn trim_to_height_inline<E: Ellipsis>(&self, height: usize) -> String;
+
+    /// returns a string limited to a box of `width` columns and `height` lines.
+    ///
+    /// each line is limited by width, as if by [`trim_to_width()`][Limited::trim_to_width], and
+    /// the string is limited by height, as if by [`trim_to_height()`][Limited::trim_to_height].
+    /// this produces text that fits a rectangular region, e.g. a panel or a tooltip.
+    ///
+    /// see [`Limits`] to limit the length of the string as well.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis::Horizontal, Limited};
+    ///
+    /// let s = "a very long first line\nshort\nthird\nfourth";
+    ///
+    /// assert_eq!(s.trim_to_box::<Horizontal>(8, 3), "a very …\nshort\n…");
+    /// ```
+    fn trim_to_box<E: Ellipsis>(&self, width: usize, height: usize) -> String;
+
+    /// returns a string limited by height, where each line's height is given by `weight`.
+    ///
+    /// this behaves like [`trim_to_height()`][Limited::trim_to_height], but lines may count for
+    /// more or less than one unit of height. for example, a line that will be wrapped onto several
+    /// rows may weigh more than one, and a header line that should not consume the budget may
+    /// weigh zero. the [`Ellipsis`] always weighs one.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "# header\none\ntwo\n# header\nthree\nfour";
+    /// let weight = |line: &str| usize::from(!line.starts_with('#'));
+    ///
+    /// let limited = s.trim_to_height_weighted::<ellipsis::Ascii>(3, weight);
+    /// assert_eq!(limited, "# header\none\ntwo\n# header\n...");
+    /// ```
+    fn trim_to_height_weighted<E: Ellipsis>(
+        &self,
+        height: usize,
+        weight: impl Fn(&str) -> usize,
+    ) -> String;
+
+    /// returns a string limited by height, counting the rows that each line wraps onto.
+    ///
+    /// this behaves like [`trim_to_height()`][Limited::trim_to_height], but each line counts for
+    /// as many rows as it occupies when wrapped at `width` columns, as
+    /// [`split_to_width()`][Limited::split_to_width] would wrap it. an empty line occupies one
+    /// row. this allows text to fit a region of a terminal, rather than a number of lines. lines
+    /// are not wrapped in the returned string.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "a line that wraps\nshort\nthird";
+    ///
+    /// assert_eq!(s.trim_to_height_wrapped::<ellipsis::Ascii>(3, 10), "a line that wraps\n...");
+    /// assert_eq!(s.trim_to_height_wrapped::<ellipsis::Ascii>(4, 10), s);
+    /// ```
+    fn trim_to_height_wrapped<E: Ellipsis>(&self, height: usize, width: usize) -> String;
+
+    /// returns a string limited by height, never eliding lines for which `keep` returns true.
+    ///
+    /// lines that are kept do not count towards the height. the budget is applied to the other
+    /// lines, as [`trim_to_height()`][Limited::trim_to_height] would apply it: if they do not
+    /// fit, the [`Ellipsis`] is placed on its own line, where the first line was elided.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let log = "INFO starting\nINFO ready\nERROR disk full\nINFO retrying\nERROR disk full";
+    /// let limited = log.trim_to_height_keeping::<ellipsis::Ascii>(2, |line| line.starts_with("ERROR"));
+    ///
+    /// assert_eq!(limited, "INFO starting\n...\nERROR disk full\nERROR disk full");
+    /// ```
+    fn trim_to_height_keeping<E: Ellipsis>(
+        &self,
+        height: usize,
+        keep: impl Fn(&str) -> bool,
+    ) -> String;
+
+    /// returns a string limited by height, cut at the end of a sentence if possible.
+    ///
+    /// this behaves like [`trim_to_height()`][Limited::trim_to_height], but if the height is
+    /// reached in the middle of a paragraph, the kept text backs up to the end of the previous
+    /// sentence, or to the previous paragraph break, before the [`Ellipsis`] is placed. if there
+    /// is no such boundary in the kept lines, they are kept as-is.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "fix the parser. it no longer panics\non empty input. also, add a\nregression test.\n";
+    /// let s = format!("{s}thanks to the reporter!");
+    /// let limited = s.trim_to_height_at_sentence::<ellipsis::Ascii>(3);
+    ///
+    /// assert_eq!(limited, "fix the parser. it no longer panics\non empty input.\n...");
+    /// ```
+    fn trim_to_height_at_sentence<E: Ellipsis>(&self, height: usize) -> String;
+
+    /// returns an iterator over segments of a string, each limited by width.
+    ///
+    /// this is the inverse of [`trim_to_width()`][Limited::trim_to_width]: rather than discarding
+    /// the contents that do not fit, the string is split into consecutive segments that are each
+    /// at most `width` columns wide. a character is never separated from the zero-width
+    /// characters, such as combining marks, that follow it.
+    ///
+    /// a character wider than `width` is yielded as a segment of its own.
+    ///
+    /// ```
+    /// use shear::str::Limited;
+    ///
+    /// let segments = "Ｈｅｌｌｏ, ｗｏｒｌｄ!".split_to_width(5).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(segments, ["Ｈｅ", "ｌｌ", "ｏ, ", "ｗｏ", "ｒｌ", "ｄ!"]);
+    /// ```
+    fn split_to_width(&self, width: usize) -> SplitToWidth<'_>;
+
+    /// returns a string limited by the given [`Units`][units::Units].
+    ///
+    /// the string is split into units, and limited to `size` according to the size of each
+    /// unit. every [`Units`][units::Units] type is also a [`Measure`][units::Measure], and this
+    /// returns the same string as [`trim_with()`][Limited::trim_with]. see [`units::Units`] for
+    /// more information.
+    fn trim_to_units<U: units::Units, E: Ellipsis>(&self, size: usize) -> String;
+
+    /// returns a string limited by the given [`Measure`][units::Measure].
+    ///
+    /// the string is split into units, and limited to `budget` according to the size of each
+    /// unit. unlike [`trim_to_units()`][Limited::trim_to_units], the measurement is a value, and
+    /// may carry state such as font metrics. see [`units::Measure`] for more information.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, units::{Chars, Height}, Limited};
+    ///
+    /// assert_eq!("crème brûlée".trim_with::<ellipsis::Ascii>(&Chars, 8), "crème...");
+    /// assert_eq!("one\ntwo\nthree".trim_with::<ellipsis::Ascii>(&Height, 2), "one\n...");
+    /// ```
+    fn trim_with<E: Ellipsis>(&self, measure: &impl units::Measure, budget: usize) -> String;
+
+    /// returns an iterator over pieces of a string, each limited by length.
+    ///
+    /// the string is split into consecutive pieces that are each at most `length` bytes long,
+    /// always at character boundaries. use [`SplitToLength::prefer_words()`] to split pieces
+    /// after whitespace where possible.
+    ///
+    /// a character longer than `length` is yielded as a piece of its own.
+    ///
+    /// ```
+    /// use shear::str::Limited;
+    ///
+    /// let s = "a very long string value";
+    ///
+    /// let pieces = s.split_to_length(10).collect::<Vec<_>>();
+    /// assert_eq!(pieces, ["a very lon", "g string v", "alue"]);
+    ///
+    /// let pieces = s.split_to_length(10).prefer_words().collect::<Vec<_>>();
+    /// assert_eq!(pieces, ["a very ", "long ", "string ", "value"]);
+    /// ```
+    fn split_to_length(&self, length: usize) -> SplitToLength<'_>;
+
+    /// returns a string trimmed or padded to exactly `width` columns.
+    ///
+    /// strings wider than `width` are limited using
+    /// [`trim_to_width()`][Limited::trim_to_width]. strings narrower than `width`, including
+    /// those that were trimmed at a wide character, are padded with spaces according to the given
+    /// [`Align`]ment. this is useful for laying out columns of text.
+    ///
+    /// if `width` is narrower than the [`Ellipsis`], a string that must be trimmed is replaced
+    /// by the ellipsis, and so may be wider than `width`.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis::Horizontal, Align, Limited};
+    ///
+    /// assert_eq!("shear".fit_to_width::<Horizontal>(8, Align::Left), "shear   ");
+    /// assert_eq!("shear".fit_to_width::<Horizontal>(8, Align::Right), "   shear");
+    /// assert_eq!("shear".fit_to_width::<Horizontal>(8, Align::Center), " shear  ");
+    /// assert_eq!("a library for trimming".fit_to_width::<Horizontal>(8, Align::Left), "a libra…");
+    /// ```
+    fn fit_to_width<E: Ellipsis>(&self, width: usize, align: Align) -> String;
+
+    /// splits a string into the prefix that fits within `length` bytes, and the overflow.
+    ///
+    /// the string is split at the last character boundary that fits. unlike
+    /// [`trim_to_length()`][Limited::trim_to_length], no [`Ellipsis`] is appended, and nothing is
+    /// discarded: the overflow can be used to show the rest of the string, e.g. in an "expand to
+    /// see more" view.
+    ///
+    /// ```
+    /// use shear::str::Limited;
+    ///
+    /// let s = "a very long string value";
+    ///
+    /// assert_eq!(s.split_at_length(10), ("a very lon", "g string value"));
+    /// assert_eq!(s.split_at_length(32), (s, ""));
+    /// ```
+    fn split_at_length(&self, length: usize) -> (&str, &str);
+
+    /// splits a string into the prefix that fits within `width` columns, and the overflow.
+    ///
+    /// a character is never separated from the zero-width characters, such as combining marks,
+    /// that follow it.
+    ///
+    /// see [`split_at_length()`][Limited::split_at_length] for more information.
+    ///
+    /// ```
+    /// use shear::str::Limited;
+    ///
+    /// assert_eq!("Ｈｅｌｌｏ!".split_at_width(5), ("Ｈｅ", "ｌｌｏ!"));
+    /// ```
+    fn split_at_width(&self, width: usize) -> (&str, &str);
+
+    /// returns a window of a string that is `width` columns wide, starting at column `offset`.
+    ///
+    /// this can be used to horizontally scroll through a long line, e.g. in a table cell or an
+    /// input field. if there are contents out of view to the left or right of the window, that
+    /// edge of the window is replaced with the [`Ellipsis`].
+    ///
+    /// the offset is clamped so that the window does not scroll past the end of the string, and
+    /// the clamped offset is returned alongside the window. a wide character that is only
+    /// partially within view is omitted.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "a very long string value";
+    ///
+    /// assert_eq!(s.scroll_to_width::<ellipsis::Ascii>(0, 10), ("a very ...".to_owned(), 0));
+    /// assert_eq!(s.scroll_to_width::<ellipsis::Ascii>(7, 10), ("...g st...".to_owned(), 7));
+    /// assert_eq!(s.scroll_to_width::<ellipsis::Ascii>(99, 10), ("...g value".to_owned(), 14));
+    /// ```
+    fn scroll_to_width<E: Ellipsis>(&self, offset: usize, width: usize) -> (String, usize);
+
+    /// returns the middle of a string, limited by width.
+    ///
+    /// if the string is wider than `width` columns, the middle of the string is kept, and both
+    /// ends are replaced with the [`Ellipsis`]. this is useful for content like titles, where the
+    /// interesting part is in the middle.
+    ///
+    /// see [`scroll_to_width()`][Limited::scroll_to_width] for more information.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "chapter 12: the interesting part, continued";
+    /// let limited = s.trim_to_width_centered::<ellipsis::Horizontal>(21);
+    ///
+    /// assert_eq!(limited, "…the interesting par…");
+    /// ```
+    fn trim_to_width_centered<E: Ellipsis>(&self, width: usize) -> String;
+
+    /// returns a string limited by length, placing the ellipsis on the correct visual edge of
+    /// predominantly right-to-left text.
+    ///
+    /// see [`trim_to_width_bidi()`][Limited::trim_to_width_bidi] for more information.
+    fn trim_to_length_bidi<E: Ellipsis>(&self, length: usize) -> String;
+
+    /// returns a string limited by width, placing the ellipsis on the correct visual edge of
+    /// predominantly right-to-left text.
+    ///
+    /// an ellipsis is directionally neutral. when appended to right-to-left text that is
+    /// displayed in a left-to-right context, it would be rendered on the right side of the text,
+    /// rather than at its visual end on the left. if the string is predominantly right-to-left,
+    /// a [`RLM`][bidi::RLM] is placed after the ellipsis so that it is rendered alongside the
+    /// right-to-left text. see [`bidi::direction()`] for more information.
+    ///
+    /// left-to-right text is trimmed exactly as it is by [`trim_to_width()`][Limited::trim_to_width].
+    fn trim_to_width_bidi<E: Ellipsis>(&self, width: usize) -> String;
+
+    /// returns a string limited by width, wrapped in a directional isolate.
+    ///
+    /// the limited string is wrapped in a [`FSI`][bidi::FSI] and [`PDI`][bidi::PDI], so that
+    /// its ellipsis is laid out alongside its text, regardless of the direction of the text that
+    /// surrounds it. directional controls left unbalanced by the cut are removed. the isolate
+    /// controls are zero columns wide, so the string still fits within `width` columns. see
+    /// [`bidi::isolate()`] for more information.
+    ///
+    /// ```
+    /// use shear::str::{bidi::{FSI, PDI}, ellipsis, Limited};
+    ///
+    /// let limited = "\u{202B}שלום עולם\u{202C}".trim_to_width_isolated::<ellipsis::Horizontal>(5);
+    ///
+    /// assert_eq!(limited, format!("{FSI}שלום…{PDI}"));
+    /// ```
+    fn trim_to_width_isolated<E: Ellipsis>(&self, width: usize) -> String;
+
+    /// writes a string limited by length into the given buffer.
+    ///
+    /// the string is limited by the length of `buf`. returns the number of bytes written, or an
+    /// error if the string does not fit and the buffer is too small to hold the [`Ellipsis`].
+    ///
+    /// this does not allocate, and is suitable for callers that own a fixed-size buffer.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let mut buf = [0; 18];
+    /// let n = "a very long string value".trim_to_length_into::<ellipsis::Ascii>(&mut buf).unwrap();
+    ///
+    /// assert_eq!(&buf[..n], b"a very long str...");
+    /// ```
+    fn trim_to_length_into<E: Ellipsis>(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall>;
+
+    /// returns a [`heapless::String`] limited by length.
+    ///
+    /// the string is limited by `length`, or the capacity `N` of the string, whichever is
+    /// smaller. this never allocates on the heap, and is suitable for embedded targets. returns
+    /// an error if the string does not fit and there is no room for the [`Ellipsis`].
+    ///
+    /// see [`trim_to_length_into()`][Limited::trim_to_length_into] for more information.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "a very long string value";
+    ///
+    /// let limited = s.trim_to_length_heapless::<ellipsis::Ascii, 32>(10).unwrap();
+    /// assert_eq!(limited, "a very ...");
+    ///
+    /// let limited = s.trim_to_length_heapless::<ellipsis::Ascii, 8>(10).unwrap();
+    /// assert_eq!(limited, "a ver...");
+    /// ```
+    #[cfg(feature = "heapless")]
+    fn trim_to_length_heapless<E: Ellipsis, const N: usize>(
+        &self,
+        length: usize,
+    ) -> Result<heapless::String<N>, BufferTooSmall>;
+
+    /// returns an [`arrayvec::ArrayString`] limited by length.
+    ///
+    /// the string is limited by `length`, or the capacity `N` of the string, whichever is
+    /// smaller. see [`trim_to_length_heapless()`][Limited::trim_to_length_heapless] for more
+    /// information.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let limited = "a very long string value"
+    ///     .trim_to_length_array_string::<ellipsis::Horizontal, 8>(usize::MAX)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(limited.as_str(), "a ver…");
+    /// ```
+    #[cfg(feature = "arrayvec")]
+    fn trim_to_length_array_string<E: Ellipsis, const N: usize>(
+        &self,
+        length: usize,
+    ) -> Result<arrayvec::ArrayString<N>, BufferTooSmall>;
+
+    /// returns a string limited by length, using a [`Marker`] to indicate truncation.
+    ///
+    /// this behaves like [`trim_to_length()`][Limited::trim_to_length], but the marker is given
+    /// by reference, so it may carry state that is only known at runtime.
+    ///
+    /// ```
+    /// use shear::str::{Limited, Marker};
+    ///
+    /// struct Localized {
+    ///     more: String,
+    /// }
+    ///
+    /// impl Marker for Localized {
+    ///     fn marker(&self) -> &str {
+    ///         &self.more
+    ///     }
+    /// }
+    ///
+    /// let marker = Localized { more: "(suite)".to_owned() };
+    /// let limited = "a very long string value".trim_to_length_with(&marker, 16);
+    ///
+    /// assert_eq!(limited, "a very lo(suite)");
+    /// ```
+    fn trim_to_length_with<M: Marker + ?Sized>(&self, marker: &M, length: usize) -> String;
+
+    /// returns a string limited by length, using a [`DynamicEllipsis`] to indicate truncation.
+    ///
+    /// the ellipsis is generated once truncation has been decided, given the number of characters
+    /// that were dropped. the ellipsis counts towards the budget, so as much of the string is
+    /// kept as possible while leaving room for it.
+    ///
+    /// ```
+    /// use shear::str::Limited;
+    ///
+    /// let s = "a very long string value";
+    /// let limited = s.trim_to_length_dynamic(16, &|n| format!("… (+{n})"));
+    ///
+    /// assert_eq!(limited, "a very … (+17)");
+    /// assert_eq!(limited.len(), 16);
+    /// ```
+    fn trim_to_length_dynamic<D: DynamicEllipsis + ?Sized>(
+        &self,
+        length: usize,
+        ellipsis: &D,
+    ) -> String;
+
+    /// returns a string limited by width, using a [`DynamicEllipsis`] to indicate truncation.
+    ///
+    /// see [`trim_to_length_dynamic()`][Limited::trim_to_length_dynamic] for more information.
+    fn trim_to_width_dynamic<D: DynamicEllipsis + ?Sized>(
+        &self,
+        width: usize,
+        ellipsis: &D,
+    ) -> String;
+
+    /// returns a string limited by height, using a [`DynamicEllipsis`] to indicate truncation.
+    ///
+    /// the last line is replaced by the ellipsis, which is given the number of lines that were
+    /// dropped, including the line it replaces. line endings are preserved as they are by
+    /// [`trim_to_height()`][Limited::trim_to_height]. see
+    /// [`Height::limited_dynamic()`][crate::iter::Height::limited_dynamic] to limit other sources
+    /// of lines in the same way.
+    ///
+    /// ```
+    /// use shear::str::Limited;
+    ///
+    /// let s = "one\ntwo\nthree\nfour";
+    /// let limited = s.trim_to_height_dynamic(2, &|n| format!("… {n} more lines"));
+    ///
+    /// assert_eq!(limited, "one\n… 3 more lines");
+    /// ```
+    fn trim_to_height_dynamic<D: DynamicEllipsis + ?Sized>(
+        &self,
+        height: usize,
+        ellipsis: &D,
+    ) -> String;
+
+    /// returns a string limited by width, using a [`Marker`] to indicate truncation.
+    ///
+    /// see [`trim_to_length_with()`][Limited::trim_to_length_with] for more information.
+    fn trim_to_width_with<M: Marker + ?Sized>(&self, marker: &M, width: usize) -> String;
+
+    /// returns a string limited by length, placing the ellipsis at the start, middle, or end.
+    ///
+    /// with [`Placement::End`][ellipsis::Placement::End], this is equivalent to
+    /// [`trim_to_length()`][Limited::trim_to_length]. otherwise, the start or the middle of the
+    /// string is cut, and the [`Ellipsis`] is placed there instead. this is useful for e.g. file
+    /// names, whose extension is often the most important part.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis::{self, Placement}, Limited};
+    ///
+    /// let s = "averylongfilename.tar.gz";
+    ///
+    /// assert_eq!(s.trim_to_width_placed::<ellipsis::Horizontal>(13, Placement::Middle), "averyl…tar.gz");
+    /// assert_eq!(s.trim_to_width_placed::<ellipsis::Horizontal>(13, Placement::Start), "…ename.tar.gz");
+    /// assert_eq!(s.trim_to_width_placed::<ellipsis::Horizontal>(13, Placement::End), "averylongfil…");
+    /// ```
+    fn trim_to_length_placed<E: Ellipsis>(
+        &self,
+        length: usize,
+        placement: ellipsis::Placement,
+    ) -> String;
+
+    /// returns a string limited by width, placing the ellipsis at the start, middle, or end.
+    ///
+    /// see [`trim_to_length_placed()`][Limited::trim_to_length_placed] for more information.
+    fn trim_to_width_placed<E: Ellipsis>(
+        &self,
+        width: usize,
+        placement: ellipsis::Placement,
+    ) -> String;
+
+    /// returns the end of a string, limited by length.
+    ///
+    /// if the string is too long, its start is cut, and the [`Ellipsis`] is placed before the
+    /// kept suffix. this is useful for e.g. file paths and breadcrumbs, whose end carries the most
+    /// information. see [`trim_to_length_placed()`][Limited::trim_to_length_placed] for more
+    /// information.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "/home/user/code/shear/src/iter.rs";
+    /// let limited = s.trim_to_length_keep_end::<ellipsis::Horizontal>(15);
+    ///
+    /// assert_eq!(limited, "…/src/iter.rs");
+    /// ```
+    fn trim_to_length_keep_end<E: Ellipsis>(&self, length: usize) -> String;
+
+    /// returns the end of a string, limited by width.
+    ///
+    /// see [`trim_to_length_keep_end()`][Limited::trim_to_length_keep_end] for more information.
+    fn trim_to_width_keep_end<E: Ellipsis>(&self, width: usize) -> String;
+
+    /// returns the last lines of a string, limited by height.
+    ///
+    /// if the string has more than `height` lines, the first line is replaced by the
+    /// [`Ellipsis`], followed by the last `height - 1` lines. this is the natural presentation
+    /// for the tail of a log, or the output of a command. see
+    /// [`trim_to_height()`][Limited::trim_to_height] for more information.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "compiling\nlinking\ntesting\nfinished";
+    /// let limited = s.trim_to_height_keep_end::<ellipsis::Ascii>(3);
+    ///
+    /// assert_eq!(limited, "...\ntesting\nfinished");
+    /// ```
+    fn trim_to_height_keep_end<E: Ellipsis>(&self, height: usize) -> String;
+
+    /// returns a string limited by length, without orphaned punctuation before the ellipsis.
+    ///
+    /// if the string is too long, and the cut would leave an opening bracket, an opening quote,
+    /// a dash, or whitespace immediately before the [`Ellipsis`], the cut is moved back past
+    /// them. strings that fit are returned unaltered.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "see (the appendix)";
+    ///
+    /// assert_eq!(s.trim_to_length::<ellipsis::Horizontal>(8), "see (…");
+    /// assert_eq!(s.trim_to_length_without_orphans::<ellipsis::Horizontal>(8), "see…");
+    /// ```
+    fn trim_to_length_without_orphans<E: Ellipsis>(&self, length: usize) -> String;
+
+    /// returns a string limited by width, without orphaned punctuation before the ellipsis.
+    ///
+    /// see [`trim_to_length_without_orphans()`][Limited::trim_to_length_without_orphans] for
+    /// more information.
+    fn trim_to_width_without_orphans<E: Ellipsis>(&self, width: usize) -> String;
+
+    /// returns a string limited by length, without ending inside of a bracket or quote.
+    ///
+    /// if the string is too long, and the cut would leave a bracket or quote unclosed, the cut is
+    /// moved back to the last position at which every bracket and quote was closed. if there is
+    /// no such position, e.g. because the whole string is a json object, the cut is moved back
+    /// to the last position outside of a quote instead. this is useful when trimming code or
+    /// structured data for logs. strings that fit are returned unaltered.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = r#"call(foo, "bar") then baz(qux)"#;
+    /// assert_eq!(s.trim_to_length_balanced::<ellipsis::Ascii>(24), r#"call(foo, "bar") then..."#);
+    ///
+    /// let json = r#"{"name": "katelyn", "tags": ["a", "b"]}"#;
+    /// assert_eq!(json.trim_to_length_balanced::<ellipsis::Ascii>(32), r#"{"name": "katelyn", "tags": [..."#);
+    /// ```
+    fn trim_to_length_balanced<E: Ellipsis>(&self, length: usize) -> String;
+
+    /// returns a string limited by width, without ending inside of a bracket or quote.
+    ///
+    /// see [`trim_to_length_balanced()`][Limited::trim_to_length_balanced] for more information.
+    fn trim_to_width_balanced<E: Ellipsis>(&self, width: usize) -> String;
+
+    /// returns a string limited by length, cut at a word boundary if possible.
+    ///
+    /// if the string is too long, it is cut at the end of the last word that fits alongside the
+    /// [`Ellipsis`], rather than in the middle of a word. if no word fits, the string is cut as
+    /// [`trim_to_length()`][Limited::trim_to_length] would cut it.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "a very long string";
+    ///
+    /// assert_eq!(s.trim_to_length::<ellipsis::Horizontal>(12), "a very lo…");
+    /// assert_eq!(s.trim_to_length_at_word::<ellipsis::Horizontal>(12), "a very…");
+    /// assert_eq!("unbreakable".trim_to_length_at_word::<ellipsis::Ascii>(8), "unbre...");
+    /// ```
+    fn trim_to_length_at_word<E: Ellipsis>(&self, length: usize) -> String;
+
+    /// returns a string limited by width, cut at a word boundary if possible.
+    ///
+    /// see [`trim_to_length_at_word()`][Limited::trim_to_length_at_word] for more information.
+    fn trim_to_width_at_word<E: Ellipsis>(&self, width: usize) -> String;
+
+    /// returns a string limited by length, cut at a natural boundary within `budget` if possible.
+    ///
+    /// if the string is longer than the end of `budget`, it is cut after the last word, or after
+    /// the last sentence or clause punctuation, such that the limited string's length (including
+    /// the [`Ellipsis`]) falls within the range. if there is no such boundary, the string is
+    /// limited to the end of the range, as [`trim_to_length()`][Limited::trim_to_length] does.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let s = "the quick brown fox jumps over the lazy dog";
+    ///
+    /// assert_eq!(s.trim_to_length::<ellipsis::Ascii>(24), "the quick brown fox j...");
+    /// assert_eq!(s.trim_to_length_within::<ellipsis::Ascii>(18..=24), "the quick brown fox...");
+    /// assert_eq!(s.trim_to_length_within::<ellipsis::Ascii>(23..=24), "the quick brown fox j...");
+    /// ```
+    fn trim_to_length_within<E: Ellipsis>(&self, budget: RangeInclusive<usize>) -> String;
+
+    /// returns a string limited by width, cut at a natural boundary within `budget` if possible.
+    ///
+    /// see [`trim_to_length_within()`][Limited::trim_to_length_within] for more information.
+    fn trim_to_width_within<E: Ellipsis>(&self, budget: RangeInclusive<usize>) -> String;
+
+    /// writes a string limited by length to the given [`std::fmt::Write`] sink.
+    ///
+    /// this writes the same contents that [`trim_to_length()`][Limited::trim_to_length] would
+    /// return, without allocating an intermediate [`String`].
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let mut out = String::from("name: ");
+    /// "a very long string value".write_limited::<ellipsis::Ascii, _>(10, &mut out).unwrap();
+    ///
+    /// assert_eq!(out, "name: a very ...");
+    /// ```
+    fn write_limited<E: Ellipsis, W: std::fmt::Write>(
+        &self,
+        length: usize,
+        out: &mut W,
+    ) -> std::fmt::Result;
+
+    /// returns a value that displays this string, limited by length.
+    ///
+    /// the string is limited while it is being formatted, so it can be embedded in e.g.
+    /// [`format!`] or [`println!`] without allocating an intermediate [`String`]. see
+    /// [`write_limited()`][Limited::write_limited] for more information.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let name = "a very long string value";
+    /// let message = format!("hello, {}!", name.display_trimmed::<ellipsis::Ascii>(10));
+    ///
+    /// assert_eq!(message, "hello, a very ...!");
+    /// ```
+    fn display_trimmed<E: Ellipsis>(&self, length: usize) -> Trimmed<'_, E>;
+
+    /// extends `sink` with a string limited by length.
+    ///
+    /// this pushes the same characters that [`trim_to_length()`][Limited::trim_to_length] would
+    /// return into an existing collection, rather than allocating a new [`String`]. this can be
+    /// used to accumulate limited strings into e.g. a rope, or an arena-backed string.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let mut s = String::from("name: ");
+    /// "a very long string value".extend_trimmed_to_length::<ellipsis::Ascii>(&mut s, 10);
+    ///
+    /// assert_eq!(s, "name: a very ...");
+    /// ```
+    fn extend_trimmed_to_length<E: Ellipsis>(&self, sink: &mut impl Extend<char>, length: usize);
+
+    /// extends `sink` with a string limited by width.
+    ///
+    /// see [`extend_trimmed_to_length()`][Limited::extend_trimmed_to_length] for more
+    /// information.
+    fn extend_trimmed_to_width<E: Ellipsis>(&self, sink: &mut impl Extend<char>, width: usize);
+
+    /// extends `sink` with the lines of a string, limited by height.
+    ///
+    /// this pushes the same lines that [`trim_to_height()`][Limited::trim_to_height] would
+    /// return, separated by the same line endings, into an existing collection.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, Limited};
+    ///
+    /// let mut pieces = Vec::new();
+    /// "one\ntwo\nthree".extend_trimmed_to_height::<ellipsis::Ascii>(&mut pieces, 2);
+    ///
+    /// assert_eq!(pieces, ["one", "\n", "..."]);
+    /// ```
+    fn extend_trimmed_to_height<'a, E: Ellipsis>(
+        &'a self,
+        sink: &mut impl Extend<&'a str>,
+        height: usize,
+    );
+
+    /// returns the rest of a string after `token`, limited by length, and a token to resume with.
+    ///
+    /// this limits the string as [`trim_to_length()`][Limited::trim_to_length] does, but starts
+    /// at the given [`Token`][resume::Token]. if the rest of the string does not fit, a token is
+    /// returned that can be used to resume limiting where this piece left off, with a fresh
+    /// budget. this can be used to paginate a string across calls, e.g. splitting content across
+    /// several chat messages.
+    ///
+    /// each piece holds at least one character, so a budget too small for the ellipsis and one
+    /// character will be exceeded rather than making no progress.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis, resume::Token, Limited};
+    ///
+    /// let s = "a very long string value";
+    ///
+    /// let (first, token) = s.trim_to_length_resumable::<ellipsis::Ascii>(Token::START, 12);
+    /// assert_eq!(first, "a very lo...");
+    ///
+    /// let token = token.unwrap().to_string().parse().unwrap();
+    /// let (second, token) = s.trim_to_length_resumable::<ellipsis::Ascii>(token, 12);
+    /// assert_eq!(second, "ng string...");
+    ///
+    /// let (third, token) = s.trim_to_length_resumable::<ellipsis::Ascii>(token.unwrap(), 12);
+    /// assert_eq!((third.as_str(), token), (" value", None));
+    /// ```
+    fn trim_to_length_resumable<E: Ellipsis>(
+        &self,
+        token: resume::Token,
+        length: usize,
+    ) -> (String, Option<resume::Token>);
+
+    /// returns the rest of a string after `token`, limited by width, and a token to resume with.
+    ///
+    /// see [`trim_to_length_resumable()`][Limited::trim_to_length_resumable] for more
+    /// information.
+    fn trim_to_width_resumable<E: Ellipsis>(
+        &self,
+        token: resume::Token,
+        width: usize,
+    ) -> (String, Option<resume::Token>);
+}
+
+/// returns a string of pre-measured characters, limited by width.
+///
+/// this accepts `(char, width)` pairs, whose widths have already been computed by the caller,
+/// e.g. using a terminal emulator's own width tables. this performs no measurement of its own,
+/// besides measuring the [`Ellipsis`]. like [`Limited::trim_to_width()`], the characters are
+/// never cut inside of a cluster.
+///
+/// see [`Limited::trim_to_width()`] for more information.
+///
+/// ```
+/// use shear::str::{ellipsis, trim_measured};
+///
+/// // a width table in which every character is two columns wide.
+/// let chars = "abcdef".chars().map(|c| (c, 2));
+/// let limited = trim_measured::<ellipsis::Ascii>(chars, 9);
+///
+/// assert_eq!(limited, "abc...");
+/// ```
+pub fn trim_measured<E: Ellipsis>(
+    chars: impl IntoIterator<Item = (char, usize)>,
+    width: usize,
+) -> String {
+    use {
+        self::trim_to_width::MeasuredEllipsis,
+        crate::iter::{LimitedIter, Weighted},
+    };
+
+    // characters that fit are never replaced by the ellipsis, as in `trim_to_width()`. the
+    // characters that were read are kept, to find the cluster boundaries around the cut.
+    let mut read = String::new();
+    let (limited, truncated) = {
+        let chars = chars.into_iter().inspect(|&(c, _)| read.push(c));
+        let chars = Weighted::<_, MeasuredEllipsis<E>>::new(chars);
+        let mut iter = LimitedIter::fitting(chars, width, MeasuredEllipsis::<E>::default());
+        let limited = iter.by_ref().map(|(c, _)| c).collect::<String>();
+        (limited, iter.is_truncated())
+    };
+
+    // characters are limited one at a time, so move the cut back to a cluster boundary if needed.
+    let kept = limited.len().saturating_sub(E::ellipsis().len());
+    match self::split::cluster_floor(&read, kept) {
+        cut if truncated && cut < kept => [&read[..cut], E::ellipsis()].concat(),
+        _ => limited,
+    }
+}
+
+/// returns the longest prefix of `value` that is no longer than `max_bytes`.
+///
+/// the prefix ends at the nearest character boundary at or before `max_bytes`, and borrows from
+/// `value` without allocating. no ellipsis is added, and characters are not kept together with
+/// the combining marks that follow them; this is the primitive that trimming methods such as
+/// [`Limited::trim_to_length()`] build upon.
+///
+/// ```
+/// use shear::str::floor_to_boundary;
+///
+/// assert_eq!(floor_to_boundary("hello", 3), "hel");
+/// assert_eq!(floor_to_boundary("hello", 8), "hello");
+///
+/// // `é` is two bytes long, so it cannot be split after one byte.
+/// assert_eq!(floor_to_boundary("héllo", 2), "h");
+/// ```
+pub fn floor_to_boundary(value: &str, max_bytes: usize) -> &str {
+    let mut end = max_bytes.min(value.len());
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    &value[..end]
+}
+
+/// returns the pieces of `value` limited by length, if they fit within `length` bytes.
+///
+/// the pieces are those that [`Limited::trim_to_length()`] would return. if the [`Ellipsis`]
+/// does not fit, an error is returned. this is used by methods that write into a fixed-size
+/// buffer, such as [`Limited::trim_to_length_into()`].
+fn fixed_parts<E: Ellipsis>(value: &str, length: usize) -> Result<[&str; 4], BufferTooSmall> {
+    let pieces = self::trim_to_length::pieces::<E>(value, length);
+
+    match pieces.iter().map(|piece| piece.len()).sum::<usize>() {
+        needed if needed > length => Err(BufferTooSmall { needed }),
+        _ => Ok(pieces),
+    }
+}
+
+/// an error returned when a buffer is too small to hold a limited string.
+///
+/// see [`Limited::trim_to_length_into()`] for more information.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BufferTooSmall {
+    /// the minimum size of a buffer that could hold the limited string.
+    pub needed: usize,
+}
+
+// === impl buffertoosmall ===
+
+impl std::fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { needed } = self;
+        write!(f, "buffer is too small, at least {needed} bytes are needed")
+    }
 }
 
-// === impl s: asref<str> ===
+impl std::error::Error for BufferTooSmall {}
 
+// === impl s: deref<target: asref<str>> ===
+
+/// strings, and smart pointers to strings, can be limited.
+///
+/// this includes e.g. `&str`, [`String`], `Box<str>`, `Cow<'_, str>`, `Arc<String>`, and
+/// `Rc<String>`.
 impl<S> Limited for S
 where
-    S: AsRef<str>,
+    S: Deref,
+    S::Target: AsRef<str>,
 {
     fn trim_to_length<E: Ellipsis>(&self, length: usize) -> String {
-        use self::trim_to_length::TrimToLengthIter;
-
-        let value: &'_ str = self.as_ref();
-
-        // we know the length of a string in advance, so we can check if the value fits into the
-        // given length, without having to iterate over its characters.
-        let fits = value.len() <= length;
-
-        // helper fn: if called, limits the contents of the string.
-        let limit = || {
-            use {crate::iter::Limited, tap::Pipe};
-            value
-                .chars()
-                .pipe(TrimToLengthIter::<_, E>::new)
-                .limited(length)
-                .collect()
-        };
+        let value: &'_ str = self.deref().as_ref();
+        let (limited, _) = self::trim_to_length::trim_to_length::<E>(value, length);
 
-        fits.then_some(value)
-            .map(str::to_owned)
-            .unwrap_or_else(limit)
+        #[cfg(feature = "audit")]
+        audit::check::<E>(audit::Metric::Length, value, &limited, length);
+
+        limited
     }
 
     fn trim_to_width<E: Ellipsis>(&self, width: usize) -> String {
-        use {self::trim_to_width::TrimToWidthIter, crate::iter::Limited, tap::Pipe};
+        let value: &'_ str = self.deref().as_ref();
+        let (limited, _) = self::trim_to_width::trim_to_width::<E>(value, width);
+
+        #[cfg(feature = "audit")]
+        audit::check::<E>(audit::Metric::Width, value, &limited, width);
+
+        limited
+    }
+
+    #[cfg(feature = "ansi")]
+    fn trim_to_width_ansi<E: Ellipsis>(&self, width: usize) -> String {
+        self::ansi::trim_to_width::<E>(self.deref().as_ref(), width)
+    }
+
+    fn trim_content_to_length<E: Ellipsis>(&self, length: usize) -> String {
+        let value: &'_ str = self.deref().as_ref();
+
+        match self::split::at_length(value, length) {
+            (kept, "") => kept.to_owned(),
+            (kept, _) => [E::leading(), kept, E::ellipsis(), E::trailing()].concat(),
+        }
+    }
+
+    fn trim_content_to_width<E: Ellipsis>(&self, width: usize) -> String {
+        let value: &'_ str = self.deref().as_ref();
+
+        match self::split::at_width(value, width) {
+            (kept, "") => kept.to_owned(),
+            (kept, _) => [E::leading(), kept, E::ellipsis(), E::trailing()].concat(),
+        }
+    }
+
+    fn trim_to_length_checked<E: Ellipsis>(&self, length: usize) -> (String, bool) {
+        let value: &'_ str = self.deref().as_ref();
+        let limited = value.trim_to_length::<E>(length);
+        let truncated = limited != value;
+        (limited, truncated)
+    }
+
+    fn trim_to_width_checked<E: Ellipsis>(&self, width: usize) -> (String, bool) {
+        let value: &'_ str = self.deref().as_ref();
+        let limited = value.trim_to_width::<E>(width);
+        let truncated = limited != value;
+        (limited, truncated)
+    }
+
+    fn trim_to_height_checked<E: Ellipsis>(&self, height: usize) -> (String, bool) {
+        use self::trim_to_height::Lines;
+
+        let value: &'_ str = self.deref().as_ref();
+        let limited = value.trim_to_height::<E>(height);
+
+        // line breaks may be normalized, so compare the lines rather than the strings.
+        let truncated = !Lines::new(&limited).eq(Lines::new(value));
+        (limited, truncated)
+    }
+
+    fn trim_to_length_inspect<'a, E: Ellipsis>(
+        &'a self,
+        length: usize,
+        f: impl FnMut(Kept<'a>),
+    ) -> String {
+        let value: &'a str = self.deref().as_ref();
+        let pieces = self::trim_to_length::pieces::<E>(value, length);
+        inspect::pieces(pieces, char::len_utf8, f)
+    }
+
+    fn trim_to_width_inspect<'a, E: Ellipsis>(
+        &'a self,
+        width: usize,
+        f: impl FnMut(Kept<'a>),
+    ) -> String {
+        let value: &'a str = self.deref().as_ref();
+        let pieces = self::trim_to_width::pieces::<E>(value, width);
+        inspect::pieces(pieces, self::width::char_width, f)
+    }
+
+    fn trim_to_height_inspect<'a, E: Ellipsis>(
+        &'a self,
+        height: usize,
+        f: impl FnMut(Kept<'a>),
+    ) -> String {
+        use {
+            self::trim_to_height::Lines,
+            crate::iter::{Height, Limited},
+        };
+
+        let value: &'a str = self.deref().as_ref();
+        let iter = Height::<_, E>::new(Lines::new(value)).limited(height);
+        inspect::lines(value, iter, f)
+    }
+
+    fn trim_point_for_length<E: Ellipsis>(&self, length: usize) -> Option<usize> {
+        let value: &'_ str = self.deref().as_ref();
+        trim_point::for_length::<E>(value, length)
+    }
+
+    fn trim_point_for_width<E: Ellipsis>(&self, width: usize) -> Option<usize> {
+        let value: &'_ str = self.deref().as_ref();
+        trim_point::for_width::<E>(value, width)
+    }
+
+    fn trim_to_length_report<E: Ellipsis>(&self, length: usize) -> TrimReport {
+        let value: &'_ str = self.deref().as_ref();
+        let (limited, kept) = self::trim_to_length::trim_to_length::<E>(value, length);
+        TrimReport::for_prefix(value, limited, kept)
+    }
+
+    fn trim_to_width_report<E: Ellipsis>(&self, width: usize) -> TrimReport {
+        let value: &'_ str = self.deref().as_ref();
+        let (limited, kept) = self::trim_to_width::trim_to_width::<E>(value, width);
+        TrimReport::for_prefix(value, limited, kept)
+    }
+
+    fn trim_to_height_report<E: Ellipsis>(&self, height: usize) -> TrimReport {
+        let value: &'_ str = self.deref().as_ref();
+        TrimReport::for_lines(value, value.trim_to_height::<E>(height))
+    }
+
+    fn strip_zero_width(&self) -> Cow<'_, str> {
+        self::zero_width::strip(self.deref().as_ref())
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    fn normalized(&self, form: normalize::Form) -> Cow<'_, str> {
+        self::normalize::normalize(self.deref().as_ref(), form)
+    }
 
-        let value: &'_ str = self.as_ref();
+    fn trim_to_length_cow<E: Ellipsis>(&self, length: usize) -> Cow<'_, str> {
+        let value: &'_ str = self.deref().as_ref();
+
+        match value.len() <= length {
+            true => Cow::Borrowed(value),
+            false => value.trim_to_length::<E>(length).into(),
+        }
+    }
+
+    fn trim_to_width_cow<E: Ellipsis>(&self, width: usize) -> Cow<'_, str> {
+        let value: &'_ str = self.deref().as_ref();
+
+        match self::width::str_width(value) <= width {
+            true => Cow::Borrowed(value),
+            false => value.trim_to_width::<E>(width).into(),
+        }
+    }
+
+    fn trim_to_height<E: Ellipsis>(&self, height: usize) -> String {
+        use {
+            self::trim_to_height::{join, line_ending, Lines},
+            crate::iter::{Height, Limited},
+            tap::Pipe,
+        };
+
+        let value: &'_ str = self.deref().as_ref();
+        let ending = line_ending(value, Terminators::default());
+
+        // values that fit are not limited, so that no ellipsis is added to them. lines after the
+        // first `height + 1` need not be counted to know that the value does not fit.
+        let limited = match Lines::new(value).take(height + 1).count() <= height {
+            true => join(|| Lines::new(value), ending),
+            false => {
+                let lines = || value.pipe(Lines::new).pipe(Height::<_, E>::new);
+                join(|| lines().limited(height), ending)
+            }
+        };
+
+        #[cfg(feature = "audit")]
+        audit::check::<E>(audit::Metric::Height, value, &limited, height);
+
+        limited
+    }
+
+    fn trim_to_height_terminated<E: Ellipsis>(
+        &self,
+        height: usize,
+        terminators: Terminators,
+    ) -> String {
+        use {
+            self::trim_to_height::{join, line_ending, Lines, WeightedEllipsis},
+            crate::iter::{Limited, Weighted},
+            tap::Pipe,
+        };
+
+        let value: &'_ str = self.deref().as_ref();
+        let ending = line_ending(value, terminators);
+        let lines = || Lines::with_terminators(value, terminators);
+
+        // values that fit are not limited, so that no ellipsis is added to them.
+        if lines().take(height + 1).count() <= height {
+            return join(lines, ending);
+        }
+
+        // each line is weighed as one line, since it may contain breaks that are not recognized.
+        let limited = || {
+            lines()
+                .map(|line| (line, 1))
+                .pipe(Weighted::<_, WeightedEllipsis<E>>::new)
+                .limited(height)
+                .map(|(line, _)| line)
+        };
+        join(limited, ending)
+    }
+
+    fn trim_to_chars<E: Ellipsis>(&self, count: usize) -> String {
+        let value: &'_ str = self.deref().as_ref();
+
+        match value.chars().count() <= count {
+            true => value.to_owned(),
+            false => value.trim_to_units::<units::Chars, E>(count),
+        }
+    }
+
+    fn trim_to_utf16_units<E: Ellipsis>(&self, count: usize) -> String {
+        let value: &'_ str = self.deref().as_ref();
+
+        match value.chars().map(char::len_utf16).sum::<usize>() <= count {
+            true => value.to_owned(),
+            false => value.trim_to_units::<units::Utf16, E>(count),
+        }
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    fn trim_to_graphemes<E: Ellipsis>(&self, count: usize) -> String {
+        use {
+            self::trim_to_graphemes::TrimToGraphemesIter, crate::iter::Limited, tap::Pipe,
+            unicode_segmentation::UnicodeSegmentation,
+        };
+
+        let value: &'_ str = self.deref().as_ref();
 
         value
-            .chars()
-            .pipe(TrimToWidthIter::<_, E>::new)
-            .limited(width)
+            .graphemes(true)
+            .pipe(TrimToGraphemesIter::<_, E>::new)
+            .limited(count)
             .collect()
     }
+
+    fn trim_to_height_keeping<E: Ellipsis>(
+        &self,
+        height: usize,
+        keep: impl Fn(&str) -> bool,
+    ) -> String {
+        use self::trim_to_height::{line_ending, Lines};
+
+        let value: &'_ str = self.deref().as_ref();
+        let ending = line_ending(value, Terminators::default());
+
+        // find how many of the other lines can be kept, leaving room for the ellipsis if needed.
+        let others = Lines::new(value).filter(|line| !keep(line)).count();
+        let room = match others <= height {
+            true => others,
+            false => height.saturating_sub(1),
+        };
+
+        let mut limited = String::with_capacity(value.len());
+        let (mut kept, mut elided, mut first) = (0, false, true);
+        for line in Lines::new(value) {
+            let line = match keep(line) {
+                true => line,
+                false if kept < room => {
+                    kept += 1;
+                    line
+                }
+                false if !elided => {
+                    elided = true;
+                    E::ellipsis()
+                }
+                false => continue,
+            };
+            // lines may be empty, so whether a line has been written is tracked separately.
+            if !std::mem::take(&mut first) {
+                limited.push_str(ending);
+            }
+            limited.push_str(line);
+        }
+
+        limited
+    }
+
+    fn trim_to_height_weighted<E: Ellipsis>(
+        &self,
+        height: usize,
+        weight: impl Fn(&str) -> usize,
+    ) -> String {
+        use {
+            self::trim_to_height::{join, line_ending, Lines, WeightedEllipsis},
+            crate::iter::{Limited, Weighted},
+            tap::Pipe,
+        };
+
+        let value: &'_ str = self.deref().as_ref();
+        let ending = line_ending(value, Terminators::default());
+
+        // values that fit are not limited, so that no ellipsis is added to them. weighing stops
+        // once the lines are known not to fit.
+        let fits = Lines::new(value)
+            .map(&weight)
+            .try_fold(0_usize, |sum, weight| {
+                sum.checked_add(weight).filter(|&sum| sum <= height)
+            })
+            .is_some();
+        if fits {
+            return join(|| Lines::new(value), ending);
+        }
+
+        let limited = || {
+            Lines::new(value)
+                .map(|line| (line, weight(line)))
+                .pipe(Weighted::<_, WeightedEllipsis<E>>::new)
+                .limited(height)
+                .map(|(line, _)| line)
+        };
+        join(limited, ending)
+    }
+
+    fn trim_to_height_wrapped<E: Ellipsis>(&self, height: usize, width: usize) -> String {
+        let rows = |line: &str| line.split_to_width(width).count().max(1);
+        self.trim_to_height_weighted::<E>(height, rows)
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    fn trim_to_length_at_grapheme<E: Ellipsis>(&self, length: usize) -> String {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let value: &'_ str = self.deref().as_ref();
+
+        if value.len() <= length {
+            return value.to_owned();
+        }
+
+        // find the last grapheme cluster boundary that fits alongside the ellipsis.
+        let ellipsis = E::ellipsis();
+        let room = length.saturating_sub(ellipsis.len());
+        let end = value
+            .grapheme_indices(true)
+            .map(|(i, grapheme)| i + grapheme.len())
+            .take_while(|&end| end <= room)
+            .last()
+            .unwrap_or_default();
+
+        let mut limited = String::with_capacity(end + ellipsis.len());
+        limited.push_str(&value[..end]);
+        limited.push_str(ellipsis);
+        limited
+    }
+
+    fn trim_to_box<E: Ellipsis>(&self, width: usize, height: usize) -> String {
+        Limits::new()
+            .max_width(width)
+            .max_height(height)
+            .ellipsis::<E>()
+            .apply(self.deref().as_ref())
+    }
+
+    fn trim_to_height_inline<E: Ellipsis>(&self, height: usize) -> String {
+        use self::trim_to_height::{line_ending, Lines};
+
+        let value: &'_ str = self.deref().as_ref();
+        let ending = line_ending(value, Terminators::default());
+        let mut lines = Lines::new(value);
+
+        let mut limited = String::with_capacity(value.len());
+        for (i, line) in lines.by_ref().take(height).enumerate() {
+            if i > 0 {
+                limited.push_str(ending);
+            }
+            limited.push_str(line);
+        }
+
+        if lines.next().is_some() {
+            limited.push_str(E::ellipsis());
+        }
+
+        limited
+    }
+
+    fn trim_to_height_at_sentence<E: Ellipsis>(&self, height: usize) -> String {
+        use self::trim_to_height::{last_sentence_end, line_ending, Lines};
+
+        let value: &'_ str = self.deref().as_ref();
+        let ending = line_ending(value, Terminators::default());
+        let mut lines = Lines::new(value);
+
+        // keep all but one line, leaving room for the ellipsis.
+        let mut kept = String::with_capacity(value.len());
+        let mut any = false; // lines may be empty, so this is tracked separately.
+        for line in lines.by_ref().take(height.saturating_sub(1)) {
+            if any {
+                kept.push_str(ending);
+            }
+            kept.push_str(line);
+            any = true;
+        }
+
+        // if the remaining lines fit, or the kept lines end a paragraph, there is nothing to do.
+        let rest = lines.clone().take(2).collect::<Vec<_>>();
+        match rest.as_slice() {
+            [] => return kept,
+            [last] if height > 0 => {
+                if any {
+                    kept.push_str(ending);
+                }
+                kept.push_str(last);
+                return kept;
+            }
+            [next, ..] if !next.trim().is_empty() => {
+                let end = last_sentence_end(&kept).unwrap_or(kept.len());
+                kept.truncate(end);
+            }
+            _ => {}
+        }
+
+        if any {
+            kept.push_str(ending);
+        }
+        kept.push_str(E::ellipsis());
+        kept
+    }
+
+    fn trim_with<E: Ellipsis>(&self, measure: &impl units::Measure, budget: usize) -> String {
+        self::units::trim_with::<E>(self.deref().as_ref(), measure, budget)
+    }
+
+    fn trim_to_units<U: units::Units, E: Ellipsis>(&self, size: usize) -> String {
+        let measure = self::units::Static::<U>::new();
+        self::units::trim_with::<E>(self.deref().as_ref(), &measure, size)
+    }
+
+    fn split_to_width(&self, width: usize) -> SplitToWidth<'_> {
+        SplitToWidth::new(self.deref().as_ref(), width)
+    }
+
+    fn split_to_length(&self, length: usize) -> SplitToLength<'_> {
+        SplitToLength::new(self.deref().as_ref(), length)
+    }
+
+    fn fit_to_width<E: Ellipsis>(&self, width: usize, align: Align) -> String {
+        self::fit::fit::<E>(self.deref().as_ref(), width, align)
+    }
+
+    fn split_at_length(&self, length: usize) -> (&str, &str) {
+        self::split::at_length(self.deref().as_ref(), length)
+    }
+
+    fn split_at_width(&self, width: usize) -> (&str, &str) {
+        self::split::at_width(self.deref().as_ref(), width)
+    }
+
+    fn scroll_to_width<E: Ellipsis>(&self, offset: usize, width: usize) -> (String, usize) {
+        self::scroll::scroll::<E>(self.deref().as_ref(), offset, width)
+    }
+
+    fn trim_to_width_centered<E: Ellipsis>(&self, width: usize) -> String {
+        let value: &'_ str = self.deref().as_ref();
+
+        let total = self::width::str_width(value);
+        let offset = total.saturating_sub(width) / 2;
+
+        self::scroll::scroll::<E>(value, offset, width).0
+    }
+
+    fn trim_to_length_bidi<E: Ellipsis>(&self, length: usize) -> String {
+        use bidi::{Direction, RLM};
+
+        let value: &'_ str = self.deref().as_ref();
+
+        match bidi::direction(value) {
+            Direction::LeftToRight => value.trim_to_length::<E>(length),
+            Direction::RightToLeft if value.len() <= length => value.to_owned(),
+            Direction::RightToLeft => {
+                // reserve room for the right-to-left mark following the ellipsis.
+                let mut limited = value.trim_to_length::<E>(length.saturating_sub(RLM.len_utf8()));
+                limited.push(RLM);
+                limited
+            }
+        }
+    }
+
+    fn trim_to_width_bidi<E: Ellipsis>(&self, width: usize) -> String {
+        use bidi::{Direction, RLM};
+
+        let value: &'_ str = self.deref().as_ref();
+        let mut limited = value.trim_to_width::<E>(width);
+
+        // the right-to-left mark is zero columns wide, so it always fits.
+        let trimmed = limited != value;
+        if trimmed && bidi::direction(value) == Direction::RightToLeft {
+            limited.push(RLM);
+        }
+
+        limited
+    }
+
+    fn trim_to_length_without_orphans<E: Ellipsis>(&self, length: usize) -> String {
+        let value: &'_ str = self.deref().as_ref();
+
+        self::orphans::back_off::<E>(value, value.trim_to_length::<E>(length))
+    }
+
+    fn trim_to_width_without_orphans<E: Ellipsis>(&self, width: usize) -> String {
+        let value: &'_ str = self.deref().as_ref();
+
+        self::orphans::back_off::<E>(value, value.trim_to_width::<E>(width))
+    }
+
+    fn trim_to_length_balanced<E: Ellipsis>(&self, length: usize) -> String {
+        let value: &'_ str = self.deref().as_ref();
+
+        self::brackets::back_off::<E>(value, value.trim_to_length::<E>(length))
+    }
+
+    fn trim_to_width_balanced<E: Ellipsis>(&self, width: usize) -> String {
+        let value: &'_ str = self.deref().as_ref();
+
+        self::brackets::back_off::<E>(value, value.trim_to_width::<E>(width))
+    }
+
+    fn trim_to_width_isolated<E: Ellipsis>(&self, width: usize) -> String {
+        let value: &'_ str = self.deref().as_ref();
+
+        bidi::isolate(&value.trim_to_width::<E>(width))
+    }
+
+    fn trim_to_length_into<E: Ellipsis>(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let value: &'_ str = self.deref().as_ref();
+
+        let mut end = 0;
+        for piece in fixed_parts::<E>(value, buf.len())? {
+            buf[end..end + piece.len()].copy_from_slice(piece.as_bytes());
+            end += piece.len();
+        }
+
+        Ok(end)
+    }
+
+    #[cfg(feature = "heapless")]
+    fn trim_to_length_heapless<E: Ellipsis, const N: usize>(
+        &self,
+        length: usize,
+    ) -> Result<heapless::String<N>, BufferTooSmall> {
+        let value: &'_ str = self.deref().as_ref();
+
+        let mut limited = heapless::String::new();
+        for piece in fixed_parts::<E>(value, length.min(N))? {
+            limited
+                .push_str(piece)
+                .expect("limited string fits within the capacity");
+        }
+
+        Ok(limited)
+    }
+
+    #[cfg(feature = "arrayvec")]
+    fn trim_to_length_array_string<E: Ellipsis, const N: usize>(
+        &self,
+        length: usize,
+    ) -> Result<arrayvec::ArrayString<N>, BufferTooSmall> {
+        let value: &'_ str = self.deref().as_ref();
+
+        let mut limited = arrayvec::ArrayString::new();
+        for piece in fixed_parts::<E>(value, length.min(N))? {
+            limited.push_str(piece);
+        }
+
+        Ok(limited)
+    }
+
+    fn trim_to_length_with<M: Marker + ?Sized>(&self, marker: &M, length: usize) -> String {
+        let value: &'_ str = self.deref().as_ref();
+
+        if value.len() <= length {
+            return value.to_owned();
+        }
+
+        // cut the string at the last cluster boundary that fits alongside the marker.
+        let marker = marker.marker();
+        let kept = self::split::cluster_floor(value, length.saturating_sub(marker.len()));
+        [&value[..kept], marker].concat()
+    }
+
+    fn trim_to_width_with<M: Marker + ?Sized>(&self, marker: &M, width: usize) -> String {
+        use self::width::str_width;
+
+        let value: &'_ str = self.deref().as_ref();
+
+        // values that fit are not limited, even if the width only has room for the marker.
+        if str_width(value) <= width {
+            return value.to_owned();
+        }
+
+        // cut the string at the last cluster boundary that fits alongside the marker.
+        let marker = marker.marker();
+        let kept = self::trim_to_width::cut(value, width, str_width(marker));
+        [&value[..kept], marker].concat()
+    }
+
+    fn trim_to_length_dynamic<D: DynamicEllipsis + ?Sized>(
+        &self,
+        length: usize,
+        ellipsis: &D,
+    ) -> String {
+        self::dynamic::length(self.deref().as_ref(), length, ellipsis)
+    }
+
+    fn trim_to_width_dynamic<D: DynamicEllipsis + ?Sized>(
+        &self,
+        width: usize,
+        ellipsis: &D,
+    ) -> String {
+        self::dynamic::width(self.deref().as_ref(), width, ellipsis)
+    }
+
+    fn trim_to_height_dynamic<D: DynamicEllipsis + ?Sized>(
+        &self,
+        height: usize,
+        ellipsis: &D,
+    ) -> String {
+        self::dynamic::height(self.deref().as_ref(), height, ellipsis)
+    }
+
+    fn trim_to_length_placed<E: Ellipsis>(
+        &self,
+        length: usize,
+        placement: ellipsis::Placement,
+    ) -> String {
+        let value: &'_ str = self.deref().as_ref();
+
+        match placement {
+            ellipsis::Placement::End => value.trim_to_length::<E>(length),
+            _ if ellipsis::is_paired::<E>() => {
+                let trim = |length| self::placement::place_length::<E>(value, length, placement);
+                ellipsis::wrap::<E>(value, length, str::len, trim)
+            }
+            _ => self::placement::place_length::<E>(value, length, placement),
+        }
+    }
+
+    fn trim_to_width_placed<E: Ellipsis>(
+        &self,
+        width: usize,
+        placement: ellipsis::Placement,
+    ) -> String {
+        let value: &'_ str = self.deref().as_ref();
+
+        match placement {
+            ellipsis::Placement::End => value.trim_to_width::<E>(width),
+            _ if ellipsis::is_paired::<E>() => {
+                let trim = |width| self::placement::place_width::<E>(value, width, placement);
+                ellipsis::wrap::<E>(value, width, width::str_width, trim)
+            }
+            _ => self::placement::place_width::<E>(value, width, placement),
+        }
+    }
+
+    fn trim_to_length_keep_end<E: Ellipsis>(&self, length: usize) -> String {
+        self.trim_to_length_placed::<E>(length, ellipsis::Placement::Start)
+    }
+
+    fn trim_to_width_keep_end<E: Ellipsis>(&self, width: usize) -> String {
+        self.trim_to_width_placed::<E>(width, ellipsis::Placement::Start)
+    }
+
+    fn trim_to_height_keep_end<E: Ellipsis>(&self, height: usize) -> String {
+        let value: &'_ str = self.deref().as_ref();
+
+        let total = Lines::new(value).count();
+        if total <= height {
+            return value.trim_to_height::<E>(height);
+        }
+
+        // skip the leading lines, leaving room for the ellipsis.
+        let kept = height.saturating_sub(1);
+        let ending = self::trim_to_height::line_ending(value, Terminators::default());
+        let mut limited = E::ellipsis().to_owned();
+        for line in Lines::new(value).skip(total - kept) {
+            limited.push_str(ending);
+            limited.push_str(line);
+        }
+
+        limited
+    }
+
+    fn trim_to_length_at_word<E: Ellipsis>(&self, length: usize) -> String {
+        let value: &'_ str = self.deref().as_ref();
+
+        self::word::trim_to_length_at_word::<E>(value, length)
+            .unwrap_or_else(|| value.trim_to_length::<E>(length))
+    }
+
+    fn trim_to_width_at_word<E: Ellipsis>(&self, width: usize) -> String {
+        let value: &'_ str = self.deref().as_ref();
+
+        self::word::trim_to_width_at_word::<E>(value, width)
+            .unwrap_or_else(|| value.trim_to_width::<E>(width))
+    }
+
+    fn trim_to_length_within<E: Ellipsis>(&self, budget: RangeInclusive<usize>) -> String {
+        self::within::within_length::<E>(self.deref().as_ref(), budget)
+    }
+
+    fn trim_to_width_within<E: Ellipsis>(&self, budget: RangeInclusive<usize>) -> String {
+        self::within::within_width::<E>(self.deref().as_ref(), budget)
+    }
+
+    fn write_limited<E: Ellipsis, W: std::fmt::Write>(
+        &self,
+        length: usize,
+        out: &mut W,
+    ) -> std::fmt::Result {
+        let value: &'_ str = self.deref().as_ref();
+
+        self::trim_to_length::pieces::<E>(value, length)
+            .into_iter()
+            .try_for_each(|piece| out.write_str(piece))
+    }
+
+    fn display_trimmed<E: Ellipsis>(&self, length: usize) -> Trimmed<'_, E> {
+        Trimmed::new(self.deref().as_ref(), length)
+    }
+
+    fn extend_trimmed_to_length<E: Ellipsis>(&self, sink: &mut impl Extend<char>, length: usize) {
+        let value: &'_ str = self.deref().as_ref();
+
+        let pieces = self::trim_to_length::pieces::<E>(value, length);
+        sink.extend(pieces.into_iter().flat_map(str::chars))
+    }
+
+    fn extend_trimmed_to_width<E: Ellipsis>(&self, sink: &mut impl Extend<char>, width: usize) {
+        let value: &'_ str = self.deref().as_ref();
+        let pieces = self::trim_to_width::pieces::<E>(value, width);
+
+        sink.extend(pieces.into_iter().flat_map(str::chars))
+    }
+
+    fn extend_trimmed_to_height<'a, E: Ellipsis>(
+        &'a self,
+        sink: &mut impl Extend<&'a str>,
+        height: usize,
+    ) {
+        use {
+            self::trim_to_height::{line_ending, Lines},
+            crate::iter::{Height, Limited},
+            tap::Pipe,
+        };
+
+        let value: &'a str = self.deref().as_ref();
+        let ending = line_ending(value, Terminators::default());
+
+        value
+            .pipe(Lines::new)
+            .pipe(Height::<_, E>::new)
+            .limited(height)
+            .enumerate()
+            .flat_map(|(i, line)| [(i > 0).then_some(ending), Some(line)])
+            .flatten()
+            .pipe(|pieces| sink.extend(pieces))
+    }
+
+    fn trim_to_length_resumable<E: Ellipsis>(
+        &self,
+        token: resume::Token,
+        length: usize,
+    ) -> (String, Option<resume::Token>) {
+        self::resume::resume_length::<E>(self.deref().as_ref(), token, length)
+    }
+
+    fn trim_to_width_resumable<E: Ellipsis>(
+        &self,
+        token: resume::Token,
+        width: usize,
+    ) -> (String, Option<resume::Token>) {
+        self::resume::resume_width::<E>(self.deref().as_ref(), token, width)
+    }
 }
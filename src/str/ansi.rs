@@ -0,0 +1,136 @@
+use super::{
+    ellipsis::Ellipsis,
+    split::{cluster_width, Clusters},
+    width::str_width,
+};
+
+/// the escape character, which begins an ansi escape sequence.
+const ESC: char = '\u{1B}';
+
+/// the bell character, which may terminate an operating system command.
+const BEL: char = '\u{07}';
+
+/// a piece of a string containing ansi escape sequences.
+#[derive(Debug, Eq, PartialEq)]
+enum Segment<'a> {
+    /// an escape sequence, e.g. `"\x1b[31m"`.
+    Escape(&'a str),
+    /// a run of text between escape sequences.
+    Text(&'a str),
+}
+
+/// an iterator over the escape sequences and text of a string.
+struct Segments<'a> {
+    rest: &'a str,
+}
+
+// === impl segments ===
+
+impl<'a> Segments<'a> {
+    /// returns a new [`Segments`] iterator.
+    fn new(s: &'a str) -> Self {
+        Self { rest: s }
+    }
+
+    /// returns the length of the escape sequence at the start of `s`.
+    ///
+    /// this recognizes control sequences (`ESC [`), operating system commands (`ESC ]`), and
+    /// two-character escapes. an unterminated sequence extends to the end of the string.
+    fn escape_len(s: &str) -> usize {
+        let mut chars = s.char_indices().skip(1);
+        let end = match chars.next() {
+            // a control sequence ends with a byte in the range `@` to `~`.
+            Some((_, '[')) => chars
+                .find(|&(_, c)| ('@'..='~').contains(&c))
+                .map(|(i, c)| i + c.len_utf8()),
+            // an operating system command ends with a bell, or a string terminator `ESC \`.
+            Some((_, ']')) => {
+                let mut chars = chars.peekable();
+                let mut end = None;
+                while let Some((i, c)) = chars.next() {
+                    match (c, chars.peek()) {
+                        (BEL, _) => end = Some(i + 1),
+                        (ESC, Some(&(j, '\\'))) => end = Some(j + 1),
+                        _ => continue,
+                    }
+                    break;
+                }
+                end
+            }
+            Some((i, c)) => Some(i + c.len_utf8()),
+            None => None,
+        };
+
+        end.unwrap_or(s.len())
+    }
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = Segment<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { rest } = self;
+
+        if rest.is_empty() {
+            return None;
+        }
+
+        let (segment, remaining) = if rest.starts_with(ESC) {
+            let (escape, remaining) = rest.split_at(Self::escape_len(rest));
+            (Segment::Escape(escape), remaining)
+        } else {
+            let end = rest.find(ESC).unwrap_or(rest.len());
+            let (text, remaining) = rest.split_at(end);
+            (Segment::Text(text), remaining)
+        };
+
+        *rest = remaining;
+        Some(segment)
+    }
+}
+
+/// returns the visual width of a string, ignoring ansi escape sequences.
+pub(super) fn width(value: &str) -> usize {
+    Segments::new(value)
+        .filter_map(|segment| match segment {
+            Segment::Text(text) => Some(text),
+            Segment::Escape(_) => None,
+        })
+        .flat_map(Clusters::new)
+        .map(cluster_width)
+        .sum()
+}
+
+/// returns a string containing ansi escape sequences, limited by width.
+///
+/// escape sequences are zero columns wide, and are never cut. escape sequences in the part of the
+/// string that is cut are kept after the ellipsis, so that e.g. a trailing reset is not lost.
+pub(super) fn trim_to_width<E: Ellipsis>(value: &str, width: usize) -> String {
+    if self::width(value) <= width {
+        return value.to_owned();
+    }
+
+    let room = width.saturating_sub(str_width(E::ellipsis()));
+    let (mut kept, mut escapes) = (String::with_capacity(value.len()), String::new());
+    let mut used = 0;
+
+    for segment in Segments::new(value) {
+        match segment {
+            Segment::Escape(escape) if escapes.is_empty() && used <= room => kept.push_str(escape),
+            Segment::Escape(escape) => escapes.push_str(escape),
+            Segment::Text(text) => {
+                for cluster in Clusters::new(text) {
+                    used += cluster_width(cluster);
+                    if used > room {
+                        break;
+                    }
+                    kept.push_str(cluster);
+                }
+            }
+        }
+    }
+
+    kept.push_str(E::ellipsis());
+    kept.push_str(&escapes);
+    kept
+}
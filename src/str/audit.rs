@@ -0,0 +1,76 @@
+use {
+    super::{ellipsis::Ellipsis, trim_to_height::Lines, width::str_width},
+    std::fmt::Write,
+};
+
+/// a metric by which a budget is measured.
+#[derive(Clone, Copy)]
+pub(super) enum Metric {
+    /// the length of a string, in bytes.
+    Length,
+    /// the visual width of a string, in columns.
+    Width,
+    /// the height of a string, in lines.
+    Height,
+}
+
+// === impl metric ===
+
+impl Metric {
+    /// returns the size of a string, according to this metric.
+    fn measure(self, s: &str) -> usize {
+        match self {
+            Self::Length => s.len(),
+            Self::Width => str_width(s),
+            Self::Height => Lines::new(s).count(),
+        }
+    }
+
+    /// returns the name of the units of this metric.
+    fn units(self) -> &'static str {
+        match self {
+            Self::Length => "bytes",
+            Self::Width => "columns",
+            Self::Height => "lines",
+        }
+    }
+}
+
+/// checks that `output` fits within `budget`, panicking if it does not.
+///
+/// if the ellipsis itself does not fit within the budget, an output consisting of only the
+/// ellipsis, along with any leading or trailing parts, is permitted.
+#[track_caller]
+pub(super) fn check<E: Ellipsis>(metric: Metric, input: &str, output: &str, budget: usize) {
+    let size = metric.measure(output);
+    let ellipsis = [E::leading(), E::ellipsis(), E::trailing()].concat();
+    let ellipsis = ellipsis.as_str();
+
+    if size <= budget || (output == ellipsis && metric.measure(ellipsis) > budget) {
+        return;
+    }
+
+    let units = metric.units();
+    let mut diagnostic = format!("trimmed output exceeds its budget of {budget} {units}\n");
+    let _ = writeln!(diagnostic, "  output:   {output:?} ({size} {units})");
+    let _ = writeln!(
+        diagnostic,
+        "  input:    {input:?} ({} {units})",
+        metric.measure(input)
+    );
+    let _ = writeln!(
+        diagnostic,
+        "  ellipsis: {ellipsis:?} ({} {units})",
+        metric.measure(ellipsis)
+    );
+    for c in output.chars().filter(|c| !c.is_ascii()) {
+        let _ = writeln!(
+            diagnostic,
+            "  {c:?} (U+{:04X}) is {} columns wide",
+            c as u32,
+            str_width(c.encode_utf8(&mut [0; 4]))
+        );
+    }
+
+    panic!("{diagnostic}");
+}
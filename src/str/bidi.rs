@@ -0,0 +1,158 @@
+use {std::borrow::Cow, tap::Pipe};
+
+/// the U+200F RIGHT-TO-LEFT MARK character.
+///
+/// this is a zero-width, strongly right-to-left character. placing it after an ellipsis ensures
+/// that the (directionally neutral) ellipsis is rendered as part of the right-to-left text that
+/// precedes it.
+pub const RLM: char = '\u{200F}';
+
+/// the U+2068 FIRST STRONG ISOLATE character.
+///
+/// this begins an isolate, whose direction is that of the first strongly directional character
+/// inside of it. see [`isolate()`] for more information.
+pub const FSI: char = '\u{2068}';
+
+/// the U+2069 POP DIRECTIONAL ISOLATE character.
+///
+/// this ends an isolate begun by [`FSI`], or by a left-to-right or right-to-left isolate.
+pub const PDI: char = '\u{2069}';
+
+/// the predominant direction of a string.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Direction {
+    /// left-to-right text, e.g. latin or cyrillic scripts.
+    LeftToRight,
+    /// right-to-left text, e.g. hebrew or arabic scripts.
+    RightToLeft,
+}
+
+/// returns the predominant [`Direction`] of a string.
+///
+/// this counts the strongly directional characters in the string. a string is considered to be
+/// right-to-left if it contains more right-to-left characters than left-to-right characters.
+///
+/// ```
+/// use shear::str::bidi::{direction, Direction};
+///
+/// assert_eq!(direction("hello"), Direction::LeftToRight);
+/// assert_eq!(direction("שלום"), Direction::RightToLeft);
+/// assert_eq!(direction("12345"), Direction::LeftToRight);
+/// ```
+pub fn direction(s: &str) -> Direction {
+    let (mut ltr, mut rtl) = (0_usize, 0_usize);
+
+    for c in s.chars() {
+        if is_rtl(c) {
+            rtl += 1;
+        } else if c.is_alphabetic() {
+            ltr += 1;
+        }
+    }
+
+    if rtl > ltr {
+        Direction::RightToLeft
+    } else {
+        Direction::LeftToRight
+    }
+}
+
+/// wraps a string in a directional isolate.
+///
+/// the contents of an isolate, including a trailing ellipsis, are laid out separately from the
+/// text around them. this prevents an ellipsis from being visually reordered into the middle of
+/// right-to-left text, or the text surrounding it. unbalanced directional controls are removed
+/// first, see [`balance()`].
+///
+/// ```
+/// use shear::str::bidi::{isolate, FSI, PDI};
+///
+/// assert_eq!(isolate("שלום…"), format!("{FSI}שלום…{PDI}"));
+/// ```
+pub fn isolate(s: &str) -> String {
+    let balanced = balance(s);
+
+    let mut isolated = String::with_capacity(balanced.len() + FSI.len_utf8() + PDI.len_utf8());
+    isolated.push(FSI);
+    isolated.push_str(&balanced);
+    isolated.push(PDI);
+    isolated
+}
+
+/// removes unbalanced directional controls from a string.
+///
+/// truncating a string can remove the character that closes a directional embedding, override,
+/// or isolate. the rest of the string, and any ellipsis appended to it, would then be laid out
+/// in that direction. this removes any such control that is not matched, as well as closing
+/// controls that do not match an earlier opening control. the string is borrowed if every
+/// control is balanced.
+///
+/// ```
+/// use shear::str::bidi::balance;
+///
+/// // a right-to-left override, whose closing U+202C POP DIRECTIONAL FORMATTING was trimmed.
+/// assert_eq!(balance("file: \u{202E}txt.exe…"), "file: txt.exe…");
+/// assert_eq!(balance("\u{2067}שלום\u{2069}"), "\u{2067}שלום\u{2069}");
+/// ```
+pub fn balance(s: &str) -> Cow<'_, str> {
+    // the byte offsets of the controls that are still open, and whether each is an isolate.
+    let mut open: Vec<(usize, bool)> = Vec::new();
+    let mut unmatched = Vec::new();
+
+    for (i, c) in s.char_indices() {
+        match c {
+            LRE | RLE | LRO | RLO => open.push((i, false)),
+            LRI | RLI | FSI => open.push((i, true)),
+            // a pop directional formatting closes the innermost embedding or override, but not
+            // an isolate.
+            PDF => match open.pop_if(|(_, isolate)| !*isolate) {
+                Some(_) => {}
+                None => unmatched.push(i),
+            },
+            // a pop directional isolate closes the innermost isolate, and any embeddings within it.
+            PDI => match open.iter().rposition(|&(_, isolate)| isolate) {
+                Some(j) => open.truncate(j),
+                None => unmatched.push(i),
+            },
+            _ => continue,
+        }
+    }
+
+    unmatched.extend(open.into_iter().map(|(i, _)| i));
+    if unmatched.is_empty() {
+        return Cow::Borrowed(s);
+    }
+
+    s.char_indices()
+        .filter(|(i, _)| !unmatched.contains(i))
+        .map(|(_, c)| c)
+        .collect::<String>()
+        .pipe(Cow::Owned)
+}
+
+/// the U+202A LEFT-TO-RIGHT EMBEDDING character.
+const LRE: char = '\u{202A}';
+/// the U+202B RIGHT-TO-LEFT EMBEDDING character.
+const RLE: char = '\u{202B}';
+/// the U+202C POP DIRECTIONAL FORMATTING character.
+const PDF: char = '\u{202C}';
+/// the U+202D LEFT-TO-RIGHT OVERRIDE character.
+const LRO: char = '\u{202D}';
+/// the U+202E RIGHT-TO-LEFT OVERRIDE character.
+const RLO: char = '\u{202E}';
+/// the U+2066 LEFT-TO-RIGHT ISOLATE character.
+const LRI: char = '\u{2066}';
+/// the U+2067 RIGHT-TO-LEFT ISOLATE character.
+const RLI: char = '\u{2067}';
+
+/// returns true if the given character belongs to a right-to-left script.
+pub(crate) fn is_rtl(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x0590..=0x08FF // hebrew, arabic, syriac, thaana, nko, samaritan, mandaic.
+            | 0xFB1D..=0xFDFF // hebrew and arabic presentation forms.
+            | 0xFE70..=0xFEFF // arabic presentation forms.
+            | 0x10800..=0x10FFF // historic right-to-left scripts.
+            | 0x1E800..=0x1EFFF // adlam, arabic mathematical symbols, etc.
+    ) && c.is_alphabetic()
+}
@@ -0,0 +1,61 @@
+use super::ellipsis::{self, Ellipsis};
+
+/// moves the cut in a limited string back to the last position outside of any brackets or quotes.
+///
+/// `limited` is `value` as limited by some other method. if it was trimmed, and the retained text
+/// ends inside of an unclosed bracket or quote, the cut is moved back to the last position at
+/// which every bracket and quote was closed. if there is no such position, the cut is moved back
+/// to the last position outside of a quote instead.
+pub(super) fn back_off<E: Ellipsis>(value: &str, limited: String) -> String {
+    ellipsis::recut::<E>(value, limited, |kept| {
+        let (balanced, unquoted) = scan(kept);
+        match balanced {
+            0 => unquoted,
+            balanced => balanced,
+        }
+    })
+}
+
+/// returns the last balanced position in `s`, and the last position outside of a quote.
+///
+/// a backslash escapes the character that follows it inside of a quote. a `'` only begins a
+/// quote if it does not follow a letter or digit, so that apostrophes are not mistaken for quotes.
+fn scan(s: &str) -> (usize, usize) {
+    let (mut open, mut quote) = (Vec::new(), None);
+    let (mut balanced, mut unquoted) = (0, 0);
+    let (mut prev, mut escaped) = (None, false);
+
+    for (i, c) in s.char_indices() {
+        match quote {
+            Some(_) if std::mem::take(&mut escaped) => {}
+            Some(_) if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '`' => quote = Some(c),
+                '\'' if !prev.is_some_and(char::is_alphanumeric) => quote = Some(c),
+                '(' | '[' | '{' => open.push(c),
+                ')' | ']' | '}' => {
+                    open.pop_if(|&mut o| closes(o, c));
+                }
+                _ => {}
+            },
+        }
+
+        let end = i + c.len_utf8();
+        if quote.is_none() {
+            unquoted = end;
+            if open.is_empty() {
+                balanced = end;
+            }
+        }
+        prev = Some(c);
+    }
+
+    (balanced, unquoted)
+}
+
+/// returns true if `close` is the closing bracket for `open`.
+fn closes(open: char, close: char) -> bool {
+    matches!((open, close), ('(', ')') | ('[', ']') | ('{', '}'))
+}
@@ -0,0 +1,345 @@
+use {
+    super::{
+        ellipsis::Ellipsis,
+        trim_to_height::{line_ending, Lines, Terminators},
+        width::str_width,
+        Limited,
+    },
+    std::marker::PhantomData,
+};
+
+/// limits a string by height, replacing the omitted lines with a counting marker.
+///
+/// if a string has more than `height` lines, the last line is replaced by a marker line that
+/// counts the omitted lines, e.g. `"… 1,204 more lines"`. if a width is given using
+/// [`Counted::width()`], each line, including the marker line, is limited to that many columns.
+/// a marker that is too wide falls back to an abbreviated count, e.g. `"… 1.2k more lines"`,
+/// before it is trimmed.
+///
+/// the last lines of a string can be kept as well, using [`Counted::tail()`]. the marker line
+/// is then placed between the first and last lines, e.g. `"… 42 lines omitted …"`. this is how
+/// compilers and continuous integration systems commonly elide long output.
+///
+/// when eliding structured text, such as code or yaml, [`Counted::indent()`] indents the marker
+/// line to match the first line that it omits, so that the output still reads as structured.
+///
+/// # examples
+///
+/// ```
+/// use shear::str::{counted::Counted, ellipsis::Horizontal};
+///
+/// let text = (1..=1208).map(|i| format!("line {i}")).collect::<Vec<_>>().join("\n");
+///
+/// let limited = Counted::<Horizontal>::new(4).trim(&text);
+/// assert_eq!(limited, "line 1\nline 2\nline 3\n… 1,205 more lines");
+///
+/// let limited = Counted::<Horizontal>::new(2).width(17).trim(&text);
+/// assert_eq!(limited, "line 1\n… 1.2k more lines");
+///
+/// let limited = Counted::<Horizontal>::new(2).width(12).trim(&text);
+/// assert_eq!(limited, "line 1\n… 1.2k more…");
+///
+/// let limited = Counted::<Horizontal>::new(4).tail(2).trim(&text);
+/// assert_eq!(limited, "line 1\n… 1,205 lines omitted …\nline 1207\nline 1208");
+/// ```
+pub struct Counted<E, N = English> {
+    /// the maximum number of lines.
+    height: usize,
+    /// the maximum width of a line, if any.
+    width: Option<usize>,
+    /// the number of lines to keep from the end of the string.
+    tail: usize,
+    /// whether the marker line is indented to match the omitted lines.
+    indent: bool,
+    /// whether the count should always be abbreviated.
+    abbreviate: bool,
+    /// the format of the count.
+    numbers: N,
+    ellipsis: PhantomData<E>,
+}
+
+/// a format for the numbers in a counting marker.
+///
+/// this allows the count of a [`Counted`] marker, or of a
+/// [`DynamicEllipsis`][super::ellipsis::DynamicEllipsis], to be formatted according to a locale.
+/// see [`English`] and [`Separators`] for provided formats.
+///
+/// # examples
+///
+/// ```
+/// use shear::str::{counted::{Counted, NumberFormat}, ellipsis::Horizontal};
+///
+/// /// formats numbers using eastern arabic numerals.
+/// struct Arabic;
+///
+/// impl NumberFormat for Arabic {
+///     fn full(&self, n: usize) -> String {
+///         let digit = |d: char| char::from_u32(0x0660 + d.to_digit(10).unwrap()).unwrap();
+///         n.to_string().chars().map(digit).collect()
+///     }
+///
+///     fn compact(&self, n: usize) -> String {
+///         self.full(n)
+///     }
+/// }
+///
+/// let limited = Counted::<Horizontal>::new(1).numbers(Arabic).trim("one\ntwo\nthree");
+/// assert_eq!(limited, "… ٣ more lines");
+/// ```
+pub trait NumberFormat {
+    /// formats a number in full, e.g. `"1,204"`.
+    fn full(&self, n: usize) -> String;
+
+    /// formats a number in compact notation, e.g. `"1.2k"`.
+    ///
+    /// this is used when a count is abbreviated. the number should be rounded down, so that the
+    /// count is never overstated.
+    fn compact(&self, n: usize) -> String;
+}
+
+/// english number formatting, e.g. `"1,204"` and `"1.2k"`.
+///
+/// this is the default format of a [`Counted`] marker.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct English;
+
+/// number formatting with the given separators.
+///
+/// compact notation uses the `k`, `M`, and `G` suffixes.
+///
+/// ```
+/// use shear::str::counted::{NumberFormat, Separators};
+///
+/// let german = Separators::new(".", ",");
+/// assert_eq!(german.full(1_204_000), "1.204.000");
+/// assert_eq!(german.compact(1_204), "1,2k");
+///
+/// let french = Separators::new("\u{202F}", ",");
+/// assert_eq!(french.full(1_204), "1\u{202F}204");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Separators<'a> {
+    /// the separator placed between groups of thousands.
+    thousands: &'a str,
+    /// the decimal separator.
+    decimal: &'a str,
+}
+
+// === impl counted ===
+
+impl<E: Ellipsis> Counted<E> {
+    /// returns a new [`Counted`] limit of `height` lines.
+    pub fn new(height: usize) -> Self {
+        Self {
+            height,
+            width: None,
+            tail: 0,
+            indent: false,
+            abbreviate: false,
+            numbers: English,
+            ellipsis: PhantomData,
+        }
+    }
+}
+
+impl<E: Ellipsis, N: NumberFormat> Counted<E, N> {
+    /// formats the count of omitted lines using the given [`NumberFormat`].
+    pub fn numbers<F: NumberFormat>(self, numbers: F) -> Counted<E, F> {
+        let Self {
+            height,
+            width,
+            tail,
+            indent,
+            abbreviate,
+            numbers: _,
+            ellipsis,
+        } = self;
+
+        Counted {
+            height,
+            width,
+            tail,
+            indent,
+            abbreviate,
+            numbers,
+            ellipsis,
+        }
+    }
+
+    /// limits each line, including the marker line, to `width` columns.
+    pub fn width(self, width: usize) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
+    }
+
+    /// keeps the last `lines` lines of a string, placing the marker line before them.
+    ///
+    /// the marker line and the last lines count towards the height. if there is not room for all
+    /// of them, fewer of the last lines are kept.
+    pub fn tail(self, lines: usize) -> Self {
+        Self {
+            tail: lines,
+            ..self
+        }
+    }
+
+    /// indents the marker line to match the first line that it omits.
+    ///
+    /// ```
+    /// use shear::str::{counted::Counted, ellipsis::Horizontal};
+    ///
+    /// let yaml = "jobs:\n  test:\n    runs-on: linux\n    steps: []\n  lint:\n    runs-on: linux";
+    /// let limited = Counted::<Horizontal>::new(3).tail(1).indent().trim(yaml);
+    ///
+    /// assert_eq!(limited, "jobs:\n  … 4 lines omitted …\n    runs-on: linux");
+    /// ```
+    pub fn indent(self) -> Self {
+        Self {
+            indent: true,
+            ..self
+        }
+    }
+
+    /// always abbreviates the count of omitted lines, e.g. `"1.2k"` rather than `"1,204"`.
+    pub fn abbreviate(self) -> Self {
+        Self {
+            abbreviate: true,
+            ..self
+        }
+    }
+
+    /// returns `text`, limited by height.
+    pub fn trim(&self, text: &str) -> String {
+        let ending = line_ending(text, Terminators::default());
+        let total = Lines::new(text).count();
+        if total <= self.height {
+            return self.lines(Lines::new(text), ending);
+        }
+
+        let kept = self.height.saturating_sub(1);
+        let tail = self.tail.min(kept);
+        let head = kept - tail;
+
+        let mut limited = self.lines(Lines::new(text).take(head), ending);
+        if head > 0 {
+            limited.push_str(ending);
+        }
+        let indent = match self.indent {
+            true => Lines::new(text)
+                .nth(head)
+                .map(indentation)
+                .unwrap_or_default(),
+            false => "",
+        };
+        limited.push_str(&self.marker(total - kept, tail > 0, indent));
+        if tail > 0 {
+            limited.push_str(ending);
+            limited.push_str(&self.lines(Lines::new(text).skip(total - tail), ending));
+        }
+        limited
+    }
+
+    /// joins lines together with the given line ending, limiting each to the width if needed.
+    fn lines<'a>(&self, lines: impl Iterator<Item = &'a str>, ending: &str) -> String {
+        let mut joined = String::new();
+        for (i, line) in lines.enumerate() {
+            if i > 0 {
+                joined.push_str(ending);
+            }
+            match self.width {
+                Some(width) => joined.push_str(&line.trim_to_width::<E>(width)),
+                None => joined.push_str(line),
+            }
+        }
+        joined
+    }
+
+    /// returns the marker line for `omitted` lines.
+    ///
+    /// a marker in the `middle` of the kept lines is enclosed by ellipses on both sides. the
+    /// marker is prefixed with the given `indent`.
+    fn marker(&self, omitted: usize, middle: bool, indent: &str) -> String {
+        let marker = |count: String| {
+            let (noun, ellipsis) = (if omitted == 1 { "line" } else { "lines" }, E::ellipsis());
+            match middle {
+                true => format!("{indent}{ellipsis} {count} {noun} omitted {ellipsis}"),
+                false => format!("{indent}{ellipsis} {count} more {noun}"),
+            }
+        };
+
+        let Some(width) = self.width else {
+            return marker(self.count(omitted));
+        };
+
+        let full = marker(self.count(omitted));
+        if str_width(&full) <= width {
+            return full;
+        }
+
+        marker(self.numbers.compact(omitted)).trim_to_width::<E>(width)
+    }
+
+    /// formats a count of omitted lines.
+    fn count(&self, n: usize) -> String {
+        match self.abbreviate {
+            true => self.numbers.compact(n),
+            false => self.numbers.full(n),
+        }
+    }
+}
+
+/// returns the leading whitespace of a line.
+fn indentation(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+// === impl english ===
+
+impl NumberFormat for English {
+    fn full(&self, n: usize) -> String {
+        Separators::new(",", ".").full(n)
+    }
+
+    fn compact(&self, n: usize) -> String {
+        Separators::new(",", ".").compact(n)
+    }
+}
+
+// === impl separators ===
+
+impl<'a> Separators<'a> {
+    /// returns a new [`Separators`] format.
+    pub fn new(thousands: &'a str, decimal: &'a str) -> Self {
+        Self { thousands, decimal }
+    }
+}
+
+impl NumberFormat for Separators<'_> {
+    fn full(&self, n: usize) -> String {
+        let digits = n.to_string();
+        let mut grouped = String::with_capacity(digits.len() * 2);
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                grouped.push_str(self.thousands);
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+
+    fn compact(&self, n: usize) -> String {
+        const UNITS: [(usize, &str); 3] = [(1_000_000_000, "G"), (1_000_000, "M"), (1_000, "k")];
+
+        let Some(&(scale, unit)) = UNITS.iter().find(|&&(scale, _)| n >= scale) else {
+            return n.to_string();
+        };
+
+        let (whole, tenths) = (n / scale, n % scale * 10 / scale);
+        match (whole, tenths) {
+            (whole, 0) | (whole @ 10.., _) => format!("{whole}{unit}"),
+            (whole, tenths) => format!("{whole}{}{tenths}{unit}", self.decimal),
+        }
+    }
+}
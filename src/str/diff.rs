@@ -0,0 +1,55 @@
+use super::{ellipsis::Ellipsis, Limited};
+
+/// limits unified diff text by height, never eliding its headers.
+///
+/// file headers (e.g. `diff --git`, `---`, and `+++` lines) and hunk headers (`@@` lines) are
+/// always kept, and do not count towards the height. the height is applied to the added,
+/// removed, and context lines of each hunk, as
+/// [`trim_to_height_keeping()`][Limited::trim_to_height_keeping] would apply it. lines are never
+/// altered, so the `+`, `-`, and ` ` markers at the start of each line are preserved, and a
+/// reader can see from each hunk header which lines were elided.
+///
+/// # examples
+///
+/// ```
+/// use shear::str::{diff, ellipsis::Ascii};
+///
+/// let patch = "\
+/// --- a/greeting.txt
+/// +++ b/greeting.txt
+/// @@ -1,3 +1,3 @@
+///  hello
+/// -world
+/// +there
+/// @@ -10,2 +10,2 @@
+/// -goodbye
+/// +farewell";
+///
+/// assert_eq!(
+///     diff::trim_to_height::<Ascii>(patch, 3),
+///     "--- a/greeting.txt\n+++ b/greeting.txt\n@@ -1,3 +1,3 @@\n hello\n-world\n...\n@@ -10,2 +10,2 @@"
+/// );
+/// ```
+pub fn trim_to_height<E: Ellipsis>(diff: &str, height: usize) -> String {
+    diff.trim_to_height_keeping::<E>(height, is_header)
+}
+
+/// returns true if `line` is a header of a unified diff.
+///
+/// this includes file headers, such as `diff --git`, `index`, `---`, and `+++` lines, as well as
+/// hunk headers, which begin with `@@`. note that a removed line whose content begins with `-- `
+/// cannot be told apart from a `---` header without counting the lines of its hunk, and is
+/// treated as a header.
+pub fn is_header(line: &str) -> bool {
+    const HEADERS: [&str; 7] = [
+        "@@",
+        "--- ",
+        "+++ ",
+        "diff ",
+        "index ",
+        "new file",
+        "deleted file",
+    ];
+
+    HEADERS.iter().any(|header| line.starts_with(header))
+}
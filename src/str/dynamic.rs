@@ -0,0 +1,79 @@
+use {
+    super::{
+        ellipsis::DynamicEllipsis,
+        split,
+        trim_to_height::{line_ending, Lines, Terminators},
+        width::str_width,
+    },
+    crate::iter::Height,
+};
+
+/// limits `value` to `budget` with a dynamic ellipsis.
+///
+/// `measure` returns the size of a string, `split_at` splits a string after the prefix that fits
+/// within a budget, and `count` counts the dropped units of the overflow.
+fn limit<D: DynamicEllipsis + ?Sized>(
+    value: &str,
+    budget: usize,
+    ellipsis: &D,
+    measure: impl Fn(&str) -> usize,
+    split_at: impl Fn(&str, usize) -> (&str, &str),
+    count: impl Fn(&str) -> usize,
+) -> String {
+    if measure(value) <= budget {
+        return value.to_owned();
+    }
+
+    // the size of the ellipsis depends upon how much is dropped, which in turn depends upon the
+    // size of the ellipsis. reserve more room for the ellipsis until it fits. the reserved room
+    // grows each time, so this finishes once the kept prefix is empty, if not sooner.
+    let mut reserved = 0;
+    loop {
+        let (kept, dropped) = split_at(value, budget.saturating_sub(reserved));
+        let marker = ellipsis.ellipsis(count(dropped));
+        let size = measure(&marker);
+        if size <= reserved || (kept.is_empty() && reserved > 0) {
+            return [kept, &marker].concat();
+        }
+        reserved = size;
+    }
+}
+
+/// limits `value` by length with a dynamic ellipsis, counting the dropped characters.
+pub(super) fn length<D: DynamicEllipsis + ?Sized>(
+    value: &str,
+    length: usize,
+    ellipsis: &D,
+) -> String {
+    let count = |dropped: &str| dropped.chars().count();
+    limit(value, length, ellipsis, str::len, split_at_cluster, count)
+}
+
+/// splits `value` after the clusters that fit within `length` bytes.
+fn split_at_cluster(value: &str, length: usize) -> (&str, &str) {
+    value.split_at(split::cluster_floor(value, length))
+}
+
+/// limits `value` by width with a dynamic ellipsis, counting the dropped characters.
+pub(super) fn width<D: DynamicEllipsis + ?Sized>(
+    value: &str,
+    width: usize,
+    ellipsis: &D,
+) -> String {
+    let count = |dropped: &str| dropped.chars().count();
+    limit(value, width, ellipsis, str_width, split::at_width, count)
+}
+
+/// limits `value` by height with a dynamic ellipsis, counting the dropped lines.
+///
+/// the ellipsis takes the place of the last line.
+pub(super) fn height<D: DynamicEllipsis + ?Sized>(
+    value: &str,
+    height: usize,
+    ellipsis: &D,
+) -> String {
+    Height::new(Lines::new(value))
+        .limited_dynamic(height, ellipsis)
+        .collect::<Vec<_>>()
+        .join(line_ending(value, Terminators::default()))
+}
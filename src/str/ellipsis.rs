@@ -4,8 +4,97 @@
 pub trait Ellipsis {
     /// return the ellipsis as a static string.
     fn ellipsis() -> &'static str;
+
+    /// return the leading part of a paired ellipsis.
+    ///
+    /// a paired ellipsis wraps a truncated string, e.g. in quotation marks. if a string is
+    /// truncated, the leading part is placed before it, and the [`trailing()`][Ellipsis::trailing]
+    /// part is placed after it. both parts count towards the budget of the limited string.
+    /// strings that fit are not wrapped.
+    ///
+    /// by default, this is empty.
+    ///
+    /// ```
+    /// use shear::str::{ellipsis::{Guillemets, Placement}, Limited};
+    ///
+    /// let s = "a very long string value";
+    ///
+    /// assert_eq!(s.trim_to_width::<Guillemets>(12), "«a very lo…»");
+    /// assert_eq!(s.trim_to_width_placed::<Guillemets>(12, Placement::Middle), "«a ver…alue»");
+    /// assert_eq!("a short value".trim_to_width::<Guillemets>(16), "a short value");
+    /// ```
+    fn leading() -> &'static str {
+        ""
+    }
+
+    /// return the trailing part of a paired ellipsis.
+    ///
+    /// see [`leading()`][Ellipsis::leading] for more information. by default, this is empty.
+    fn trailing() -> &'static str {
+        ""
+    }
+}
+
+/// an ellipsis that may carry runtime state.
+///
+/// unlike [`Ellipsis`], this takes `&self`, so that a marker can hold e.g. a localized string
+/// loaded at startup, or a themed style. every [`Ellipsis`] is also a [`Marker`].
+///
+/// strings are markers too, so an ellipsis read from e.g. a configuration file can be used
+/// directly:
+///
+/// ```
+/// use shear::str::Limited;
+///
+/// let marker: String = String::from(" [more]");
+/// let limited = "a very long string value".trim_to_length_with(&marker, 16);
+///
+/// assert_eq!(limited, "a very lo [more]");
+/// assert_eq!("a very long string value".trim_to_width_with("~", 8), "a very ~");
+/// ```
+///
+/// see [`Limited::trim_to_length_with()`][super::Limited::trim_to_length_with] for more
+/// information.
+pub trait Marker {
+    /// return the ellipsis.
+    fn marker(&self) -> &str;
+}
+
+/// an ellipsis generated from the amount of omitted content.
+///
+/// unlike [`Ellipsis`] and [`Marker`], this is given the number of units that were dropped once
+/// truncation has been decided, so that it can produce markers like `"… (+37 chars)"`. any
+/// `Fn(usize) -> String` closure is a [`DynamicEllipsis`].
+///
+/// see [`Limited::trim_to_length_dynamic()`][super::Limited::trim_to_length_dynamic] for more
+/// information.
+pub trait DynamicEllipsis {
+    /// return the ellipsis for a string, given the number of `dropped` units.
+    fn ellipsis(&self, dropped: usize) -> String;
 }
 
+/// where an ellipsis is placed within a limited string.
+///
+/// see [`Limited::trim_to_length_placed()`][super::Limited::trim_to_length_placed] for more
+/// information.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Placement {
+    /// the start of the string is cut, and the ellipsis is placed before the kept suffix.
+    Start,
+    /// the middle of the string is cut, and the ellipsis is placed between a kept prefix and
+    /// suffix.
+    Middle,
+    /// the end of the string is cut, and the ellipsis is placed after the kept prefix.
+    #[default]
+    End,
+}
+
+/// a paired ellipsis, wrapping a truncated string in guillemets, `"«…»"`.
+///
+/// this places `"«"` before the truncated string, and `"…»"` after it. this is 3 columns wide,
+/// and 7 bytes long.
+pub struct Guillemets;
+
 /// an asci ellipsis.
 pub struct Ascii;
 
@@ -15,6 +104,123 @@ pub struct Contd;
 /// a horizontal utf-8 ellipsis.
 pub struct Horizontal;
 
+/// a horizontal utf-8 ellipsis, preceded by a narrow no-break space, `"\u{202F}…"`.
+///
+/// this follows the french typographic convention of separating an ellipsis from the preceding
+/// word. the narrow no-break space keeps the ellipsis from being wrapped onto its own line.
+///
+/// this is 2 characters, 2 columns wide, and 6 bytes long. the space counts towards the width and
+/// length of the ellipsis when trimming.
+pub struct French;
+
+/// a midline horizontal utf-8 ellipsis, `"⋯"`.
+///
+/// this is 1 column wide, and 3 bytes long.
+pub struct Midline;
+
+/// a vertical utf-8 ellipsis, `"⋮"`.
+///
+/// this is 1 column wide, and 3 bytes long. this reads naturally as a marker for omitted lines,
+/// e.g. in place of the last line of a string limited by height.
+pub struct Vertical;
+
+/// a sequence of bullets, `"•••"`.
+///
+/// this is 3 columns wide, and 9 bytes long.
+pub struct Bullets;
+
+/// a rightwards arrow, `"→"`.
+///
+/// this is 1 column wide, and 3 bytes long.
+pub struct Arrow;
+
+/// a tilde, `"~"`.
+///
+/// this is 1 column wide, and 1 byte long.
+pub struct Tilde;
+
+// === impl e: ellipsis ===
+
+impl<E: Ellipsis> Marker for E {
+    fn marker(&self) -> &str {
+        E::ellipsis()
+    }
+}
+
+/// returns true if the ellipsis `E` has a leading or trailing part.
+pub(crate) fn is_paired<E: Ellipsis>() -> bool {
+    !E::leading().is_empty() || !E::trailing().is_empty()
+}
+
+/// limits `value` to `budget` using a paired ellipsis `E`.
+///
+/// `measure` returns the size of a string, and `trim` limits `value` to a smaller budget, leaving
+/// room for the leading and trailing parts of the ellipsis.
+pub(crate) fn wrap<E: Ellipsis>(
+    value: &str,
+    budget: usize,
+    measure: impl Fn(&str) -> usize,
+    trim: impl FnOnce(usize) -> String,
+) -> String {
+    if measure(value) <= budget {
+        return value.to_owned();
+    }
+
+    let (leading, trailing) = (E::leading(), E::trailing());
+    let limited = trim(budget.saturating_sub(measure(leading) + measure(trailing)));
+    [leading, &limited, trailing].concat()
+}
+
+/// moves the cut in `limited`, a trimmed copy of `value`, back to an earlier position.
+///
+/// `cut` is given the text retained before the ellipsis `E`, and returns the length to keep. if
+/// `value` was not trimmed, or `cut` keeps all of the retained text, `limited` is returned as-is.
+pub(crate) fn recut<E: Ellipsis>(
+    value: &str,
+    limited: String,
+    cut: impl FnOnce(&str) -> usize,
+) -> String {
+    if limited == value {
+        return limited;
+    }
+
+    let (leading, ellipsis, trailing) = (E::leading(), E::ellipsis(), E::trailing());
+    let Some(kept) = limited
+        .strip_prefix(leading)
+        .and_then(|s| s.strip_suffix(trailing))
+        .and_then(|s| s.strip_suffix(ellipsis))
+    else {
+        return limited;
+    };
+
+    match cut(kept) {
+        end if end < kept.len() => [leading, &kept[..end], ellipsis, trailing].concat(),
+        _ => limited,
+    }
+}
+
+// === impl f: fn(usize) -> string ===
+
+impl<F: Fn(usize) -> String> DynamicEllipsis for F {
+    fn ellipsis(&self, dropped: usize) -> String {
+        self(dropped)
+    }
+}
+
+// === impl str ===
+
+impl Marker for str {
+    fn marker(&self) -> &str {
+        self
+    }
+}
+
+impl Marker for String {
+    fn marker(&self) -> &str {
+        self
+    }
+}
+
 // === impl ascii ===
 
 impl Ellipsis for Ascii {
@@ -38,3 +244,67 @@ impl Ellipsis for Horizontal {
         "…"
     }
 }
+
+// === impl french ===
+
+impl Ellipsis for French {
+    fn ellipsis() -> &'static str {
+        "\u{202F}…"
+    }
+}
+
+// === impl midline ===
+
+impl Ellipsis for Midline {
+    fn ellipsis() -> &'static str {
+        "⋯"
+    }
+}
+
+// === impl vertical ===
+
+impl Ellipsis for Vertical {
+    fn ellipsis() -> &'static str {
+        "⋮"
+    }
+}
+
+// === impl bullets ===
+
+impl Ellipsis for Bullets {
+    fn ellipsis() -> &'static str {
+        "•••"
+    }
+}
+
+// === impl arrow ===
+
+impl Ellipsis for Arrow {
+    fn ellipsis() -> &'static str {
+        "→"
+    }
+}
+
+// === impl guillemets ===
+
+impl Ellipsis for Guillemets {
+    fn ellipsis() -> &'static str {
+        "…"
+    }
+
+    fn leading() -> &'static str {
+        "«"
+    }
+
+    fn trailing() -> &'static str {
+        "»"
+    }
+}
+
+// === impl tilde ===
+
+impl Ellipsis for Tilde {
+    fn ellipsis() -> &'static str {
+        "~"
+    }
+}
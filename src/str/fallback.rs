@@ -0,0 +1,90 @@
+use {
+    super::{ellipsis::Ellipsis, width::str_width, word::trim_to_width_at_word, Limited},
+    tap::Pipe,
+};
+
+/// a chain of trimming strategies, tried in order.
+///
+/// each strategy is given the value and the width it must fit within, and may either return a
+/// limited string, or decline by returning `None`. the first strategy to return a string wins.
+///
+/// this allows for policies like "cut at a word boundary, unless that would leave less than
+/// `k` columns; otherwise cut anywhere; if the marker does not fit at all, emit nothing" to be
+/// composed from small pieces.
+///
+/// # examples
+///
+/// ```
+/// use shear::str::{ellipsis::Horizontal, fallback::Fallback};
+///
+/// let fallback = Fallback::new()
+///     .or_nothing_below_marker::<Horizontal>()
+///     .or_word_boundary::<Horizontal>(6)
+///     .or_hard::<Horizontal>();
+///
+/// assert_eq!(fallback.trim_to_width("a very long string", 12).as_deref(), Some("a very long…"));
+/// assert_eq!(fallback.trim_to_width("a very long string", 10).as_deref(), Some("a very…"));
+/// assert_eq!(fallback.trim_to_width("a verylongstring", 10).as_deref(), Some("a verylon…"));
+/// assert_eq!(fallback.trim_to_width("a very long string", 0).as_deref(), Some(""));
+/// ```
+#[derive(Default)]
+pub struct Fallback<'a> {
+    strategies: Vec<Box<Strategy<'a>>>,
+}
+
+/// a trimming strategy, see [`Fallback`].
+type Strategy<'a> = dyn Fn(&str, usize) -> Option<String> + 'a;
+
+// === impl fallback ===
+
+impl<'a> Fallback<'a> {
+    /// returns a new, empty [`Fallback`] chain.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// adds a custom strategy to the chain.
+    pub fn or(mut self, strategy: impl Fn(&str, usize) -> Option<String> + 'a) -> Self {
+        self.strategies.push(Box::new(strategy));
+        self
+    }
+
+    /// adds a strategy that emits nothing if the [`Ellipsis`] would not fit in the width.
+    ///
+    /// values that fit are returned as-is.
+    pub fn or_nothing_below_marker<E: Ellipsis>(self) -> Self {
+        self.or(|value, width| {
+            let fits = str_width(value) <= width;
+            let too_narrow = str_width(E::ellipsis()) > width;
+            (!fits && too_narrow).then(String::new)
+        })
+    }
+
+    /// adds a strategy that cuts the value at a word boundary.
+    ///
+    /// this declines if the value has no word boundary that fits, or if the kept contents would
+    /// be narrower than `min_width` columns.
+    pub fn or_word_boundary<E: Ellipsis>(self, min_width: usize) -> Self {
+        self.or(move |value, width| {
+            let limited = trim_to_width_at_word::<E>(value, width)?;
+            let kept = str_width(&limited).saturating_sub(str_width(E::ellipsis()));
+            (limited == value || kept >= min_width).then_some(limited)
+        })
+    }
+
+    /// adds a strategy that cuts the value at any character.
+    ///
+    /// see [`Limited::trim_to_width()`] for more information. this never declines.
+    pub fn or_hard<E: Ellipsis>(self) -> Self {
+        self.or(|value, width| value.trim_to_width::<E>(width).pipe(Some))
+    }
+
+    /// returns a string limited by width, using the first strategy that does not decline.
+    ///
+    /// returns `None` if every strategy declined.
+    pub fn trim_to_width(&self, value: &str, width: usize) -> Option<String> {
+        self.strategies
+            .iter()
+            .find_map(|strategy| strategy(value, width))
+    }
+}
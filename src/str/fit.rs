@@ -0,0 +1,42 @@
+use {
+    super::{ellipsis::Ellipsis, width::str_width, Limited},
+    std::borrow::Cow,
+};
+
+/// the alignment of a string within a fixed width.
+///
+/// see [`Limited::fit_to_width()`] for more information.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Align {
+    /// pad the string on its right, aligning it to the left.
+    #[default]
+    Left,
+    /// pad the string on both sides, centering it.
+    ///
+    /// if the padding cannot be split evenly, the extra column is placed on the right.
+    Center,
+    /// pad the string on its left, aligning it to the right.
+    Right,
+}
+
+/// returns `value`, trimmed or padded with spaces to `width` columns.
+pub(super) fn fit<E: Ellipsis>(value: &str, width: usize, align: Align) -> String {
+    // strings that fit are never trimmed, even if the ellipsis itself would not fit.
+    let value = match str_width(value) <= width {
+        true => Cow::Borrowed(value),
+        false => Cow::Owned(value.trim_to_width::<E>(width)),
+    };
+
+    let padding = width.saturating_sub(str_width(&value));
+    let (left, right) = match align {
+        Align::Left => (0, padding),
+        Align::Center => (padding / 2, padding - padding / 2),
+        Align::Right => (padding, 0),
+    };
+
+    let mut fitted = String::with_capacity(value.len() + padding);
+    fitted.extend(std::iter::repeat_n(' ', left));
+    fitted.push_str(&value);
+    fitted.extend(std::iter::repeat_n(' ', right));
+    fitted
+}
@@ -0,0 +1,137 @@
+use {
+    super::{ellipsis::Ellipsis, width::char_width},
+    crate::iter::{Limited, LimitedIter},
+    std::{marker::PhantomData, str::CharIndices},
+};
+
+/// a character of a limited string, along with its byte offset in the original string.
+///
+/// characters of the [`Ellipsis`] have no offset.
+pub type Indexed = (Option<usize>, char);
+
+/// the continuation sequence of a limited [`LengthIndices`] or [`WidthIndices`] iterator.
+pub type Contd = std::iter::Map<std::str::Chars<'static>, fn(char) -> Indexed>;
+
+/// an iterator over the characters of a string and their byte offsets, limited by length.
+///
+/// this behaves like [`Limited::trim_to_length()`][super::Limited::trim_to_length], but each
+/// character is yielded alongside its byte offset in the original string. this allows the
+/// limited output to be mapped back to positions in the source.
+///
+/// ```
+/// use shear::{iter::Limited, str::{ellipsis, indices::LengthIndices}};
+///
+/// let s = "a very long string";
+/// let limited = LengthIndices::<ellipsis::Ascii>::new(s).limited(8).collect::<Vec<_>>();
+///
+/// assert_eq!(limited[..2], [(Some(0), 'a'), (Some(1), ' ')]);
+/// assert_eq!(limited[4..], [(Some(4), 'r'), (None, '.'), (None, '.'), (None, '.')]);
+/// ```
+pub struct LengthIndices<'a, E> {
+    iter: CharIndices<'a>,
+    ellipses: PhantomData<E>,
+}
+
+/// an iterator over the characters of a string and their byte offsets, limited by width.
+///
+/// see [`LengthIndices`] for more information.
+pub struct WidthIndices<'a, E> {
+    iter: CharIndices<'a>,
+    ellipses: PhantomData<E>,
+}
+
+/// returns the continuation sequence for an [`Ellipsis`].
+fn contd<E: Ellipsis>() -> Contd {
+    /// helper fn: pairs a character of the ellipsis with no offset.
+    fn unindexed(c: char) -> Indexed {
+        (None, c)
+    }
+
+    E::ellipsis().chars().map(unindexed as fn(char) -> Indexed)
+}
+
+// === impl LengthIndices ===
+
+impl<'a, E> LengthIndices<'a, E> {
+    /// returns a new [`LengthIndices`] iterator.
+    pub fn new(s: &'a str) -> Self {
+        Self {
+            iter: s.char_indices(),
+            ellipses: PhantomData,
+        }
+    }
+}
+
+/// character indices can be limited by length with an [`Ellipsis`].
+impl<E: Ellipsis> Limited for LengthIndices<'_, E> {
+    fn limited(self, size: usize) -> LimitedIter<Self> {
+        LimitedIter::new(self, size)
+    }
+
+    type Contd = Contd;
+
+    fn contd() -> Self::Contd {
+        contd::<E>()
+    }
+
+    /// counts characters according to their encoded length.
+    fn element_size((_, c): &Indexed) -> usize {
+        c.len_utf8()
+    }
+}
+
+impl<E> Iterator for LengthIndices<'_, E> {
+    type Item = Indexed;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(i, c)| (Some(i), c))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+// === impl WidthIndices ===
+
+impl<'a, E> WidthIndices<'a, E> {
+    /// returns a new [`WidthIndices`] iterator.
+    pub fn new(s: &'a str) -> Self {
+        Self {
+            iter: s.char_indices(),
+            ellipses: PhantomData,
+        }
+    }
+}
+
+/// character indices can be limited by width with an [`Ellipsis`].
+impl<E: Ellipsis> Limited for WidthIndices<'_, E> {
+    /// characters that fit are never replaced by the ellipsis, even if `size` only has room for
+    /// the ellipsis.
+    fn limited(self, size: usize) -> LimitedIter<Self> {
+        LimitedIter::fitting(self, size, contd::<E>())
+    }
+
+    type Contd = Contd;
+
+    fn contd() -> Self::Contd {
+        contd::<E>()
+    }
+
+    /// counts characters according to their unicode width.
+    fn element_size((_, c): &Indexed) -> usize {
+        char_width(*c)
+    }
+}
+
+impl<E> Iterator for WidthIndices<'_, E> {
+    type Item = Indexed;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(i, c)| (Some(i), c))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
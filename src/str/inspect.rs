@@ -0,0 +1,77 @@
+use {
+    super::trim_to_height::{join, line_ending, Terminators},
+    crate::iter::{Limited, LimitedIter},
+};
+
+/// a unit of a string that was kept while limiting it.
+///
+/// see [`Limited::trim_to_length_inspect()`][super::Limited::trim_to_length_inspect] for more
+/// information.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Kept<'a> {
+    /// the kept unit, e.g. a character or a line.
+    pub unit: &'a str,
+    /// the byte offset of the unit in the original string.
+    pub offset: usize,
+    /// the running position of the unit, i.e. the total size of the units kept before it.
+    pub position: usize,
+    /// the size of the unit.
+    pub size: usize,
+}
+
+/// collects the pieces of a limited string, calling `f` for each kept character.
+///
+/// the kept piece is the second of the `[leading, kept, ellipsis, trailing]` pieces returned by
+/// e.g. [`trim_to_length::pieces()`][super::trim_to_length::pieces], and is a prefix of the
+/// original string. `size` measures each kept character.
+pub(super) fn pieces<'a>(
+    pieces: [&'a str; 4],
+    size: impl Fn(char) -> usize,
+    mut f: impl FnMut(Kept<'a>),
+) -> String {
+    let [_, kept, ..] = pieces;
+
+    let mut position = 0;
+    for (offset, c) in kept.char_indices() {
+        let unit = &kept[offset..offset + c.len_utf8()];
+        let size = size(c);
+        f(Kept {
+            unit,
+            offset,
+            position,
+            size,
+        });
+        position += size;
+    }
+
+    pieces.concat()
+}
+
+/// collects a limited string of lines, calling `f` for each kept line.
+pub(super) fn lines<'a, I>(
+    value: &'a str,
+    mut iter: LimitedIter<I>,
+    mut f: impl FnMut(Kept<'a>),
+) -> String
+where
+    I: Iterator<Item = &'a str> + Limited,
+{
+    let mut lines = Vec::new();
+
+    while let Some(line) = iter.next() {
+        if !iter.is_truncated() {
+            f(Kept {
+                unit: line,
+                offset: line.as_ptr() as usize - value.as_ptr() as usize,
+                position: lines.len(),
+                size: 1,
+            });
+        }
+        lines.push(line);
+    }
+
+    join(
+        || lines.iter().copied(),
+        line_ending(value, Terminators::default()),
+    )
+}
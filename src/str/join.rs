@@ -0,0 +1,51 @@
+use super::ellipsis::Ellipsis;
+
+/// joins limited segments together, merging adjacent ellipses.
+///
+/// segments that were each limited separately (e.g. the columns of a table row) often begin or
+/// end with an [`Ellipsis`]. concatenating them directly can place two ellipses side by side,
+/// e.g. `"ab……cd"`. this joins the segments with the given separator, and:
+///
+/// * merges an ellipsis at the start of a segment into an ellipsis at the end of the previous
+///   segment, if the two would be adjacent.
+/// * suppresses a segment consisting only of an ellipsis, if the previous segment already ends
+///   with one. the separator preceding the suppressed segment is omitted as well.
+///
+/// # examples
+///
+/// ```
+/// use shear::str::{ellipsis::Horizontal, join::join};
+///
+/// assert_eq!(join::<Horizontal, _>(["ab…", "…cd"], ""), "ab…cd");
+/// assert_eq!(join::<Horizontal, _>(["ab…", "…", "ef"], " | "), "ab… | ef");
+/// assert_eq!(join::<Horizontal, _>(["ab…", "…cd"], " | "), "ab… | …cd");
+/// ```
+pub fn join<E: Ellipsis, S: AsRef<str>>(
+    segments: impl IntoIterator<Item = S>,
+    separator: &str,
+) -> String {
+    let ellipsis = E::ellipsis();
+    let mut joined = String::new();
+    let mut first = true;
+
+    for segment in segments {
+        let mut segment = segment.as_ref();
+        let contd = !ellipsis.is_empty() && joined.ends_with(ellipsis);
+
+        if contd && segment == ellipsis {
+            continue; // this segment adds nothing to the previous ellipsis.
+        }
+
+        if !first {
+            joined.push_str(separator);
+        }
+        if contd && separator.is_empty() {
+            segment = segment.strip_prefix(ellipsis).unwrap_or(segment);
+        }
+
+        joined.push_str(segment);
+        first = false;
+    }
+
+    joined
+}
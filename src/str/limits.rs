@@ -0,0 +1,213 @@
+use {
+    super::{
+        ellipsis::{Ascii, Ellipsis},
+        trim_to_height::{line_ending, Lines, Terminators},
+        Limited,
+    },
+    crate::fmt::LimitedWriter,
+    std::{borrow::Cow, fmt::Write, marker::PhantomData},
+};
+
+/// a combination of limits on the length, width, and height of a string.
+///
+/// [`Limits::apply()`] applies each limit in a single pass over the string, rather than chaining
+/// calls to [`trim_to_height()`][Limited::trim_to_height],
+/// [`trim_to_width()`][Limited::trim_to_width], and [`trim_to_length()`][Limited::trim_to_length].
+/// the height limits the number of lines, the width limits each line, and the length limits
+/// the string as a whole. each line carries at most one ellipsis: a line cut short by the length
+/// ends with a single ellipsis, even if it was also limited by width. a line cut short by the
+/// width keeps its own ellipsis when it is followed by the line marking omitted lines, as the
+/// first example below shows.
+///
+/// an [`Ascii`] ellipsis is used by default. another [`Ellipsis`] can be provided using
+/// [`Limits::ellipsis()`]. a different ellipsis can be used to indicate omitted lines, e.g. a
+/// [`Vertical`][super::ellipsis::Vertical] ellipsis, using [`Limits::vertical()`].
+///
+/// by default, the ellipsis takes the place of the last line when there are too many lines.
+/// [`Limits::inline()`] appends it to the last line that is kept instead, as
+/// [`trim_to_height_inline()`][Limited::trim_to_height_inline] does.
+///
+/// # examples
+///
+/// ```
+/// use shear::str::{ellipsis::{Horizontal, Vertical}, Limits};
+///
+/// let text = "one\ntwo\nthree is a long line\nfour\nfive";
+///
+/// let limits = Limits::new().max_width(8).max_height(4);
+/// assert_eq!(limits.apply(text), "one\ntwo\nthree...\n...");
+///
+/// let limits = Limits::new().max_len(12).max_height(4).ellipsis::<Horizontal>();
+/// assert_eq!(limits.apply(text), "one\ntwo\nt…");
+///
+/// let limits = Limits::new().max_width(8).max_height(2).inline();
+/// assert_eq!(limits.apply(text), "one\ntwo...");
+///
+/// let limits = Limits::new()
+///     .max_width(8)
+///     .max_height(4)
+///     .ellipsis::<Horizontal>()
+///     .vertical::<Vertical>();
+/// assert_eq!(limits.apply(text), "one\ntwo\nthree i…\n⋮");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Limits<E = Ascii, V = E> {
+    /// the maximum length of the string, in bytes.
+    len: Option<usize>,
+    /// the maximum width of each line, in columns.
+    width: Option<usize>,
+    /// the maximum number of lines.
+    height: Option<usize>,
+    /// whether the ellipsis is appended to the last line, rather than replacing it.
+    inline: bool,
+    ellipsis: PhantomData<(E, V)>,
+}
+
+// === impl limits ===
+
+impl Limits {
+    /// returns a new set of [`Limits`], without any limits.
+    pub fn new() -> Self {
+        Self {
+            len: None,
+            width: None,
+            height: None,
+            inline: false,
+            ellipsis: PhantomData,
+        }
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Ellipsis, V: Ellipsis> Limits<E, V> {
+    /// limits the string to `len` bytes.
+    pub fn max_len(self, len: usize) -> Self {
+        Self {
+            len: Some(len),
+            ..self
+        }
+    }
+
+    /// limits each line of the string to `width` columns.
+    pub fn max_width(self, width: usize) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
+    }
+
+    /// limits the string to `height` lines.
+    pub fn max_height(self, height: usize) -> Self {
+        Self {
+            height: Some(height),
+            ..self
+        }
+    }
+
+    /// appends the vertical ellipsis to the last line that is kept, rather than replacing that
+    /// line.
+    ///
+    /// this leaves one more line of the height for the string itself.
+    pub fn inline(self) -> Self {
+        Self {
+            inline: true,
+            ..self
+        }
+    }
+
+    /// uses the given [`Ellipsis`] to indicate truncation.
+    ///
+    /// this replaces the vertical ellipsis as well. call [`Limits::vertical()`] afterwards to use
+    /// a different ellipsis for omitted lines.
+    pub fn ellipsis<F: Ellipsis>(self) -> Limits<F> {
+        self.with_ellipses()
+    }
+
+    /// uses the given [`Ellipsis`] to indicate that lines were omitted.
+    ///
+    /// the ellipsis given to [`Limits::ellipsis()`] is still used to indicate that a line, or the
+    /// string as a whole, was truncated.
+    pub fn vertical<F: Ellipsis>(self) -> Limits<E, F> {
+        self.with_ellipses()
+    }
+
+    /// returns these limits, using other ellipses.
+    fn with_ellipses<F, G>(self) -> Limits<F, G> {
+        let Self {
+            len,
+            width,
+            height,
+            inline,
+            ..
+        } = self;
+
+        Limits {
+            len,
+            width,
+            height,
+            inline,
+            ellipsis: PhantomData,
+        }
+    }
+
+    /// returns `value`, limited by each of these limits.
+    pub fn apply(&self, value: &str) -> String {
+        let Self {
+            len,
+            width,
+            height,
+            inline,
+            ..
+        } = *self;
+
+        let mut writer = LimitedWriter::<_, E>::new(String::new(), len.unwrap_or(usize::MAX));
+
+        // helper fn: writes each piece of the limited string, until the length runs out.
+        let mut write = |pieces: &[&str]| pieces.iter().try_for_each(|p| writer.write_str(p));
+
+        if width.is_none() && height.is_none() {
+            let _ = write(&[value]);
+        } else {
+            let (height, ending) = (
+                height.unwrap_or(usize::MAX),
+                line_ending(value, Terminators::default()),
+            );
+            let mut lines = Lines::new(value).peekable();
+            let mut row = 0;
+
+            while let Some(line) = lines.next() {
+                let separator = if row > 0 { ending } else { "" };
+                row += 1;
+
+                // if there are more lines than fit, the last line is replaced by the ellipsis.
+                let last = row == height && lines.peek().is_some();
+                if row > height || (last && !inline) {
+                    let separator = if inline { "" } else { separator };
+                    let _ = write(&[separator, V::ellipsis()]);
+                    break;
+                }
+
+                // ...or, the ellipsis is appended to the last line, before it is limited by width.
+                let marked = match last {
+                    true => Cow::Owned(format!("{line}{}", V::ellipsis())),
+                    false => Cow::Borrowed(line),
+                };
+                let line = match (width, last) {
+                    (Some(width), false) => marked.trim_to_width_cow::<E>(width),
+                    (Some(width), true) => marked.trim_to_width_cow::<V>(width),
+                    (None, _) => Cow::Borrowed(marked.as_ref()),
+                };
+                if write(&[separator, &line]).is_err() || last {
+                    break; // the length or height has run out.
+                }
+            }
+        }
+
+        writer.finish().unwrap_or_default()
+    }
+}
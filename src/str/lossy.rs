@@ -0,0 +1,113 @@
+use {
+    super::{
+        ellipsis::{self, Ellipsis},
+        split::cluster_floor_before,
+        trim_to_length::TrimToLengthIter,
+        trim_to_width::TrimToWidthIter,
+        Limited as _,
+    },
+    crate::iter::Limited,
+    tap::Pipe,
+};
+
+/// limits possibly invalid utf-8 by length, replacing invalid sequences with `U+FFFD`.
+///
+/// this returns the same string as calling [`String::from_utf8_lossy()`] and then
+/// [`Limited::trim_to_length()`][super::Limited::trim_to_length], without first allocating the
+/// full lossy string.
+///
+/// # examples
+///
+/// ```
+/// use shear::str::{ellipsis::Ascii, lossy};
+///
+/// let payload = b"status: \xF0\x90\x80 ok, body follows";
+///
+/// assert_eq!(lossy::trim_to_length::<Ascii>(payload, 16), "status: \u{FFFD} o...");
+/// ```
+pub fn trim_to_length<E: Ellipsis>(bytes: &[u8], length: usize) -> String {
+    if ellipsis::is_paired::<E>() {
+        return String::from_utf8_lossy(bytes).trim_to_length::<E>(length);
+    }
+
+    // the length of the lossy string can be found without building it, so we can check if the
+    // value fits into the given length before limiting it.
+    let fits = lossy_len(bytes) <= length;
+
+    match fits {
+        true => chars(bytes).collect(),
+        false => chars(bytes)
+            .pipe(TrimToLengthIter::<_, E>::new)
+            .limited(length)
+            .collect::<String>()
+            .pipe(|limited| floor::<E>(bytes, limited)),
+    }
+}
+
+/// limits possibly invalid utf-8 by width, replacing invalid sequences with `U+FFFD`.
+///
+/// this returns the same string as calling [`String::from_utf8_lossy()`] and then
+/// [`Limited::trim_to_width()`][super::Limited::trim_to_width], without first allocating the
+/// full lossy string.
+///
+/// # examples
+///
+/// ```
+/// use shear::str::{ellipsis::Horizontal, lossy};
+///
+/// let payload = b"caf\xE9 au lait".to_vec();
+///
+/// assert_eq!(lossy::trim_to_width::<Horizontal>(&payload, 7), "caf\u{FFFD} a…");
+/// ```
+pub fn trim_to_width<E: Ellipsis>(bytes: &[u8], width: usize) -> String {
+    if ellipsis::is_paired::<E>() {
+        return String::from_utf8_lossy(bytes).trim_to_width::<E>(width);
+    }
+
+    let limited = chars(bytes)
+        .pipe(TrimToWidthIter::<_, E>::new)
+        .limited(width)
+        .collect::<String>();
+
+    match chars(bytes).eq(limited.chars()) {
+        true => limited,
+        false => floor::<E>(bytes, limited),
+    }
+}
+
+/// moves the cut in a limited string back to a cluster boundary, if needed.
+///
+/// see [`Limited::trim_to_length()`][super::Limited::trim_to_length] for more information.
+fn floor<E: Ellipsis>(bytes: &[u8], mut limited: String) -> String {
+    let kept = limited.len().saturating_sub(E::ellipsis().len());
+    let next = chars(bytes).nth(limited[..kept].chars().count());
+
+    let cut = cluster_floor_before(&limited[..kept], next);
+    if cut < kept {
+        limited.replace_range(cut..kept, "");
+    }
+
+    limited
+}
+
+/// returns an iterator over the characters of possibly invalid utf-8.
+///
+/// each invalid sequence is replaced with one `U+FFFD`, in the same manner as
+/// [`String::from_utf8_lossy()`].
+fn chars(bytes: &[u8]) -> impl Iterator<Item = char> + '_ {
+    bytes.utf8_chunks().flat_map(|chunk| {
+        let replacement = (!chunk.invalid().is_empty()).then_some(char::REPLACEMENT_CHARACTER);
+        chunk.valid().chars().chain(replacement)
+    })
+}
+
+/// returns the length of the lossy string, without building it.
+fn lossy_len(bytes: &[u8]) -> usize {
+    bytes
+        .utf8_chunks()
+        .map(|chunk| match chunk.invalid().is_empty() {
+            true => chunk.valid().len(),
+            false => chunk.valid().len() + char::REPLACEMENT_CHARACTER.len_utf8(),
+        })
+        .sum()
+}
@@ -0,0 +1,96 @@
+use {
+    super::{
+        ellipsis::Ellipsis,
+        split::{cluster_width, Clusters},
+        width::str_width,
+    },
+    std::borrow::Cow,
+};
+
+/// a string, along with the cumulative width of its clusters.
+///
+/// measuring a string is a linear scan. if the same long string is limited to many different
+/// widths, e.g. in a resizable preview pane, this can be avoided by measuring the string once.
+/// each subsequent trim is then a binary search, taking `O(log n)` time.
+///
+/// a cluster is a character, followed by any zero-width characters that modify it. clusters are
+/// never split, like [`Limited::split_to_width()`][super::Limited::split_to_width].
+///
+/// # examples
+///
+/// ```
+/// use shear::str::{ellipsis::Horizontal, MeasuredStr};
+///
+/// let measured = MeasuredStr::new("a very long string value");
+///
+/// assert_eq!(measured.width(), 24);
+/// assert_eq!(measured.trim_to_width::<Horizontal>(32), "a very long string value");
+/// assert_eq!(measured.trim_to_width::<Horizontal>(12), "a very long…");
+/// assert_eq!(measured.trim_to_width::<Horizontal>(8), "a very …");
+/// ```
+#[derive(Clone, Debug)]
+pub struct MeasuredStr<'a> {
+    /// the measured string.
+    value: &'a str,
+    /// the byte offset at the end of each cluster.
+    ends: Vec<usize>,
+    /// the total width of the string, at the end of each cluster.
+    widths: Vec<usize>,
+}
+
+// === impl measuredstr ===
+
+impl<'a> MeasuredStr<'a> {
+    /// returns a new [`MeasuredStr`], measuring each cluster of `value`.
+    pub fn new(value: &'a str) -> Self {
+        let (mut ends, mut widths) = (Vec::new(), Vec::new());
+        let (mut end, mut width) = (0, 0);
+
+        for cluster in Clusters::new(value) {
+            end += cluster.len();
+            width += cluster_width(cluster);
+            ends.push(end);
+            widths.push(width);
+        }
+
+        Self {
+            value,
+            ends,
+            widths,
+        }
+    }
+
+    /// returns the measured string.
+    pub fn as_str(&self) -> &'a str {
+        self.value
+    }
+
+    /// returns the width of the string.
+    pub fn width(&self) -> usize {
+        self.widths.last().copied().unwrap_or_default()
+    }
+
+    /// splits the string into the prefix that fits within `width` columns, and the overflow.
+    ///
+    /// see [`Limited::split_at_width()`][super::Limited::split_at_width] for more information.
+    pub fn split_at_width(&self, width: usize) -> (&'a str, &'a str) {
+        let kept = self.widths.partition_point(|&w| w <= width);
+        let end = kept.checked_sub(1).map_or(0, |i| self.ends[i]);
+        self.value.split_at(end)
+    }
+
+    /// returns the string limited by width.
+    ///
+    /// if the string does not fit, it is cut at the last cluster that leaves room for the
+    /// [`Ellipsis`]. strings that fit are returned unaltered.
+    pub fn trim_to_width<E: Ellipsis>(&self, width: usize) -> Cow<'a, str> {
+        if self.width() <= width {
+            return Cow::Borrowed(self.value);
+        }
+
+        let ellipsis = E::ellipsis();
+        let room = width.saturating_sub(str_width(ellipsis));
+        let (kept, _) = self.split_at_width(room);
+        Cow::Owned([kept, ellipsis].concat())
+    }
+}
@@ -0,0 +1,49 @@
+use {
+    std::borrow::Cow,
+    unicode_normalization::{
+        is_nfc, is_nfc_quick, is_nfkc, is_nfkc_quick, IsNormalized, UnicodeNormalization,
+    },
+};
+
+/// a unicode normalization form.
+///
+/// strings that look identical can be encoded in different ways, e.g. `"é"` may be a single
+/// precomposed character, or an `"e"` followed by a combining acute accent. normalizing strings
+/// before they are limited means that they measure and truncate identically.
+///
+/// see [`Limited::normalized()`][super::Limited::normalized] for more information.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Form {
+    /// canonical composition, or NFC.
+    ///
+    /// this composes characters with their combining marks, without otherwise changing how they
+    /// are displayed.
+    #[default]
+    Nfc,
+    /// compatibility composition, or NFKC.
+    ///
+    /// this also replaces compatibility characters with their plain equivalents, e.g. the
+    /// fullwidth `"Ａ"` becomes `"A"`, and the ligature `"ﬁ"` becomes `"fi"`.
+    Nfkc,
+}
+
+/// returns `value` in the given normalization [`Form`], borrowing it if it is already normalized.
+pub(super) fn normalize(value: &str, form: Form) -> Cow<'_, str> {
+    // the quick check is not always conclusive, so fall back to a full check if needed.
+    let normalized = match form {
+        Form::Nfc => match is_nfc_quick(value.chars()) {
+            IsNormalized::Maybe => is_nfc(value),
+            quick => quick == IsNormalized::Yes,
+        },
+        Form::Nfkc => match is_nfkc_quick(value.chars()) {
+            IsNormalized::Maybe => is_nfkc(value),
+            quick => quick == IsNormalized::Yes,
+        },
+    };
+
+    match (normalized, form) {
+        (true, _) => Cow::Borrowed(value),
+        (false, Form::Nfc) => Cow::Owned(value.nfc().collect()),
+        (false, Form::Nfkc) => Cow::Owned(value.nfkc().collect()),
+    }
+}
@@ -0,0 +1,26 @@
+use super::ellipsis::{self, Ellipsis};
+
+/// moves the cut in a limited string back past any characters that would be orphaned.
+///
+/// `limited` is `value` as limited by some other method. if it was trimmed, and the retained
+/// text ends with an opening bracket, an opening quote, a dash, or whitespace, those characters
+/// are removed so that the [`Ellipsis`] follows the last meaningful character.
+pub(super) fn back_off<E: Ellipsis>(value: &str, limited: String) -> String {
+    ellipsis::recut::<E>(value, limited, |kept| {
+        kept.trim_end_matches(is_orphan).len()
+    })
+}
+
+/// returns true if `c` should not be the last character before an ellipsis.
+fn is_orphan(c: char) -> bool {
+    c.is_whitespace()
+        || matches!(
+            c,
+            // opening brackets.
+            '(' | '[' | '{' | '<' | '⟨' | '「' | '『' | '【' | '（' | '［' | '｛'
+            // opening quotes.
+            | '“' | '‘' | '„' | '‚' | '«' | '‹' | '¿' | '¡'
+            // dashes, and other connecting punctuation.
+            | '-' | '‐' | '‑' | '‒' | '–' | '—' | '―' | '_' | '/' | '\\' | '&' | '@' | '#'
+        )
+}
@@ -0,0 +1,80 @@
+use super::{
+    ellipsis::{Ellipsis, Placement},
+    split::{cluster_width, Clusters},
+    width::str_width,
+};
+
+/// limits a string by some measure of size, placing the ellipsis at the start or in the middle.
+///
+/// `size` measures a cluster of characters, and `ellipsis_size` is the size of the ellipsis. when
+/// the ellipsis is placed in the middle, the prefix is given any leftover unit of room.
+fn place<E: Ellipsis>(
+    value: &str,
+    budget: usize,
+    placement: Placement,
+    ellipsis_size: usize,
+    size: impl Fn(&str) -> usize,
+) -> String {
+    let clusters = Clusters::new(value).collect::<Vec<_>>();
+
+    let room = budget.saturating_sub(ellipsis_size);
+    let (head, tail) = match placement {
+        Placement::Start => (0, room),
+        Placement::Middle => (room - room / 2, room / 2),
+        Placement::End => (room, 0),
+    };
+
+    // take clusters from the front of the string, and then from the back.
+    let (mut used, mut end, mut kept) = (0, 0, 0);
+    for cluster in &clusters {
+        used += size(cluster);
+        if used > head {
+            break;
+        }
+        end += cluster.len();
+        kept += 1;
+    }
+    let (mut used, mut start) = (0, value.len());
+    for cluster in clusters[kept..].iter().rev() {
+        used += size(cluster);
+        if used > tail {
+            break;
+        }
+        start -= cluster.len();
+    }
+
+    let ellipsis = E::ellipsis();
+    let mut limited = String::with_capacity(end + ellipsis.len() + value.len() - start);
+    limited.push_str(&value[..end]);
+    limited.push_str(ellipsis);
+    limited.push_str(&value[start..]);
+    limited
+}
+
+/// limits a string by length, placing the ellipsis at the start or in the middle.
+pub(super) fn place_length<E: Ellipsis>(
+    value: &str,
+    length: usize,
+    placement: Placement,
+) -> String {
+    if value.len() <= length {
+        return value.to_owned();
+    }
+
+    place::<E>(value, length, placement, E::ellipsis().len(), str::len)
+}
+
+/// limits a string by width, placing the ellipsis at the start or in the middle.
+pub(super) fn place_width<E: Ellipsis>(value: &str, width: usize, placement: Placement) -> String {
+    if str_width(value) <= width {
+        return value.to_owned();
+    }
+
+    place::<E>(
+        value,
+        width,
+        placement,
+        str_width(E::ellipsis()),
+        cluster_width,
+    )
+}
@@ -0,0 +1,121 @@
+use super::{trim_to_height::Lines, width::str_width};
+
+/// a limited string, along with a report of what was discarded.
+///
+/// see [`Limited::trim_to_length_report()`][super::Limited::trim_to_length_report] for more
+/// information.
+///
+/// # examples
+///
+/// ```
+/// use shear::str::{ellipsis::Ascii, Limited};
+///
+/// let report = "a very long string value".trim_to_length_report::<Ascii>(10);
+///
+/// assert_eq!(report.as_str(), "a very ...");
+/// assert!(report.is_truncated());
+/// assert_eq!(report.dropped_bytes(), 17);
+/// assert_eq!(report.dropped_chars(), 17);
+/// assert_eq!(report.dropped_lines(), 0);
+/// assert_eq!(report.width(), 10);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrimReport {
+    value: String,
+    dropped_bytes: usize,
+    dropped_chars: usize,
+    dropped_lines: usize,
+}
+
+// === impl trimreport ===
+
+impl TrimReport {
+    /// returns a report for a string that was limited to a prefix, followed by an ellipsis.
+    ///
+    /// `kept` is the number of bytes of `original` that were kept.
+    pub(super) fn for_prefix(original: &str, value: String, kept: usize) -> Self {
+        if kept == original.len() {
+            return Self::untouched(value);
+        }
+
+        let dropped = &original[kept..];
+        let lines = Lines::new(original).count() - Lines::new(&original[..kept]).count();
+
+        Self {
+            dropped_bytes: dropped.len(),
+            dropped_chars: dropped.chars().count(),
+            dropped_lines: lines,
+            value,
+        }
+    }
+
+    /// returns a report for a string that was limited to its leading lines, followed by an
+    /// ellipsis line.
+    pub(super) fn for_lines(original: &str, value: String) -> Self {
+        // line breaks may be normalized, so compare the lines rather than the strings.
+        if Lines::new(&value).eq(Lines::new(original)) {
+            return Self::untouched(value);
+        }
+
+        let kept = Lines::new(&value).count().saturating_sub(1);
+        let start = Lines::new(original)
+            .nth(kept)
+            .map(|line| line.as_ptr() as usize - original.as_ptr() as usize)
+            .unwrap_or(original.len());
+        let dropped = &original[start..];
+
+        Self {
+            dropped_bytes: dropped.len(),
+            dropped_chars: dropped.chars().count(),
+            dropped_lines: Lines::new(dropped).count(),
+            value,
+        }
+    }
+
+    /// returns a report for a string that was not truncated.
+    fn untouched(value: String) -> Self {
+        Self {
+            value,
+            dropped_bytes: 0,
+            dropped_chars: 0,
+            dropped_lines: 0,
+        }
+    }
+
+    /// returns the limited string.
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// returns the limited string, consuming the report.
+    pub fn into_string(self) -> String {
+        self.value
+    }
+
+    /// returns true if anything was discarded.
+    pub fn is_truncated(&self) -> bool {
+        self.dropped_bytes > 0
+    }
+
+    /// returns the number of bytes that were discarded.
+    pub fn dropped_bytes(&self) -> usize {
+        self.dropped_bytes
+    }
+
+    /// returns the number of characters that were discarded.
+    pub fn dropped_chars(&self) -> usize {
+        self.dropped_chars
+    }
+
+    /// returns the number of lines that were discarded entirely.
+    ///
+    /// a line that was only partially discarded is not counted.
+    pub fn dropped_lines(&self) -> usize {
+        self.dropped_lines
+    }
+
+    /// returns the width of the limited string.
+    pub fn width(&self) -> usize {
+        str_width(&self.value)
+    }
+}
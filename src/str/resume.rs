@@ -0,0 +1,114 @@
+use {
+    super::{ellipsis::Ellipsis, split::Clusters, width::str_width},
+    std::{fmt, num::ParseIntError, str::FromStr},
+};
+
+/// a continuation token, marking how much of a string has been consumed.
+///
+/// this is returned by [`Limited::trim_to_length_resumable()`][super::Limited::trim_to_length_resumable]
+/// and [`Limited::trim_to_width_resumable()`][super::Limited::trim_to_width_resumable], and can
+/// be passed back to them to resume limiting the rest of the string with a fresh budget.
+///
+/// a token can be serialized using its [`Display`][fmt::Display] implementation, and parsed using
+/// its [`FromStr`] implementation, so that limiting can be resumed across calls, e.g. when
+/// splitting content across multiple messages.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Token {
+    /// the byte offset at which to resume.
+    offset: usize,
+}
+
+// === impl token ===
+
+impl Token {
+    /// a token marking the start of a string.
+    pub const START: Self = Self { offset: 0 };
+
+    /// returns a token that resumes at the given byte offset.
+    pub fn from_offset(offset: usize) -> Self {
+        Self { offset }
+    }
+
+    /// returns the byte offset at which this token resumes.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// returns the rest of the string, from this token's offset.
+    ///
+    /// an offset that is not on a character boundary is moved back to the previous boundary.
+    fn rest(self, value: &str) -> (usize, &str) {
+        let mut offset = self.offset.min(value.len());
+        while !value.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        (offset, &value[offset..])
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { offset } = self;
+        write!(f, "{offset}")
+    }
+}
+
+impl FromStr for Token {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self::from_offset)
+    }
+}
+
+/// limits the rest of a string, returning a token to resume with if it did not fit.
+///
+/// `size` measures a cluster of characters, and `budget` is the space available, including the
+/// ellipsis whose size is `ellipsis_size`. at least one cluster is always kept, so that progress
+/// is made even if the budget is smaller than the ellipsis.
+pub(super) fn resume<E: Ellipsis>(
+    value: &str,
+    token: Token,
+    budget: usize,
+    ellipsis_size: usize,
+    size: impl Fn(&str) -> usize,
+) -> (String, Option<Token>) {
+    let (offset, rest) = token.rest(value);
+    if size(rest) <= budget {
+        return (rest.to_owned(), None);
+    }
+
+    let room = budget.saturating_sub(ellipsis_size);
+    let (mut used, mut end) = (0, 0);
+    for cluster in Clusters::new(rest) {
+        used += size(cluster);
+        if used > room && end > 0 {
+            break;
+        }
+        end += cluster.len();
+    }
+
+    let mut limited = String::with_capacity(end + E::ellipsis().len());
+    limited.push_str(&rest[..end]);
+    limited.push_str(E::ellipsis());
+
+    (limited, Some(Token::from_offset(offset + end)))
+}
+
+/// limits the rest of a string by length, see [`resume()`].
+pub(super) fn resume_length<E: Ellipsis>(
+    value: &str,
+    token: Token,
+    length: usize,
+) -> (String, Option<Token>) {
+    resume::<E>(value, token, length, E::ellipsis().len(), str::len)
+}
+
+/// limits the rest of a string by width, see [`resume()`].
+pub(super) fn resume_width<E: Ellipsis>(
+    value: &str,
+    token: Token,
+    width: usize,
+) -> (String, Option<Token>) {
+    resume::<E>(value, token, width, str_width(E::ellipsis()), str_width)
+}
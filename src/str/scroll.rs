@@ -0,0 +1,57 @@
+use super::{
+    ellipsis::Ellipsis,
+    split::{cluster_width, Clusters},
+    width::str_width,
+};
+
+/// returns a window of a string, `width` columns wide, starting at column `offset`.
+///
+/// see [`Limited::scroll_to_width()`][super::Limited::scroll_to_width] for more information.
+pub(super) fn scroll<E: Ellipsis>(value: &str, offset: usize, width: usize) -> (String, usize) {
+    let total = str_width(value);
+    if total <= width {
+        return (value.to_owned(), 0);
+    }
+
+    // clamp the offset so that the window does not scroll past the end of the string.
+    let offset = offset.min(total - width);
+    let end = offset + width;
+
+    // an ellipsis replaces the edges of the window, if there are contents out of view.
+    //
+    // if the window is too narrow to hold both, the left ellipsis is omitted. if the window is
+    // too narrow to hold either, both are omitted.
+    let ellipsis = E::ellipsis();
+    let size = str_width(ellipsis);
+    let (left, right) = (offset > 0, end < total);
+    let left = left && (usize::from(left) + usize::from(right)) * size <= width;
+    let right = right && size <= width;
+    let (start, stop) = {
+        let start = if left { offset + size } else { offset };
+        let stop = if right { end.saturating_sub(size) } else { end };
+        (start, stop)
+    };
+
+    let mut window = String::with_capacity(width);
+    if left {
+        window.push_str(ellipsis);
+    }
+
+    // take the clusters that are entirely within view.
+    let mut column = 0;
+    for cluster in Clusters::new(value) {
+        let size = cluster_width(cluster);
+        if column >= start && column + size <= stop {
+            window.push_str(cluster);
+        } else if column >= stop {
+            break;
+        }
+        column += size;
+    }
+
+    if right {
+        window.push_str(ellipsis);
+    }
+
+    (window, offset)
+}
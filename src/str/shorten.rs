@@ -0,0 +1,176 @@
+use {
+    super::{ellipsis::Ellipsis, width::str_width, Limited},
+    std::marker::PhantomData,
+};
+
+/// a progressive shortening pipeline.
+///
+/// this applies a sequence of gentle shortening steps to a string, in order, until it fits. if
+/// the string still does not fit once every step has been applied, it is truncated with an
+/// [`Ellipsis`].
+///
+/// # examples
+///
+/// ```
+/// use shear::str::{ellipsis::Horizontal, shorten::Shorten};
+///
+/// let abbreviations = [("Documents", "Docs"), ("Projects", "Proj")];
+/// let shorten = Shorten::<Horizontal>::new()
+///     .collapse_whitespace()
+///     .drop_parentheticals()
+///     .abbreviate(&abbreviations);
+///
+/// let title = "Documents  ›  Projects (archived)  ›  shear";
+///
+/// assert_eq!(shorten.trim_to_width(title, 40), "Documents › Projects (archived) › shear");
+/// assert_eq!(shorten.trim_to_width(title, 30), "Documents › Projects › shear");
+/// assert_eq!(shorten.trim_to_width(title, 20), "Docs › Proj › shear");
+/// assert_eq!(shorten.trim_to_width(title, 12), "Docs › Proj…");
+/// ```
+pub struct Shorten<'a, E> {
+    steps: Vec<Step<'a>>,
+    ellipsis: PhantomData<E>,
+}
+
+/// a step in a [`Shorten`] pipeline.
+enum Step<'a> {
+    /// collapse runs of whitespace into a single space.
+    CollapseWhitespace,
+    /// remove parenthesized text.
+    DropParentheticals,
+    /// replace words with their abbreviations.
+    Abbreviate(&'a [(&'a str, &'a str)]),
+}
+
+// === impl shorten ===
+
+impl<'a, E: Ellipsis> Shorten<'a, E> {
+    /// returns a new, empty [`Shorten`] pipeline.
+    ///
+    /// without any steps, this is equivalent to truncating the string with an ellipsis.
+    pub fn new() -> Self {
+        Self {
+            steps: Vec::new(),
+            ellipsis: PhantomData,
+        }
+    }
+
+    /// collapses runs of whitespace into a single space, and removes leading and trailing
+    /// whitespace.
+    pub fn collapse_whitespace(self) -> Self {
+        self.step(Step::CollapseWhitespace)
+    }
+
+    /// removes parenthesized text, e.g. `"report (final)"` becomes `"report"`.
+    pub fn drop_parentheticals(self) -> Self {
+        self.step(Step::DropParentheticals)
+    }
+
+    /// replaces whole words with their abbreviations, given as `(word, abbreviation)` pairs.
+    pub fn abbreviate(self, abbreviations: &'a [(&'a str, &'a str)]) -> Self {
+        self.step(Step::Abbreviate(abbreviations))
+    }
+
+    /// returns a string shortened to fit within `length` bytes.
+    pub fn trim_to_length(&self, value: &str, length: usize) -> String {
+        self.shorten(value, |s| s.len() <= length)
+            .unwrap_or_else(|s| s.trim_to_length::<E>(length))
+    }
+
+    /// returns a string shortened to fit within `width` columns.
+    pub fn trim_to_width(&self, value: &str, width: usize) -> String {
+        self.shorten(value, |s| str_width(s) <= width)
+            .unwrap_or_else(|s| s.trim_to_width::<E>(width))
+    }
+
+    /// adds a step to the pipeline.
+    fn step(mut self, step: Step<'a>) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// applies each step until the value fits.
+    ///
+    /// returns `Ok(_)` if the value fits, or `Err(_)` with the fully shortened value if not.
+    fn shorten(&self, value: &str, fits: impl Fn(&str) -> bool) -> Result<String, String> {
+        let mut value = value.to_owned();
+
+        for step in &self.steps {
+            if fits(&value) {
+                break;
+            }
+            value = step.apply(&value);
+        }
+
+        if fits(&value) {
+            Ok(value)
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl<E: Ellipsis> Default for Shorten<'_, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// === impl step ===
+
+impl Step<'_> {
+    /// applies this step to a string.
+    fn apply(&self, value: &str) -> String {
+        match self {
+            Self::CollapseWhitespace => value.split_whitespace().collect::<Vec<_>>().join(" "),
+            Self::DropParentheticals => Self::drop_parentheticals(value),
+            Self::Abbreviate(abbreviations) => Self::abbreviate(value, abbreviations),
+        }
+    }
+
+    /// removes parenthesized text, along with any whitespace preceding it.
+    fn drop_parentheticals(value: &str) -> String {
+        let mut out = String::with_capacity(value.len());
+        let mut depth = 0_usize;
+
+        for c in value.chars() {
+            match c {
+                '(' => {
+                    if depth == 0 {
+                        out.truncate(out.trim_end().len());
+                    }
+                    depth += 1;
+                }
+                ')' if depth > 0 => depth -= 1,
+                _ if depth > 0 => {}
+                c => out.push(c),
+            }
+        }
+
+        out
+    }
+
+    /// replaces whole words with their abbreviations.
+    fn abbreviate(value: &str, abbreviations: &[(&str, &str)]) -> String {
+        let mut out = String::with_capacity(value.len());
+        let mut rest = value;
+
+        while !rest.is_empty() {
+            // split off the next word, and the whitespace that precedes it.
+            let start = rest.len() - rest.trim_start().len();
+            let end = rest[start..]
+                .find(char::is_whitespace)
+                .map_or(rest.len(), |i| start + i);
+            let (space, word) = (&rest[..start], &rest[start..end]);
+
+            out.push_str(space);
+            match abbreviations.iter().find(|(w, _)| *w == word) {
+                Some((_, abbreviation)) => out.push_str(abbreviation),
+                None => out.push_str(word),
+            }
+            rest = &rest[end..];
+        }
+
+        out
+    }
+}
@@ -0,0 +1,138 @@
+use {
+    super::{
+        ellipsis::Ellipsis,
+        trim_to_height::{line_ending, Lines, Terminators},
+    },
+    std::{
+        fmt::{Display, Write},
+        marker::PhantomData,
+    },
+};
+
+/// limits source code by height, prefixing each kept line with its line number.
+///
+/// if the code has more than `height` lines, the omitted lines are replaced by a marker line that
+/// names the range of lines that were omitted, e.g. `"… lines 3-5 omitted"`. line numbers are
+/// right-aligned in a gutter, separated from each line by `" | "`.
+///
+/// the last lines of the code can be kept as well, using [`Snippet::tail()`]. if the code is an
+/// excerpt of a larger file, [`Snippet::start()`] sets the number of its first line.
+///
+/// # examples
+///
+/// ```
+/// use shear::str::{ellipsis::Horizontal, snippet::Snippet};
+///
+/// let code = "fn main() {\n    let a = 1;\n    let b = 2;\n    let c = 3;\n}";
+///
+/// let limited = Snippet::<Horizontal>::new(3).trim(code);
+/// assert_eq!(limited, "1 | fn main() {\n2 |     let a = 1;\n  | … lines 3-5 omitted");
+///
+/// let limited = Snippet::<Horizontal>::new(4).tail(1).start(9).trim(code);
+/// assert_eq!(
+///     limited,
+///     " 9 | fn main() {\n10 |     let a = 1;\n   | … lines 11-12 omitted\n13 | }"
+/// );
+/// ```
+pub struct Snippet<E> {
+    /// the maximum number of lines.
+    height: usize,
+    /// the number of the first line.
+    start: usize,
+    /// the number of lines to keep from the end of the code.
+    tail: usize,
+    ellipsis: PhantomData<E>,
+}
+
+// === impl snippet ===
+
+impl<E: Ellipsis> Snippet<E> {
+    /// returns a new [`Snippet`] limit of `height` lines.
+    pub fn new(height: usize) -> Self {
+        Self {
+            height,
+            start: 1,
+            tail: 0,
+            ellipsis: PhantomData,
+        }
+    }
+
+    /// numbers the first line of the code `line`, rather than `1`.
+    pub fn start(self, line: usize) -> Self {
+        Self {
+            start: line,
+            ..self
+        }
+    }
+
+    /// keeps the last `lines` lines of the code, placing the marker line before them.
+    ///
+    /// the marker line and the last lines count towards the height. if there is not room for all
+    /// of them, fewer of the last lines are kept.
+    pub fn tail(self, lines: usize) -> Self {
+        Self {
+            tail: lines,
+            ..self
+        }
+    }
+
+    /// returns `code`, limited by height and prefixed with line numbers.
+    ///
+    /// a height of zero has no room for the marker line, so an empty string is returned.
+    pub fn trim(&self, code: &str) -> String {
+        let Self {
+            height,
+            start,
+            tail,
+            ..
+        } = *self;
+
+        if height == 0 {
+            return String::new();
+        }
+
+        let ending = line_ending(code, Terminators::default());
+        let total = Lines::new(code).count();
+        let gutter = (start + total.saturating_sub(1)).to_string().len();
+
+        // decide which lines are kept, and which range of lines is omitted.
+        let (head, omitted) = match total <= height {
+            true => (total, 0..0),
+            false => {
+                let kept = height.saturating_sub(1);
+                let tail = tail.min(kept);
+                let head = kept - tail;
+                (head, head..total - tail)
+            }
+        };
+
+        // helper fn: writes a row, with the given number or marker in its gutter.
+        let mut limited = String::new();
+        let mut row = |number: &dyn Display, content: &dyn Display| {
+            if !limited.is_empty() {
+                limited.push_str(ending);
+            }
+            let _ = write!(limited, "{number:>gutter$} | {content}");
+        };
+
+        let mut lines = Lines::new(code).enumerate();
+        for (i, line) in lines.by_ref().take(head) {
+            row(&(start + i), &line);
+        }
+
+        if !omitted.is_empty() {
+            let (first, last) = (start + omitted.start, start + omitted.end - 1);
+            let marker = match first == last {
+                true => format!("{} line {first} omitted", E::ellipsis()),
+                false => format!("{} lines {first}-{last} omitted", E::ellipsis()),
+            };
+            row(&"", &marker);
+        }
+
+        for (i, line) in lines.skip(omitted.len()) {
+            row(&(start + i), &line);
+        }
+
+        limited
+    }
+}
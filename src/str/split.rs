@@ -0,0 +1,254 @@
+use super::{
+    width::{char_width, str_width},
+    zero_width::is_zero_width,
+};
+
+/// an iterator over segments of a string, each limited by width.
+///
+/// see [`Limited::split_to_width()`][super::Limited::split_to_width] for more information.
+pub struct SplitToWidth<'a> {
+    clusters: Clusters<'a>,
+    width: usize,
+}
+
+/// an iterator over pieces of a string, each limited by length.
+///
+/// see [`Limited::split_to_length()`][super::Limited::split_to_length] for more information.
+pub struct SplitToLength<'a> {
+    rest: &'a str,
+    length: usize,
+    words: bool,
+}
+
+/// an iterator over the clusters of a string.
+///
+/// a cluster is a character, followed by any zero-width characters (e.g. combining marks,
+/// variation selectors) that modify it. characters joined by a zero-width joiner are also treated
+/// as a single cluster.
+pub(crate) struct Clusters<'a> {
+    rest: &'a str,
+}
+
+// === impl splittowidth ===
+
+impl<'a> SplitToWidth<'a> {
+    /// returns a new [`SplitToWidth`] iterator.
+    pub(crate) fn new(s: &'a str, width: usize) -> Self {
+        Self {
+            clusters: Clusters::new(s),
+            width,
+        }
+    }
+}
+
+impl<'a> Iterator for SplitToWidth<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { clusters, width } = self;
+
+        let start = clusters.rest;
+        let (mut end, mut used) = (0, 0);
+
+        // take clusters until we run out of room, yielding at least one cluster.
+        while let Some(cluster) = clusters.peek() {
+            let size = cluster_width(cluster);
+            if end > 0 && used + size > *width {
+                break;
+            }
+            clusters.next();
+            end += cluster.len();
+            used += size;
+        }
+
+        (end > 0).then(|| &start[..end])
+    }
+}
+
+// === impl splittolength ===
+
+impl<'a> SplitToLength<'a> {
+    /// returns a new [`SplitToLength`] iterator.
+    pub(crate) fn new(s: &'a str, length: usize) -> Self {
+        Self {
+            rest: s,
+            length,
+            words: false,
+        }
+    }
+
+    /// prefer splitting the string after whitespace, rather than in the middle of a word.
+    ///
+    /// if a piece contains no whitespace, it is split at the last character boundary that fits.
+    pub fn prefer_words(self) -> Self {
+        Self {
+            words: true,
+            ..self
+        }
+    }
+}
+
+impl<'a> Iterator for SplitToLength<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self {
+            rest,
+            length,
+            words,
+        } = self;
+
+        if rest.is_empty() {
+            return None;
+        } else if rest.len() <= *length {
+            return Some(std::mem::take(rest));
+        }
+
+        // find the last character boundary that fits, yielding at least one character.
+        let mut end = *length;
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == 0 {
+            end = rest.chars().next().map(char::len_utf8).unwrap_or_default();
+        }
+
+        // if asked to, move back to the end of the last whitespace that fits.
+        if *words {
+            if let Some((i, c)) = rest[..end]
+                .char_indices()
+                .rev()
+                .find(|(_, c)| c.is_whitespace())
+            {
+                end = i + c.len_utf8();
+            }
+        }
+
+        let (piece, remaining) = rest.split_at(end);
+        *rest = remaining;
+        Some(piece)
+    }
+}
+
+/// splits a string at the last character boundary within `length` bytes.
+pub(crate) fn at_length(s: &str, length: usize) -> (&str, &str) {
+    s.split_at(super::floor_to_boundary(s, length).len())
+}
+
+/// splits a string after the last cluster that fits within `width` columns.
+pub(crate) fn at_width(s: &str, width: usize) -> (&str, &str) {
+    let mut clusters = Clusters::new(s);
+    let (mut end, mut used) = (0, 0);
+
+    while let Some(cluster) = clusters.peek() {
+        used += cluster_width(cluster);
+        if used > width {
+            break;
+        }
+        clusters.next();
+        end += cluster.len();
+    }
+
+    s.split_at(end)
+}
+
+/// returns the last cluster boundary in `s` at or before `index`.
+///
+/// cutting a string at this offset never separates a character from the zero-width characters
+/// that follow it, or splits a sequence of characters joined by a zero-width joiner. the string
+/// before the offset never ends with an invisible zero-width character.
+pub(crate) fn cluster_floor(s: &str, index: usize) -> usize {
+    let index = super::floor_to_boundary(s, index).len();
+
+    let (kept, rest) = s.split_at(index);
+    cluster_floor_before(kept, rest.chars().next())
+}
+
+/// returns the length of `kept`, once any cluster that would continue into `next` is removed.
+///
+/// invisible zero-width characters at the end of what remains are removed as well, so that they
+/// never immediately precede an ellipsis. see [`cluster_floor()`].
+pub(crate) fn cluster_floor_before(kept: &str, next: Option<char>) -> usize {
+    let continues = next.is_some_and(|c| kept.ends_with(ZWJ) || extends(c));
+    match continues {
+        // drop the last cluster of `kept`, since it continues past the cut.
+        true => without_last_cluster(kept),
+        false => kept.trim_end_matches(is_zero_width).len(),
+    }
+}
+
+/// returns the length of `kept`, once its last cluster is removed.
+///
+/// invisible zero-width characters at the end of what remains are removed as well. this is where
+/// `kept` can be cut if it is not yet known what follows it, since that may extend its last
+/// cluster. see [`cluster_floor_before()`].
+pub(crate) fn without_last_cluster(kept: &str) -> usize {
+    let mut start = 0;
+    let mut clusters = Clusters::new(kept);
+    while let Some(cluster) = clusters.next() {
+        if clusters.rest.is_empty() {
+            break;
+        }
+        start += cluster.len();
+    }
+
+    kept[..start].trim_end_matches(is_zero_width).len()
+}
+
+// === impl clusters ===
+
+impl<'a> Clusters<'a> {
+    /// returns a new [`Clusters`] iterator.
+    pub(crate) fn new(s: &'a str) -> Self {
+        Self { rest: s }
+    }
+
+    /// returns the next cluster, without consuming it.
+    fn peek(&self) -> Option<&'a str> {
+        let Self { rest } = self;
+
+        let mut chars = rest.char_indices();
+        let (_, first) = chars.next()?;
+
+        let mut joined = first == ZWJ;
+        let end = chars
+            .find(|&(_, c)| {
+                let extends = joined || extends(c);
+                joined = c == ZWJ;
+                !extends
+            })
+            .map(|(i, _)| i)
+            .unwrap_or(rest.len());
+
+        Some(&rest[..end])
+    }
+}
+
+impl<'a> Iterator for Clusters<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cluster = self.peek()?;
+        self.rest = &self.rest[cluster.len()..];
+        Some(cluster)
+    }
+}
+
+/// the U+200D ZERO WIDTH JOINER character.
+const ZWJ: char = '\u{200D}';
+
+/// returns true if `c` extends the cluster before it, e.g. a combining mark.
+///
+/// control characters, such as line breaks, have no width but are never part of a cluster.
+fn extends(c: char) -> bool {
+    char_width(c) == 0 && !c.is_control()
+}
+
+/// returns the width of a cluster.
+///
+/// every character of a cluster is measured, like [`str_width()`], so that the width of a string
+/// is the sum of the widths of its clusters. this means that characters joined by a zero-width
+/// joiner are measured separately, even if a terminal renders them as one glyph.
+pub(crate) fn cluster_width(cluster: &str) -> usize {
+    str_width(cluster)
+}
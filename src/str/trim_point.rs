@@ -0,0 +1,21 @@
+use super::ellipsis::Ellipsis;
+
+/// returns the byte offset at which `value` would be cut to fit within `length` bytes.
+///
+/// see [`Limited::trim_point_for_length()`][super::Limited::trim_point_for_length].
+pub(super) fn for_length<E: Ellipsis>(value: &str, length: usize) -> Option<usize> {
+    match super::trim_to_length::pieces::<E>(value, length) {
+        [_, kept, ..] if kept.len() < value.len() => Some(kept.len()),
+        _ => None,
+    }
+}
+
+/// returns the byte offset at which `value` would be cut to fit within `width` columns.
+///
+/// see [`Limited::trim_point_for_width()`][super::Limited::trim_point_for_width].
+pub(super) fn for_width<E: Ellipsis>(value: &str, width: usize) -> Option<usize> {
+    match super::trim_to_width::pieces::<E>(value, width) {
+        [_, kept, ..] if kept.len() < value.len() => Some(kept.len()),
+        _ => None,
+    }
+}
@@ -0,0 +1,67 @@
+use {
+    super::ellipsis::Ellipsis,
+    crate::iter::{Limited, LimitedIter},
+    std::marker::PhantomData,
+    unicode_segmentation::UnicodeSegmentation,
+};
+
+pub struct TrimToGraphemesIter<I, E> {
+    iter: I,
+    ellipses: PhantomData<E>,
+}
+
+// === impl TrimToGraphemesIter ===
+
+impl<I, E> TrimToGraphemesIter<I, E> {
+    /// returns a new [`TrimToGraphemesIter`].
+    pub fn new(iter: I) -> Self {
+        Self {
+            iter,
+            ellipses: PhantomData,
+        }
+    }
+}
+
+/// grapheme cluster iterators can be limited with an [`Ellipsis`].
+impl<'a, I, E> Limited for TrimToGraphemesIter<I, E>
+where
+    I: Iterator<Item = &'a str> + Sized,
+    E: Ellipsis,
+{
+    fn limited(self, size: usize) -> LimitedIter<Self> {
+        LimitedIter::new(self, size)
+    }
+
+    type Contd = Vec<&'a str>;
+
+    fn contd() -> Self::Contd {
+        E::ellipsis()
+            .graphemes(true)
+            .map(|g| g as &'a str)
+            .collect()
+    }
+
+    /// counts each grapheme cluster as one unit.
+    fn element_size(_: &&'a str) -> usize {
+        1
+    }
+}
+
+impl<'a, I, E> Iterator for TrimToGraphemesIter<I, E>
+where
+    I: Iterator<Item = &'a str> + Sized,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { iter, .. } = self;
+
+        iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Self { iter, .. } = self;
+
+        iter.size_hint()
+    }
+}
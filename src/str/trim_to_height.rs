@@ -0,0 +1,187 @@
+use {super::ellipsis::Ellipsis, std::marker::PhantomData, tap::Pipe};
+
+/// an iterator over the lines of a string.
+///
+/// unlike [`str::lines()`], this recognizes U+0085 NEXT LINE, U+2028 LINE SEPARATOR, and U+2029
+/// PARAGRAPH SEPARATOR as line breaks, in addition to `\n` and `\r\n`. other line breaks can be
+/// recognized using [`Lines::with_terminators()`].
+#[derive(Clone)]
+pub struct Lines<'a> {
+    rest: Option<&'a str>,
+    terminators: Terminators,
+}
+
+/// the characters recognized as line breaks.
+///
+/// see [`Limited::trim_to_height_terminated()`][super::Limited::trim_to_height_terminated] and
+/// [`Lines::with_terminators()`] for more information.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Terminators {
+    /// `\n` and `\r\n`, as recognized by [`str::lines()`].
+    Ascii,
+    /// `\n`, `\r\n`, U+0085 NEXT LINE, U+2028 LINE SEPARATOR, and U+2029 PARAGRAPH SEPARATOR.
+    #[default]
+    Unicode,
+    /// each of the [`Unicode`][Terminators::Unicode] line breaks, as well as a lone `\r`.
+    ///
+    /// this recognizes text using classic mac line endings, or progress output that redraws a
+    /// line by returning to its start.
+    All,
+}
+
+/// an [`Ellipsis`] line, paired with its weight of one.
+///
+/// this is used to limit weighted lines, see [`crate::iter::Weighted`].
+pub struct WeightedEllipsis<'a, E> {
+    ellipses: PhantomData<(&'a (), E)>,
+}
+
+// === impl WeightedEllipsis ===
+
+impl<E> Default for WeightedEllipsis<'_, E> {
+    fn default() -> Self {
+        Self {
+            ellipses: PhantomData,
+        }
+    }
+}
+
+impl<'a, E: Ellipsis> IntoIterator for WeightedEllipsis<'a, E> {
+    type Item = (&'a str, usize);
+    type IntoIter = std::iter::Once<(&'a str, usize)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        std::iter::once((E::ellipsis(), 1))
+    }
+}
+
+// === impl Lines ===
+
+impl<'a> Lines<'a> {
+    /// returns a new [`Lines`] iterator.
+    pub fn new(s: &'a str) -> Self {
+        Self::with_terminators(s, Terminators::default())
+    }
+
+    /// returns a new [`Lines`] iterator, recognizing the given line breaks.
+    ///
+    /// ```
+    /// use shear::str::{Lines, Terminators};
+    ///
+    /// let s = "one\rtwo\r\nthree\u{2028}four";
+    ///
+    /// assert_eq!(Lines::new(s).count(), 3);
+    /// assert_eq!(Lines::with_terminators(s, Terminators::Ascii).count(), 2);
+    /// assert_eq!(Lines::with_terminators(s, Terminators::All).count(), 4);
+    /// ```
+    pub fn with_terminators(s: &'a str, terminators: Terminators) -> Self {
+        Self {
+            rest: (!s.is_empty()).then_some(s),
+            terminators,
+        }
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { rest, terminators } = self;
+        let rest = rest.take()?;
+
+        let Some((i, c)) = rest
+            .char_indices()
+            .find(|&(_, c)| terminators.is_line_break(c))
+        else {
+            return Some(rest); // this is the last line.
+        };
+
+        let (line, mut remaining) = (&rest[..i], &rest[i + c.len_utf8()..]);
+        if c == '\r' {
+            remaining = remaining.strip_prefix('\n').unwrap_or(remaining);
+        }
+        self.rest = (!remaining.is_empty()).then_some(remaining);
+
+        match c {
+            '\n' => line.strip_suffix('\r').unwrap_or(line),
+            _ => line,
+        }
+        .pipe(Some)
+    }
+}
+
+// === impl Terminators ===
+
+impl Terminators {
+    /// returns true if the given character is a line break.
+    ///
+    /// a `\r` that is followed by a `\n` is part of a `\r\n` line break, regardless of this.
+    fn is_line_break(self, c: char) -> bool {
+        match self {
+            Self::Ascii => c == '\n',
+            Self::Unicode => matches!(c, '\n' | '\u{0085}' | '\u{2028}' | '\u{2029}'),
+            Self::All => Self::Unicode.is_line_break(c) || c == '\r',
+        }
+    }
+}
+
+/// returns the line ending used by `s`, when split at the given line breaks.
+///
+/// this is `"\r\n"` if the first line break in `s` is a carriage return followed by a line feed,
+/// and `"\n"` otherwise.
+pub(super) fn line_ending(s: &str, terminators: Terminators) -> &'static str {
+    let Some(i) = s.find(|c| terminators.is_line_break(c)) else {
+        return "\n";
+    };
+
+    match s[i..].starts_with("\r\n") || (s[i..].starts_with('\n') && s[..i].ends_with('\r')) {
+        true => "\r\n",
+        false => "\n",
+    }
+}
+
+/// joins the lines yielded by `lines` with the given line ending.
+///
+/// the lines are measured first, so that only one allocation is needed.
+pub(super) fn join<'a, I>(lines: impl Fn() -> I, ending: &str) -> String
+where
+    I: Iterator<Item = &'a str>,
+{
+    let (count, size) = lines().fold((0_usize, 0), |(count, size), line| {
+        (count + 1, size + line.len())
+    });
+
+    let mut joined = String::with_capacity(size + ending.len() * count.saturating_sub(1));
+    for (i, line) in lines().enumerate() {
+        if i > 0 {
+            joined.push_str(ending);
+        }
+        joined.push_str(line);
+    }
+
+    joined
+}
+
+/// returns the end of the last sentence or paragraph in `text`.
+///
+/// a sentence ends with `.`, `!`, or `?`, followed by whitespace or the end of the text. a
+/// paragraph ends before a blank line. trailing whitespace is not included.
+pub(super) fn last_sentence_end(text: &str) -> Option<usize> {
+    let mut chars = text.char_indices().peekable();
+    let mut end = None;
+
+    while let Some((i, c)) = chars.next() {
+        let next = chars.peek().map(|&(_, c)| c);
+        let sentence = matches!(c, '.' | '!' | '?') && next.is_none_or(char::is_whitespace);
+        let paragraph = c == '\n' && next == Some('\n');
+        if sentence {
+            end = Some(i + c.len_utf8());
+        } else if paragraph {
+            end = Some(text[..i].trim_end().len())
+                .filter(|&end| end > 0)
+                .or(end);
+        }
+    }
+
+    end
+}
@@ -4,6 +4,40 @@ use {
     std::marker::PhantomData,
 };
 
+/// limits `value` by length.
+///
+/// returns the limited string, along with the number of bytes of `value` that it kept. see
+/// [`pieces()`] for more information.
+pub(super) fn trim_to_length<E: Ellipsis>(value: &str, length: usize) -> (String, usize) {
+    let pieces @ [_, kept, ..] = pieces::<E>(value, length);
+
+    (pieces.concat(), kept.len())
+}
+
+/// returns the pieces of `value`, limited by length.
+///
+/// these are the leading part of the [`Ellipsis`], the kept prefix of `value`, the ellipsis, and
+/// its trailing part. if the value fits, it is the only piece that is not empty. the value is
+/// never cut between a character and the combining marks that follow it.
+///
+/// every method that limits a string by length writes these pieces, so that each agrees with
+/// [`Limited::trim_to_length()`][super::Limited::trim_to_length].
+pub(super) fn pieces<E: Ellipsis>(value: &str, length: usize) -> [&str; 4] {
+    // we know the length of a string in advance, so we can check if the value fits into the
+    // given length, without having to iterate over its characters.
+    if value.len() <= length {
+        return ["", value, "", ""];
+    }
+
+    // otherwise, cut the string at the last cluster boundary that fits alongside the ellipsis. if
+    // the ellipsis does not fit, nothing is kept.
+    let (leading, ellipsis, trailing) = (E::leading(), E::ellipsis(), E::trailing());
+    let room = length.saturating_sub(leading.len() + ellipsis.len() + trailing.len());
+    let kept = super::split::cluster_floor(value, room);
+
+    [leading, &value[..kept], ellipsis, trailing]
+}
+
 pub struct TrimToLengthIter<I, E> {
     iter: I,
     ellipses: PhantomData<E>,
@@ -1,9 +1,68 @@
 use {
-    super::ellipsis::Ellipsis,
+    super::{
+        ellipsis::Ellipsis,
+        split::cluster_floor,
+        width::{char_width, str_width},
+    },
     crate::iter::{Limited, LimitedIter},
     std::marker::PhantomData,
 };
 
+/// limits `value` by width.
+///
+/// returns the limited string, along with the number of bytes of `value` that it kept. see
+/// [`pieces()`] for more information.
+pub(super) fn trim_to_width<E: Ellipsis>(value: &str, width: usize) -> (String, usize) {
+    let pieces @ [_, kept, ..] = pieces::<E>(value, width);
+
+    (pieces.concat(), kept.len())
+}
+
+/// returns the pieces of `value`, limited by width.
+///
+/// these are the leading part of the [`Ellipsis`], the kept prefix of `value`, the ellipsis, and
+/// its trailing part. if the value fits, it is the only piece that is not empty. the value is
+/// never cut inside of a cluster, e.g. between an emoji and its variation selector, or within a
+/// sequence of emoji joined by zero-width joiners.
+///
+/// every method that limits a string by width writes these pieces, so that each agrees with
+/// [`Limited::trim_to_width()`][super::Limited::trim_to_width].
+pub(super) fn pieces<E: Ellipsis>(value: &str, width: usize) -> [&str; 4] {
+    if str_width(value) <= width {
+        return ["", value, "", ""];
+    }
+
+    let (leading, ellipsis, trailing) = (E::leading(), E::ellipsis(), E::trailing());
+    let room = width.saturating_sub(str_width(leading) + str_width(trailing));
+    let kept = cut(value, room, str_width(ellipsis));
+
+    [leading, &value[..kept], ellipsis, trailing]
+}
+
+/// returns the offset at which `value`, which does not fit, is cut to fit within `width` columns
+/// alongside a marker that is `marker` columns wide.
+///
+/// if the width only has room for the marker, nothing is kept. otherwise, the cut is made before
+/// the first character that does not fit, and moved back to a cluster boundary if needed.
+pub(super) fn cut(value: &str, width: usize, marker: usize) -> usize {
+    let Some(mut room) = width.checked_sub(marker).filter(|&room| room > 0) else {
+        return 0;
+    };
+
+    let end = value
+        .char_indices()
+        .find(|&(_, c)| match room.checked_sub(char_width(c)) {
+            Some(rest) => {
+                room = rest;
+                false
+            }
+            None => true,
+        })
+        .map_or(value.len(), |(i, _)| i);
+
+    cluster_floor(value, end)
+}
+
 pub struct TrimToWidthIter<I, E> {
     iter: I,
     ellipses: PhantomData<E>,
@@ -27,8 +86,10 @@ where
     I: Iterator<Item = char> + Sized,
     E: Ellipsis,
 {
+    /// characters that fit are never replaced by the ellipsis, even if `size` only has room for
+    /// the ellipsis.
     fn limited(self, size: usize) -> LimitedIter<Self> {
-        LimitedIter::new(self, size)
+        LimitedIter::fitting(self, size, E::ellipsis().chars())
     }
 
     type Contd = std::str::Chars<'static>;
@@ -41,10 +102,7 @@ where
     ///
     /// see [`unicode_width`] for more information.
     fn element_size(c: &char) -> usize {
-        use unicode_width::UnicodeWidthChar;
-
-        c.width()
-            .unwrap_or_default(/* returns `None` for control characters */)
+        super::width::char_width(*c)
     }
 }
 
@@ -66,3 +124,36 @@ where
         iter.size_hint()
     }
 }
+
+/// the characters of an [`Ellipsis`], paired with their widths.
+///
+/// this is used to limit pre-measured characters, see [`crate::iter::Weighted`].
+pub struct MeasuredEllipsis<E> {
+    ellipses: PhantomData<E>,
+}
+
+// === impl MeasuredEllipsis ===
+
+impl<E> Default for MeasuredEllipsis<E> {
+    fn default() -> Self {
+        Self {
+            ellipses: PhantomData,
+        }
+    }
+}
+
+impl<E: Ellipsis> IntoIterator for MeasuredEllipsis<E> {
+    type Item = (char, usize);
+    type IntoIter = std::iter::Map<std::str::Chars<'static>, fn(char) -> (char, usize)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        /// helper fn: pairs a character with its width.
+        fn measure(c: char) -> (char, usize) {
+            (c, super::width::char_width(c))
+        }
+
+        E::ellipsis()
+            .chars()
+            .map(measure as fn(char) -> (char, usize))
+    }
+}
@@ -0,0 +1,42 @@
+use {
+    super::{ellipsis::Ellipsis, Limited},
+    std::{fmt, marker::PhantomData},
+};
+
+/// a string that is limited by length when it is displayed.
+///
+/// see [`Limited::display_trimmed()`] for more information.
+pub struct Trimmed<'a, E> {
+    /// the string to display.
+    value: &'a str,
+    /// the maximum length of the displayed string, in bytes.
+    length: usize,
+    ellipsis: PhantomData<E>,
+}
+
+// === impl trimmed ===
+
+impl<'a, E> Trimmed<'a, E> {
+    /// returns a new [`Trimmed`] value.
+    pub(super) fn new(value: &'a str, length: usize) -> Self {
+        Self {
+            value,
+            length,
+            ellipsis: PhantomData,
+        }
+    }
+}
+
+impl<E: Ellipsis> fmt::Display for Trimmed<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { value, length, .. } = self;
+
+        value.write_limited::<E, _>(*length, f)
+    }
+}
+
+impl<E: Ellipsis> fmt::Debug for Trimmed<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_string(), f)
+    }
+}
@@ -0,0 +1,301 @@
+use {
+    super::{ellipsis::Ellipsis, width::str_width, Lines},
+    std::marker::PhantomData,
+};
+
+/// a unit by which strings can be limited.
+///
+/// a string is split into units using [`Units::units()`], and the size of each unit is
+/// determined by [`Units::size()`]. when a string is limited, the units that fit are joined
+/// together using [`Units::SEPARATOR`], followed by the [`Ellipsis`] if the string did not fit.
+///
+/// [`Length`], [`Width`], [`Chars`], [`Utf16`], and [`Height`] are provided, and correspond to
+/// the [`trim_to_length()`][super::Limited::trim_to_length],
+/// [`trim_to_width()`][super::Limited::trim_to_width],
+/// [`trim_to_chars()`][super::Limited::trim_to_chars],
+/// [`trim_to_utf16_units()`][super::Limited::trim_to_utf16_units], and
+/// [`trim_to_height()`][super::Limited::trim_to_height] methods. other units, such as words or
+/// the tokens of a lexer, can be defined by implementing this trait.
+///
+/// see [`Limited::trim_to_units()`][super::Limited::trim_to_units] for more information.
+///
+/// # examples
+///
+/// ```
+/// use shear::str::{ellipsis, units::Units, Limited};
+///
+/// /// a comma-separated field.
+/// struct Fields;
+///
+/// impl Units for Fields {
+///     type Iter<'a> = std::str::Split<'a, char>;
+///     const SEPARATOR: &'static str = ",";
+///
+///     fn units(s: &str) -> Self::Iter<'_> {
+///         s.split(',')
+///     }
+/// }
+///
+/// let limited = "a,b,c,d,e".trim_to_units::<Fields, ellipsis::Ascii>(3);
+/// assert_eq!(limited, "a,b,...");
+/// ```
+pub trait Units {
+    /// the type of iterator returned by [`Units::units()`].
+    type Iter<'a>: Iterator<Item = &'a str>;
+
+    /// the separator used to join units back together.
+    ///
+    /// by default, units are concatenated.
+    const SEPARATOR: &'static str = "";
+
+    /// returns an iterator over the units of a string.
+    fn units(s: &str) -> Self::Iter<'_>;
+
+    /// returns the size of a unit.
+    ///
+    /// by default this counts each unit as 1.
+    fn size(_unit: &str) -> usize {
+        1
+    }
+}
+
+/// a measurement by which strings can be limited.
+///
+/// this is like [`Units`], but is given a value. this allows a measurement to carry state, such
+/// as the glyph metrics of a proportional font. every [`Units`] implementation is also a
+/// [`Measure`], so the built-in [`Length`], [`Width`], [`Chars`], [`Utf16`], and [`Height`]
+/// units can be passed to [`Limited::trim_with()`][super::Limited::trim_with] as well.
+///
+/// the [`Ellipsis`] is measured using [`Measure::measure()`], as though it were a single unit.
+/// strings that fit within the budget are returned unaltered, even if the budget only has room
+/// for the ellipsis.
+///
+/// # examples
+///
+/// ```
+/// use shear::str::{
+///     ellipsis,
+///     units::{Measure, Units, Width},
+///     Limited,
+/// };
+///
+/// /// a proportional font, whose narrow glyphs are half as wide as the others.
+/// struct Font {
+///     narrow: &'static str,
+/// }
+///
+/// impl Measure for Font {
+///     type Iter<'a> = <Width as Units>::Iter<'a>;
+///
+///     fn split<'a>(&self, s: &'a str) -> Self::Iter<'a> {
+///         Width::units(s)
+///     }
+///
+///     /// returns the width of a string, in half-columns.
+///     fn measure(&self, unit: &str) -> usize {
+///         unit.chars()
+///             .map(|c| if self.narrow.contains(c) { 1 } else { 2 })
+///             .sum()
+///     }
+/// }
+///
+/// let font = Font { narrow: "il.!' " };
+///
+/// assert_eq!("illicit".trim_with::<ellipsis::Ascii>(&font, 12), "illicit");
+/// assert_eq!("millimetre".trim_with::<ellipsis::Ascii>(&font, 12), "millim...");
+/// ```
+pub trait Measure {
+    /// the type of iterator returned by [`Measure::split()`].
+    type Iter<'a>: Iterator<Item = &'a str>;
+
+    /// returns the separator used to join units back together.
+    ///
+    /// by default, units are concatenated.
+    fn separator(&self) -> &str {
+        ""
+    }
+
+    /// returns an iterator over the units of a string.
+    fn split<'a>(&self, s: &'a str) -> Self::Iter<'a>;
+
+    /// returns the size of a unit.
+    fn measure(&self, unit: &str) -> usize;
+}
+
+/// characters, measured by their length in bytes.
+pub struct Length;
+
+/// characters, measured by their visual width.
+pub struct Width;
+
+/// characters, measured by their number.
+pub struct Chars;
+
+/// characters, measured by their length in utf-16 code units.
+pub struct Utf16;
+
+/// lines, measured by their number.
+pub struct Height;
+
+/// a [`Measure`] value for a [`Units`] type, which need not be constructed itself.
+pub(super) struct Static<U>(PhantomData<U>);
+
+/// the type of iterator used for character units.
+type CharSlices<'a> = std::str::SplitInclusive<'a, fn(char) -> bool>;
+
+/// returns an iterator over the characters of a string, as string slices.
+fn chars(s: &str) -> CharSlices<'_> {
+    s.split_inclusive((|_| true) as fn(char) -> bool)
+}
+
+// === impl measure ===
+
+impl<U: Units> Measure for U {
+    type Iter<'a> = U::Iter<'a>;
+
+    fn separator(&self) -> &str {
+        U::SEPARATOR
+    }
+
+    fn split<'a>(&self, s: &'a str) -> Self::Iter<'a> {
+        U::units(s)
+    }
+
+    fn measure(&self, unit: &str) -> usize {
+        U::size(unit)
+    }
+}
+
+// === impl static ===
+
+impl<U> Static<U> {
+    /// returns a new [`Static`] measure.
+    pub(super) fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<U: Units> Measure for Static<U> {
+    type Iter<'a> = U::Iter<'a>;
+
+    fn separator(&self) -> &str {
+        U::SEPARATOR
+    }
+
+    fn split<'a>(&self, s: &'a str) -> Self::Iter<'a> {
+        U::units(s)
+    }
+
+    fn measure(&self, unit: &str) -> usize {
+        U::size(unit)
+    }
+}
+
+/// limits `value` to `budget`, according to the given [`Measure`].
+///
+/// units that fit are written directly, once there is room left over for the ellipsis. units that
+/// would only fit without the ellipsis are held back, until it is known whether the rest of the
+/// string fits.
+pub(super) fn trim_with<E: Ellipsis>(value: &str, measure: &impl Measure, budget: usize) -> String {
+    let (ellipsis, separator) = (E::ellipsis(), measure.separator());
+    let reserve = measure.measure(ellipsis);
+
+    // helper fn: pushes a unit onto the output, following a separator if needed.
+    let push = |out: &mut String, first: &mut bool, unit: &str| {
+        if !std::mem::take(first) {
+            out.push_str(separator);
+        }
+        out.push_str(unit);
+    };
+
+    let (mut out, mut first) = (String::new(), true);
+    let (mut pending, mut used, mut held) = (Vec::new(), 0, 0);
+    for unit in measure.split(value) {
+        let size = measure.measure(unit);
+        if pending.is_empty() && used + size + reserve <= budget {
+            push(&mut out, &mut first, unit);
+            used += size;
+        } else if used + held + size <= budget {
+            pending.push(unit);
+            held += size;
+        } else {
+            push(&mut out, &mut first, ellipsis);
+            return out;
+        }
+    }
+
+    // everything fit, so write the units that were held back.
+    for unit in pending {
+        push(&mut out, &mut first, unit);
+    }
+
+    out
+}
+
+// === impl length ===
+
+impl Units for Length {
+    type Iter<'a> = CharSlices<'a>;
+
+    fn units(s: &str) -> Self::Iter<'_> {
+        chars(s)
+    }
+
+    fn size(unit: &str) -> usize {
+        unit.len()
+    }
+}
+
+// === impl width ===
+
+impl Units for Width {
+    type Iter<'a> = CharSlices<'a>;
+
+    fn units(s: &str) -> Self::Iter<'_> {
+        chars(s)
+    }
+
+    fn size(unit: &str) -> usize {
+        str_width(unit)
+    }
+}
+
+// === impl chars ===
+
+impl Units for Chars {
+    type Iter<'a> = CharSlices<'a>;
+
+    fn units(s: &str) -> Self::Iter<'_> {
+        chars(s)
+    }
+
+    /// counts each character, so that a multi-character [`Ellipsis`] is measured correctly.
+    fn size(unit: &str) -> usize {
+        unit.chars().count()
+    }
+}
+
+// === impl utf16 ===
+
+impl Units for Utf16 {
+    type Iter<'a> = CharSlices<'a>;
+
+    fn units(s: &str) -> Self::Iter<'_> {
+        chars(s)
+    }
+
+    fn size(unit: &str) -> usize {
+        unit.encode_utf16().count()
+    }
+}
+
+// === impl height ===
+
+impl Units for Height {
+    type Iter<'a> = Lines<'a>;
+    const SEPARATOR: &'static str = "\n";
+
+    fn units(s: &str) -> Self::Iter<'_> {
+        Lines::new(s)
+    }
+}
@@ -0,0 +1,85 @@
+use super::{ellipsis::Ellipsis, width::str_width, Limited};
+
+/// returns a url, shortened to fit within `length` bytes.
+///
+/// see [`trim_to_width()`] for more information.
+///
+/// ```
+/// use shear::str::{ellipsis::Ascii, url};
+///
+/// let u = "https://example.com/docs/guides/install/linux?arch=x86_64";
+///
+/// assert_eq!(url::trim_to_length::<Ascii>(u, 45), "https://example.com/.../linux?arch=x86_64");
+/// ```
+pub fn trim_to_length<E: Ellipsis>(url: &str, length: usize) -> String {
+    shorten::<E>(url, length, str::len, |url| url.trim_to_length::<E>(length))
+}
+
+/// returns a url, shortened to fit within `width` columns.
+///
+/// naively truncating the end of a url hides its most identifying parts. instead, this keeps
+/// the scheme and host, along with the last segments of the path, the query, and the fragment,
+/// replacing segments from the middle of the path with an [`Ellipsis`]. as many of the last
+/// segments of the path are kept as will fit.
+///
+/// if the last segment and the query do not fit, the shortened url is truncated at its end, as
+/// if by [`trim_to_width()`][Limited::trim_to_width]. strings without a scheme and host are
+/// truncated the same way.
+///
+/// # examples
+///
+/// ```
+/// use shear::str::{ellipsis::Horizontal, url};
+///
+/// let u = "https://example.com/blog/2024/05/a-post/page?id=9";
+///
+/// assert_eq!(url::trim_to_width::<Horizontal>(u, 60), u);
+/// assert_eq!(url::trim_to_width::<Horizontal>(u, 40), "https://example.com/…/a-post/page?id=9");
+/// assert_eq!(url::trim_to_width::<Horizontal>(u, 32), "https://example.com/…/page?id=9");
+/// assert_eq!(url::trim_to_width::<Horizontal>(u, 24), "https://example.com/…/p…");
+/// ```
+pub fn trim_to_width<E: Ellipsis>(url: &str, width: usize) -> String {
+    shorten::<E>(url, width, str_width, |url| url.trim_to_width::<E>(width))
+}
+
+/// shortens `url` to fit within `budget`, eliding segments from the middle of its path.
+///
+/// `measure` returns the size of a string, and `trim` truncates a string that does not fit.
+fn shorten<E: Ellipsis>(
+    url: &str,
+    budget: usize,
+    measure: impl Fn(&str) -> usize,
+    trim: impl Fn(&str) -> String,
+) -> String {
+    if measure(url) <= budget {
+        return url.to_owned();
+    }
+
+    // split the url into its origin, its path, and the query and fragment that follow it.
+    let Some(scheme) = url.find("://").map(|i| i + "://".len()) else {
+        return trim(url);
+    };
+    let path = url[scheme..]
+        .find(['/', '?', '#'])
+        .map_or(url.len(), |i| scheme + i);
+    let tail = url[path..].find(['?', '#']).map_or(url.len(), |i| path + i);
+    let (origin, segments, tail) = (&url[..path], &url[path..tail], &url[tail..]);
+
+    // find where each segment of the path begins, after its leading `/`.
+    let starts = segments
+        .match_indices('/')
+        .map(|(i, _)| i + 1)
+        .collect::<Vec<_>>();
+
+    // keep as many of the last segments as will fit, eliding at least one segment.
+    let mut shortened = None;
+    for &start in starts.iter().skip(1) {
+        let candidate = [origin, "/", E::ellipsis(), "/", &segments[start..], tail].concat();
+        if measure(&candidate) <= budget {
+            return candidate;
+        }
+        shortened = Some(candidate);
+    }
+
+    trim(shortened.as_deref().unwrap_or(url))
+}
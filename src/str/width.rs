@@ -0,0 +1,33 @@
+/// a pinned table of character widths.
+///
+/// this was generated from version 0.1.13 of the `unicode-width` crate, and lists each range of
+/// characters whose width is not one column.
+#[cfg(feature = "pinned-width")]
+mod pinned;
+
+/// the version of unicode used to measure the width of characters.
+#[cfg(feature = "pinned-width")]
+pub(crate) const UNICODE_VERSION: (u8, u8, u8) = pinned::UNICODE_VERSION;
+
+/// the version of unicode used to measure the width of characters.
+#[cfg(not(feature = "pinned-width"))]
+pub(crate) const UNICODE_VERSION: (u8, u8, u8) = unicode_width::UNICODE_VERSION;
+
+/// returns the width of a character, treating control characters as zero columns wide.
+#[cfg(feature = "pinned-width")]
+pub(crate) fn char_width(c: char) -> usize {
+    pinned::char_width(c)
+}
+
+/// returns the width of a character, treating control characters as zero columns wide.
+#[cfg(not(feature = "pinned-width"))]
+pub(crate) fn char_width(c: char) -> usize {
+    use unicode_width::UnicodeWidthChar;
+
+    c.width().unwrap_or_default()
+}
+
+/// returns the width of a string, which is the sum of the widths of its characters.
+pub(crate) fn str_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
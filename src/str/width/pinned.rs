@@ -0,0 +1,523 @@
+// NB: this file is generated from version 0.1.13 of the `unicode-width` crate. characters for
+// which `unicode-width` returns `None`, i.e. control characters, are zero columns wide. it must
+// not be edited, so that output remains stable.
+
+/// the version of unicode that this table implements.
+pub(super) const UNICODE_VERSION: (u8, u8, u8) = (15, 1, 0);
+
+/// ranges of characters whose width is not one column, sorted by their first character.
+const TABLE: &[(char, char, u8)] = &[
+    ('\u{0}', '\u{1F}', 0),
+    ('\u{7F}', '\u{9F}', 0),
+    ('\u{AD}', '\u{AD}', 0),
+    ('\u{300}', '\u{36F}', 0),
+    ('\u{483}', '\u{489}', 0),
+    ('\u{591}', '\u{5BD}', 0),
+    ('\u{5BF}', '\u{5BF}', 0),
+    ('\u{5C1}', '\u{5C2}', 0),
+    ('\u{5C4}', '\u{5C5}', 0),
+    ('\u{5C7}', '\u{5C7}', 0),
+    ('\u{605}', '\u{605}', 0),
+    ('\u{610}', '\u{61A}', 0),
+    ('\u{61C}', '\u{61C}', 0),
+    ('\u{64B}', '\u{65F}', 0),
+    ('\u{670}', '\u{670}', 0),
+    ('\u{6D6}', '\u{6DC}', 0),
+    ('\u{6DF}', '\u{6E4}', 0),
+    ('\u{6E7}', '\u{6E8}', 0),
+    ('\u{6EA}', '\u{6ED}', 0),
+    ('\u{70F}', '\u{70F}', 0),
+    ('\u{711}', '\u{711}', 0),
+    ('\u{730}', '\u{74A}', 0),
+    ('\u{7A6}', '\u{7B0}', 0),
+    ('\u{7EB}', '\u{7F3}', 0),
+    ('\u{7FD}', '\u{7FD}', 0),
+    ('\u{816}', '\u{819}', 0),
+    ('\u{81B}', '\u{823}', 0),
+    ('\u{825}', '\u{827}', 0),
+    ('\u{829}', '\u{82D}', 0),
+    ('\u{859}', '\u{85B}', 0),
+    ('\u{890}', '\u{891}', 0),
+    ('\u{898}', '\u{89F}', 0),
+    ('\u{8CA}', '\u{902}', 0),
+    ('\u{93A}', '\u{93A}', 0),
+    ('\u{93C}', '\u{93C}', 0),
+    ('\u{941}', '\u{948}', 0),
+    ('\u{94D}', '\u{94D}', 0),
+    ('\u{951}', '\u{957}', 0),
+    ('\u{962}', '\u{963}', 0),
+    ('\u{981}', '\u{981}', 0),
+    ('\u{9BC}', '\u{9BC}', 0),
+    ('\u{9BE}', '\u{9BE}', 0),
+    ('\u{9C1}', '\u{9C4}', 0),
+    ('\u{9CD}', '\u{9CD}', 0),
+    ('\u{9D7}', '\u{9D7}', 0),
+    ('\u{9E2}', '\u{9E3}', 0),
+    ('\u{9FE}', '\u{9FE}', 0),
+    ('\u{A01}', '\u{A02}', 0),
+    ('\u{A3C}', '\u{A3C}', 0),
+    ('\u{A41}', '\u{A42}', 0),
+    ('\u{A47}', '\u{A48}', 0),
+    ('\u{A4B}', '\u{A4D}', 0),
+    ('\u{A51}', '\u{A51}', 0),
+    ('\u{A70}', '\u{A71}', 0),
+    ('\u{A75}', '\u{A75}', 0),
+    ('\u{A81}', '\u{A82}', 0),
+    ('\u{ABC}', '\u{ABC}', 0),
+    ('\u{AC1}', '\u{AC5}', 0),
+    ('\u{AC7}', '\u{AC8}', 0),
+    ('\u{ACD}', '\u{ACD}', 0),
+    ('\u{AE2}', '\u{AE3}', 0),
+    ('\u{AFA}', '\u{AFF}', 0),
+    ('\u{B01}', '\u{B01}', 0),
+    ('\u{B3C}', '\u{B3C}', 0),
+    ('\u{B3E}', '\u{B3F}', 0),
+    ('\u{B41}', '\u{B44}', 0),
+    ('\u{B4D}', '\u{B4D}', 0),
+    ('\u{B55}', '\u{B57}', 0),
+    ('\u{B62}', '\u{B63}', 0),
+    ('\u{B82}', '\u{B82}', 0),
+    ('\u{BBE}', '\u{BBE}', 0),
+    ('\u{BC0}', '\u{BC0}', 0),
+    ('\u{BCD}', '\u{BCD}', 0),
+    ('\u{BD7}', '\u{BD7}', 0),
+    ('\u{C00}', '\u{C00}', 0),
+    ('\u{C04}', '\u{C04}', 0),
+    ('\u{C3C}', '\u{C3C}', 0),
+    ('\u{C3E}', '\u{C40}', 0),
+    ('\u{C46}', '\u{C48}', 0),
+    ('\u{C4A}', '\u{C4D}', 0),
+    ('\u{C55}', '\u{C56}', 0),
+    ('\u{C62}', '\u{C63}', 0),
+    ('\u{C81}', '\u{C81}', 0),
+    ('\u{CBC}', '\u{CBC}', 0),
+    ('\u{CBF}', '\u{CC0}', 0),
+    ('\u{CC2}', '\u{CC2}', 0),
+    ('\u{CC6}', '\u{CC8}', 0),
+    ('\u{CCA}', '\u{CCD}', 0),
+    ('\u{CD5}', '\u{CD6}', 0),
+    ('\u{CE2}', '\u{CE3}', 0),
+    ('\u{D00}', '\u{D01}', 0),
+    ('\u{D3B}', '\u{D3C}', 0),
+    ('\u{D3E}', '\u{D3E}', 0),
+    ('\u{D41}', '\u{D44}', 0),
+    ('\u{D4D}', '\u{D4D}', 0),
+    ('\u{D57}', '\u{D57}', 0),
+    ('\u{D62}', '\u{D63}', 0),
+    ('\u{D81}', '\u{D81}', 0),
+    ('\u{DCA}', '\u{DCA}', 0),
+    ('\u{DCF}', '\u{DCF}', 0),
+    ('\u{DD2}', '\u{DD4}', 0),
+    ('\u{DD6}', '\u{DD6}', 0),
+    ('\u{DDF}', '\u{DDF}', 0),
+    ('\u{E31}', '\u{E31}', 0),
+    ('\u{E34}', '\u{E3A}', 0),
+    ('\u{E47}', '\u{E4E}', 0),
+    ('\u{EB1}', '\u{EB1}', 0),
+    ('\u{EB4}', '\u{EBC}', 0),
+    ('\u{EC8}', '\u{ECE}', 0),
+    ('\u{F18}', '\u{F19}', 0),
+    ('\u{F35}', '\u{F35}', 0),
+    ('\u{F37}', '\u{F37}', 0),
+    ('\u{F39}', '\u{F39}', 0),
+    ('\u{F71}', '\u{F7E}', 0),
+    ('\u{F80}', '\u{F84}', 0),
+    ('\u{F86}', '\u{F87}', 0),
+    ('\u{F8D}', '\u{F97}', 0),
+    ('\u{F99}', '\u{FBC}', 0),
+    ('\u{FC6}', '\u{FC6}', 0),
+    ('\u{102D}', '\u{1030}', 0),
+    ('\u{1032}', '\u{1037}', 0),
+    ('\u{1039}', '\u{103A}', 0),
+    ('\u{103D}', '\u{103E}', 0),
+    ('\u{1058}', '\u{1059}', 0),
+    ('\u{105E}', '\u{1060}', 0),
+    ('\u{1071}', '\u{1074}', 0),
+    ('\u{1082}', '\u{1082}', 0),
+    ('\u{1085}', '\u{1086}', 0),
+    ('\u{108D}', '\u{108D}', 0),
+    ('\u{109D}', '\u{109D}', 0),
+    ('\u{1100}', '\u{115F}', 2),
+    ('\u{1160}', '\u{11FF}', 0),
+    ('\u{135D}', '\u{135F}', 0),
+    ('\u{1712}', '\u{1714}', 0),
+    ('\u{1732}', '\u{1733}', 0),
+    ('\u{1752}', '\u{1753}', 0),
+    ('\u{1772}', '\u{1773}', 0),
+    ('\u{17B4}', '\u{17B5}', 0),
+    ('\u{17B7}', '\u{17BD}', 0),
+    ('\u{17C6}', '\u{17C6}', 0),
+    ('\u{17C9}', '\u{17D3}', 0),
+    ('\u{17DD}', '\u{17DD}', 0),
+    ('\u{180B}', '\u{180F}', 0),
+    ('\u{1885}', '\u{1886}', 0),
+    ('\u{18A9}', '\u{18A9}', 0),
+    ('\u{1920}', '\u{1922}', 0),
+    ('\u{1927}', '\u{1928}', 0),
+    ('\u{1932}', '\u{1932}', 0),
+    ('\u{1939}', '\u{193B}', 0),
+    ('\u{1A17}', '\u{1A18}', 0),
+    ('\u{1A1B}', '\u{1A1B}', 0),
+    ('\u{1A56}', '\u{1A56}', 0),
+    ('\u{1A58}', '\u{1A5E}', 0),
+    ('\u{1A60}', '\u{1A60}', 0),
+    ('\u{1A62}', '\u{1A62}', 0),
+    ('\u{1A65}', '\u{1A6C}', 0),
+    ('\u{1A73}', '\u{1A7C}', 0),
+    ('\u{1A7F}', '\u{1A7F}', 0),
+    ('\u{1AB0}', '\u{1ACE}', 0),
+    ('\u{1B00}', '\u{1B03}', 0),
+    ('\u{1B34}', '\u{1B3D}', 0),
+    ('\u{1B42}', '\u{1B43}', 0),
+    ('\u{1B6B}', '\u{1B73}', 0),
+    ('\u{1B80}', '\u{1B81}', 0),
+    ('\u{1BA2}', '\u{1BA5}', 0),
+    ('\u{1BA8}', '\u{1BA9}', 0),
+    ('\u{1BAB}', '\u{1BAD}', 0),
+    ('\u{1BE6}', '\u{1BE6}', 0),
+    ('\u{1BE8}', '\u{1BE9}', 0),
+    ('\u{1BED}', '\u{1BED}', 0),
+    ('\u{1BEF}', '\u{1BF1}', 0),
+    ('\u{1C2C}', '\u{1C33}', 0),
+    ('\u{1C36}', '\u{1C37}', 0),
+    ('\u{1CD0}', '\u{1CD2}', 0),
+    ('\u{1CD4}', '\u{1CE0}', 0),
+    ('\u{1CE2}', '\u{1CE8}', 0),
+    ('\u{1CED}', '\u{1CED}', 0),
+    ('\u{1CF4}', '\u{1CF4}', 0),
+    ('\u{1CF8}', '\u{1CF9}', 0),
+    ('\u{1DC0}', '\u{1DFF}', 0),
+    ('\u{200B}', '\u{200F}', 0),
+    ('\u{202A}', '\u{202E}', 0),
+    ('\u{2060}', '\u{206F}', 0),
+    ('\u{20D0}', '\u{20F0}', 0),
+    ('\u{231A}', '\u{231B}', 2),
+    ('\u{2329}', '\u{232A}', 2),
+    ('\u{23E9}', '\u{23EC}', 2),
+    ('\u{23F0}', '\u{23F0}', 2),
+    ('\u{23F3}', '\u{23F3}', 2),
+    ('\u{25FD}', '\u{25FE}', 2),
+    ('\u{2614}', '\u{2615}', 2),
+    ('\u{2648}', '\u{2653}', 2),
+    ('\u{267F}', '\u{267F}', 2),
+    ('\u{2693}', '\u{2693}', 2),
+    ('\u{26A1}', '\u{26A1}', 2),
+    ('\u{26AA}', '\u{26AB}', 2),
+    ('\u{26BD}', '\u{26BE}', 2),
+    ('\u{26C4}', '\u{26C5}', 2),
+    ('\u{26CE}', '\u{26CE}', 2),
+    ('\u{26D4}', '\u{26D4}', 2),
+    ('\u{26EA}', '\u{26EA}', 2),
+    ('\u{26F2}', '\u{26F3}', 2),
+    ('\u{26F5}', '\u{26F5}', 2),
+    ('\u{26FA}', '\u{26FA}', 2),
+    ('\u{26FD}', '\u{26FD}', 2),
+    ('\u{2705}', '\u{2705}', 2),
+    ('\u{270A}', '\u{270B}', 2),
+    ('\u{2728}', '\u{2728}', 2),
+    ('\u{274C}', '\u{274C}', 2),
+    ('\u{274E}', '\u{274E}', 2),
+    ('\u{2753}', '\u{2755}', 2),
+    ('\u{2757}', '\u{2757}', 2),
+    ('\u{2795}', '\u{2797}', 2),
+    ('\u{27B0}', '\u{27B0}', 2),
+    ('\u{27BF}', '\u{27BF}', 2),
+    ('\u{2B1B}', '\u{2B1C}', 2),
+    ('\u{2B50}', '\u{2B50}', 2),
+    ('\u{2B55}', '\u{2B55}', 2),
+    ('\u{2CEF}', '\u{2CF1}', 0),
+    ('\u{2D7F}', '\u{2D7F}', 0),
+    ('\u{2DE0}', '\u{2DFF}', 0),
+    ('\u{2E80}', '\u{2E99}', 2),
+    ('\u{2E9B}', '\u{2EF3}', 2),
+    ('\u{2F00}', '\u{2FD5}', 2),
+    ('\u{2FF0}', '\u{3029}', 2),
+    ('\u{302A}', '\u{302F}', 0),
+    ('\u{3030}', '\u{303E}', 2),
+    ('\u{3041}', '\u{3096}', 2),
+    ('\u{3099}', '\u{309A}', 0),
+    ('\u{309B}', '\u{30FF}', 2),
+    ('\u{3105}', '\u{312F}', 2),
+    ('\u{3131}', '\u{3163}', 2),
+    ('\u{3164}', '\u{3164}', 0),
+    ('\u{3165}', '\u{318E}', 2),
+    ('\u{3190}', '\u{31E3}', 2),
+    ('\u{31EF}', '\u{321E}', 2),
+    ('\u{3220}', '\u{3247}', 2),
+    ('\u{3250}', '\u{4DBF}', 2),
+    ('\u{4E00}', '\u{A48C}', 2),
+    ('\u{A490}', '\u{A4C6}', 2),
+    ('\u{A66F}', '\u{A672}', 0),
+    ('\u{A674}', '\u{A67D}', 0),
+    ('\u{A69E}', '\u{A69F}', 0),
+    ('\u{A6F0}', '\u{A6F1}', 0),
+    ('\u{A802}', '\u{A802}', 0),
+    ('\u{A806}', '\u{A806}', 0),
+    ('\u{A80B}', '\u{A80B}', 0),
+    ('\u{A825}', '\u{A826}', 0),
+    ('\u{A82C}', '\u{A82C}', 0),
+    ('\u{A8C4}', '\u{A8C5}', 0),
+    ('\u{A8E0}', '\u{A8F1}', 0),
+    ('\u{A8FA}', '\u{A8FA}', 0),
+    ('\u{A8FF}', '\u{A8FF}', 0),
+    ('\u{A926}', '\u{A92D}', 0),
+    ('\u{A947}', '\u{A951}', 0),
+    ('\u{A960}', '\u{A97C}', 2),
+    ('\u{A980}', '\u{A982}', 0),
+    ('\u{A9B3}', '\u{A9B3}', 0),
+    ('\u{A9B6}', '\u{A9B9}', 0),
+    ('\u{A9BC}', '\u{A9BD}', 0),
+    ('\u{A9E5}', '\u{A9E5}', 0),
+    ('\u{AA29}', '\u{AA2E}', 0),
+    ('\u{AA31}', '\u{AA32}', 0),
+    ('\u{AA35}', '\u{AA36}', 0),
+    ('\u{AA43}', '\u{AA43}', 0),
+    ('\u{AA4C}', '\u{AA4C}', 0),
+    ('\u{AA7C}', '\u{AA7C}', 0),
+    ('\u{AAB0}', '\u{AAB0}', 0),
+    ('\u{AAB2}', '\u{AAB4}', 0),
+    ('\u{AAB7}', '\u{AAB8}', 0),
+    ('\u{AABE}', '\u{AABF}', 0),
+    ('\u{AAC1}', '\u{AAC1}', 0),
+    ('\u{AAEC}', '\u{AAED}', 0),
+    ('\u{AAF6}', '\u{AAF6}', 0),
+    ('\u{ABE5}', '\u{ABE5}', 0),
+    ('\u{ABE8}', '\u{ABE8}', 0),
+    ('\u{ABED}', '\u{ABED}', 0),
+    ('\u{AC00}', '\u{D7A3}', 2),
+    ('\u{D7B0}', '\u{D7C6}', 0),
+    ('\u{D7CB}', '\u{D7FB}', 0),
+    ('\u{F900}', '\u{FAFF}', 2),
+    ('\u{FB1E}', '\u{FB1E}', 0),
+    ('\u{FE00}', '\u{FE0F}', 0),
+    ('\u{FE10}', '\u{FE19}', 2),
+    ('\u{FE20}', '\u{FE2F}', 0),
+    ('\u{FE30}', '\u{FE52}', 2),
+    ('\u{FE54}', '\u{FE66}', 2),
+    ('\u{FE68}', '\u{FE6B}', 2),
+    ('\u{FEFF}', '\u{FEFF}', 0),
+    ('\u{FF01}', '\u{FF60}', 2),
+    ('\u{FF9E}', '\u{FFA0}', 0),
+    ('\u{FFE0}', '\u{FFE6}', 2),
+    ('\u{FFF0}', '\u{FFF8}', 0),
+    ('\u{101FD}', '\u{101FD}', 0),
+    ('\u{102E0}', '\u{102E0}', 0),
+    ('\u{10376}', '\u{1037A}', 0),
+    ('\u{10A01}', '\u{10A03}', 0),
+    ('\u{10A05}', '\u{10A06}', 0),
+    ('\u{10A0C}', '\u{10A0F}', 0),
+    ('\u{10A38}', '\u{10A3A}', 0),
+    ('\u{10A3F}', '\u{10A3F}', 0),
+    ('\u{10AE5}', '\u{10AE6}', 0),
+    ('\u{10D24}', '\u{10D27}', 0),
+    ('\u{10EAB}', '\u{10EAC}', 0),
+    ('\u{10EFD}', '\u{10EFF}', 0),
+    ('\u{10F46}', '\u{10F50}', 0),
+    ('\u{10F82}', '\u{10F85}', 0),
+    ('\u{11001}', '\u{11001}', 0),
+    ('\u{11038}', '\u{11046}', 0),
+    ('\u{11070}', '\u{11070}', 0),
+    ('\u{11073}', '\u{11074}', 0),
+    ('\u{1107F}', '\u{11081}', 0),
+    ('\u{110B3}', '\u{110B6}', 0),
+    ('\u{110B9}', '\u{110BA}', 0),
+    ('\u{110C2}', '\u{110C2}', 0),
+    ('\u{11100}', '\u{11102}', 0),
+    ('\u{11127}', '\u{1112B}', 0),
+    ('\u{1112D}', '\u{11134}', 0),
+    ('\u{11173}', '\u{11173}', 0),
+    ('\u{11180}', '\u{11181}', 0),
+    ('\u{111B6}', '\u{111BE}', 0),
+    ('\u{111C9}', '\u{111CC}', 0),
+    ('\u{111CF}', '\u{111CF}', 0),
+    ('\u{1122F}', '\u{11231}', 0),
+    ('\u{11234}', '\u{11234}', 0),
+    ('\u{11236}', '\u{11237}', 0),
+    ('\u{1123E}', '\u{1123E}', 0),
+    ('\u{11241}', '\u{11241}', 0),
+    ('\u{112DF}', '\u{112DF}', 0),
+    ('\u{112E3}', '\u{112EA}', 0),
+    ('\u{11300}', '\u{11301}', 0),
+    ('\u{1133B}', '\u{1133C}', 0),
+    ('\u{1133E}', '\u{1133E}', 0),
+    ('\u{11340}', '\u{11340}', 0),
+    ('\u{11357}', '\u{11357}', 0),
+    ('\u{11366}', '\u{1136C}', 0),
+    ('\u{11370}', '\u{11374}', 0),
+    ('\u{11438}', '\u{1143F}', 0),
+    ('\u{11442}', '\u{11444}', 0),
+    ('\u{11446}', '\u{11446}', 0),
+    ('\u{1145E}', '\u{1145E}', 0),
+    ('\u{114B0}', '\u{114B0}', 0),
+    ('\u{114B3}', '\u{114B8}', 0),
+    ('\u{114BA}', '\u{114BA}', 0),
+    ('\u{114BD}', '\u{114BD}', 0),
+    ('\u{114BF}', '\u{114C0}', 0),
+    ('\u{114C2}', '\u{114C3}', 0),
+    ('\u{115AF}', '\u{115AF}', 0),
+    ('\u{115B2}', '\u{115B5}', 0),
+    ('\u{115BC}', '\u{115BD}', 0),
+    ('\u{115BF}', '\u{115C0}', 0),
+    ('\u{115DC}', '\u{115DD}', 0),
+    ('\u{11633}', '\u{1163A}', 0),
+    ('\u{1163D}', '\u{1163D}', 0),
+    ('\u{1163F}', '\u{11640}', 0),
+    ('\u{116AB}', '\u{116AB}', 0),
+    ('\u{116AD}', '\u{116AD}', 0),
+    ('\u{116B0}', '\u{116B5}', 0),
+    ('\u{116B7}', '\u{116B7}', 0),
+    ('\u{1171D}', '\u{1171F}', 0),
+    ('\u{11722}', '\u{11725}', 0),
+    ('\u{11727}', '\u{1172B}', 0),
+    ('\u{1182F}', '\u{11837}', 0),
+    ('\u{11839}', '\u{1183A}', 0),
+    ('\u{11930}', '\u{11930}', 0),
+    ('\u{1193B}', '\u{1193C}', 0),
+    ('\u{1193E}', '\u{1193E}', 0),
+    ('\u{11943}', '\u{11943}', 0),
+    ('\u{119D4}', '\u{119D7}', 0),
+    ('\u{119DA}', '\u{119DB}', 0),
+    ('\u{119E0}', '\u{119E0}', 0),
+    ('\u{11A01}', '\u{11A0A}', 0),
+    ('\u{11A33}', '\u{11A38}', 0),
+    ('\u{11A3B}', '\u{11A3E}', 0),
+    ('\u{11A47}', '\u{11A47}', 0),
+    ('\u{11A51}', '\u{11A56}', 0),
+    ('\u{11A59}', '\u{11A5B}', 0),
+    ('\u{11A8A}', '\u{11A96}', 0),
+    ('\u{11A98}', '\u{11A99}', 0),
+    ('\u{11C30}', '\u{11C36}', 0),
+    ('\u{11C38}', '\u{11C3D}', 0),
+    ('\u{11C3F}', '\u{11C3F}', 0),
+    ('\u{11C92}', '\u{11CA7}', 0),
+    ('\u{11CAA}', '\u{11CB0}', 0),
+    ('\u{11CB2}', '\u{11CB3}', 0),
+    ('\u{11CB5}', '\u{11CB6}', 0),
+    ('\u{11D31}', '\u{11D36}', 0),
+    ('\u{11D3A}', '\u{11D3A}', 0),
+    ('\u{11D3C}', '\u{11D3D}', 0),
+    ('\u{11D3F}', '\u{11D45}', 0),
+    ('\u{11D47}', '\u{11D47}', 0),
+    ('\u{11D90}', '\u{11D91}', 0),
+    ('\u{11D95}', '\u{11D95}', 0),
+    ('\u{11D97}', '\u{11D97}', 0),
+    ('\u{11EF3}', '\u{11EF4}', 0),
+    ('\u{11F00}', '\u{11F01}', 0),
+    ('\u{11F36}', '\u{11F3A}', 0),
+    ('\u{11F40}', '\u{11F40}', 0),
+    ('\u{11F42}', '\u{11F42}', 0),
+    ('\u{13440}', '\u{13440}', 0),
+    ('\u{13447}', '\u{13455}', 0),
+    ('\u{16AF0}', '\u{16AF4}', 0),
+    ('\u{16B30}', '\u{16B36}', 0),
+    ('\u{16F4F}', '\u{16F4F}', 0),
+    ('\u{16F8F}', '\u{16F92}', 0),
+    ('\u{16FE0}', '\u{16FE3}', 2),
+    ('\u{16FE4}', '\u{16FE4}', 0),
+    ('\u{16FF0}', '\u{16FF1}', 2),
+    ('\u{17000}', '\u{187F7}', 2),
+    ('\u{18800}', '\u{18CD5}', 2),
+    ('\u{18D00}', '\u{18D08}', 2),
+    ('\u{1AFF0}', '\u{1AFF3}', 2),
+    ('\u{1AFF5}', '\u{1AFFB}', 2),
+    ('\u{1AFFD}', '\u{1AFFE}', 2),
+    ('\u{1B000}', '\u{1B122}', 2),
+    ('\u{1B132}', '\u{1B132}', 2),
+    ('\u{1B150}', '\u{1B152}', 2),
+    ('\u{1B155}', '\u{1B155}', 2),
+    ('\u{1B164}', '\u{1B167}', 2),
+    ('\u{1B170}', '\u{1B2FB}', 2),
+    ('\u{1BC9D}', '\u{1BC9E}', 0),
+    ('\u{1BCA0}', '\u{1BCA3}', 0),
+    ('\u{1CF00}', '\u{1CF2D}', 0),
+    ('\u{1CF30}', '\u{1CF46}', 0),
+    ('\u{1D165}', '\u{1D165}', 0),
+    ('\u{1D167}', '\u{1D169}', 0),
+    ('\u{1D16E}', '\u{1D182}', 0),
+    ('\u{1D185}', '\u{1D18B}', 0),
+    ('\u{1D1AA}', '\u{1D1AD}', 0),
+    ('\u{1D242}', '\u{1D244}', 0),
+    ('\u{1DA00}', '\u{1DA36}', 0),
+    ('\u{1DA3B}', '\u{1DA6C}', 0),
+    ('\u{1DA75}', '\u{1DA75}', 0),
+    ('\u{1DA84}', '\u{1DA84}', 0),
+    ('\u{1DA9B}', '\u{1DA9F}', 0),
+    ('\u{1DAA1}', '\u{1DAAF}', 0),
+    ('\u{1E000}', '\u{1E006}', 0),
+    ('\u{1E008}', '\u{1E018}', 0),
+    ('\u{1E01B}', '\u{1E021}', 0),
+    ('\u{1E023}', '\u{1E024}', 0),
+    ('\u{1E026}', '\u{1E02A}', 0),
+    ('\u{1E08F}', '\u{1E08F}', 0),
+    ('\u{1E130}', '\u{1E136}', 0),
+    ('\u{1E2AE}', '\u{1E2AE}', 0),
+    ('\u{1E2EC}', '\u{1E2EF}', 0),
+    ('\u{1E4EC}', '\u{1E4EF}', 0),
+    ('\u{1E8D0}', '\u{1E8D6}', 0),
+    ('\u{1E944}', '\u{1E94A}', 0),
+    ('\u{1F004}', '\u{1F004}', 2),
+    ('\u{1F0CF}', '\u{1F0CF}', 2),
+    ('\u{1F18E}', '\u{1F18E}', 2),
+    ('\u{1F191}', '\u{1F19A}', 2),
+    ('\u{1F200}', '\u{1F202}', 2),
+    ('\u{1F210}', '\u{1F23B}', 2),
+    ('\u{1F240}', '\u{1F248}', 2),
+    ('\u{1F250}', '\u{1F251}', 2),
+    ('\u{1F260}', '\u{1F265}', 2),
+    ('\u{1F300}', '\u{1F320}', 2),
+    ('\u{1F32D}', '\u{1F335}', 2),
+    ('\u{1F337}', '\u{1F37C}', 2),
+    ('\u{1F37E}', '\u{1F393}', 2),
+    ('\u{1F3A0}', '\u{1F3CA}', 2),
+    ('\u{1F3CF}', '\u{1F3D3}', 2),
+    ('\u{1F3E0}', '\u{1F3F0}', 2),
+    ('\u{1F3F4}', '\u{1F3F4}', 2),
+    ('\u{1F3F8}', '\u{1F43E}', 2),
+    ('\u{1F440}', '\u{1F440}', 2),
+    ('\u{1F442}', '\u{1F4FC}', 2),
+    ('\u{1F4FF}', '\u{1F53D}', 2),
+    ('\u{1F54B}', '\u{1F54E}', 2),
+    ('\u{1F550}', '\u{1F567}', 2),
+    ('\u{1F57A}', '\u{1F57A}', 2),
+    ('\u{1F595}', '\u{1F596}', 2),
+    ('\u{1F5A4}', '\u{1F5A4}', 2),
+    ('\u{1F5FB}', '\u{1F64F}', 2),
+    ('\u{1F680}', '\u{1F6C5}', 2),
+    ('\u{1F6CC}', '\u{1F6CC}', 2),
+    ('\u{1F6D0}', '\u{1F6D2}', 2),
+    ('\u{1F6D5}', '\u{1F6D7}', 2),
+    ('\u{1F6DC}', '\u{1F6DF}', 2),
+    ('\u{1F6EB}', '\u{1F6EC}', 2),
+    ('\u{1F6F4}', '\u{1F6FC}', 2),
+    ('\u{1F7E0}', '\u{1F7EB}', 2),
+    ('\u{1F7F0}', '\u{1F7F0}', 2),
+    ('\u{1F90C}', '\u{1F93A}', 2),
+    ('\u{1F93C}', '\u{1F945}', 2),
+    ('\u{1F947}', '\u{1F9FF}', 2),
+    ('\u{1FA70}', '\u{1FA7C}', 2),
+    ('\u{1FA80}', '\u{1FA88}', 2),
+    ('\u{1FA90}', '\u{1FABD}', 2),
+    ('\u{1FABF}', '\u{1FAC5}', 2),
+    ('\u{1FACE}', '\u{1FADB}', 2),
+    ('\u{1FAE0}', '\u{1FAE8}', 2),
+    ('\u{1FAF0}', '\u{1FAF8}', 2),
+    ('\u{20000}', '\u{2FFFD}', 2),
+    ('\u{30000}', '\u{3FFFD}', 2),
+    ('\u{E0000}', '\u{E0FFF}', 0),
+];
+
+/// returns the width of a character, according to the pinned table.
+pub(super) fn char_width(c: char) -> usize {
+    match TABLE.binary_search_by(|&(lo, hi, _)| {
+        if hi < c {
+            std::cmp::Ordering::Less
+        } else if lo > c {
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Equal
+        }
+    }) {
+        Ok(i) => TABLE[i].2.into(),
+        Err(_) => 1,
+    }
+}
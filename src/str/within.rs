@@ -0,0 +1,100 @@
+use {
+    super::{
+        ellipsis::Ellipsis,
+        split::{cluster_width, Clusters},
+        width::str_width,
+        Limited,
+    },
+    std::ops::RangeInclusive,
+};
+
+/// limits a string to a size within `budget`, preferring to cut at a natural boundary.
+///
+/// `size` measures a cluster of characters, and `ellipsis_size` is the size of the ellipsis. if
+/// there is no boundary at which the limited string's size falls within the budget, `hard` is
+/// used to cut the string at the end of the budget.
+fn within<E: Ellipsis>(
+    value: &str,
+    budget: RangeInclusive<usize>,
+    ellipsis_size: usize,
+    size: impl Fn(&str) -> usize,
+    hard: impl FnOnce(usize) -> String,
+) -> String {
+    let (min, max) = budget.into_inner();
+    let Some(room) = max.checked_sub(ellipsis_size) else {
+        return hard(max);
+    };
+    let wanted = min.saturating_sub(ellipsis_size)..=room;
+
+    let (mut used, mut end) = (0, 0);
+    let mut word = (0, 0); // the end and size of the content before any trailing whitespace.
+    let mut cut = None;
+    let mut consider = |(end, used): (usize, usize)| {
+        if end > 0 && wanted.contains(&used) {
+            cut = Some(end);
+        }
+    };
+
+    for cluster in Clusters::new(value) {
+        let space = cluster.starts_with(char::is_whitespace);
+        if space {
+            consider(word); // a word ends before whitespace.
+        }
+
+        used += size(cluster);
+        end += cluster.len();
+        if used > room {
+            break;
+        } else if !space {
+            word = (end, used);
+        }
+        if cluster.starts_with(is_clause_end) {
+            consider(word); // a clause ends after punctuation.
+        }
+    }
+
+    match cut {
+        Some(cut) => {
+            let ellipsis = E::ellipsis();
+            let mut limited = String::with_capacity(cut + ellipsis.len());
+            limited.push_str(&value[..cut]);
+            limited.push_str(ellipsis);
+            limited
+        }
+        None => hard(max),
+    }
+}
+
+/// returns true if a character ends a sentence or clause.
+fn is_clause_end(c: char) -> bool {
+    matches!(
+        c,
+        '.' | ',' | ';' | ':' | '!' | '?' | ')' | ']' | '}' | '–' | '—'
+    )
+}
+
+/// limits a string to a length within `budget`, see [`within()`].
+pub(super) fn within_length<E: Ellipsis>(value: &str, budget: RangeInclusive<usize>) -> String {
+    if value.len() <= *budget.end() {
+        return value.to_owned();
+    }
+
+    within::<E>(value, budget, E::ellipsis().len(), str::len, |length| {
+        value.trim_to_length::<E>(length)
+    })
+}
+
+/// limits a string to a width within `budget`, see [`within()`].
+pub(super) fn within_width<E: Ellipsis>(value: &str, budget: RangeInclusive<usize>) -> String {
+    if str_width(value) <= *budget.end() {
+        return value.to_owned();
+    }
+
+    within::<E>(
+        value,
+        budget,
+        str_width(E::ellipsis()),
+        cluster_width,
+        |width| value.trim_to_width::<E>(width),
+    )
+}
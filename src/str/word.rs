@@ -0,0 +1,66 @@
+use {
+    super::{
+        ellipsis::Ellipsis,
+        split::{cluster_width, Clusters},
+        width::str_width,
+    },
+    tap::Pipe,
+};
+
+/// returns a string limited by some measure of size, cut at the last word boundary that fits.
+///
+/// `size` measures a cluster of characters, and `ellipsis_size` is the size of the ellipsis.
+/// returns `None` if the value does not fit, and there is no word boundary at which it can be cut.
+fn at_word<E: Ellipsis>(
+    value: &str,
+    budget: usize,
+    ellipsis_size: usize,
+    size: impl Fn(&str) -> usize,
+) -> Option<String> {
+    let ellipsis = E::ellipsis();
+    let room = budget.checked_sub(ellipsis_size)?;
+
+    // find the end of the last word that fits in the remaining room.
+    let (mut used, mut end, mut cut) = (0, 0, None);
+    for cluster in Clusters::new(value) {
+        if used > room {
+            break;
+        }
+        if cluster.starts_with(char::is_whitespace) {
+            let word = value[..end].trim_end();
+            if !word.is_empty() {
+                cut = Some(word.len());
+            }
+        }
+        used += size(cluster);
+        end += cluster.len();
+    }
+
+    let cut = cut?;
+    let mut limited = String::with_capacity(cut + ellipsis.len());
+    limited.push_str(&value[..cut]);
+    limited.push_str(ellipsis);
+    Some(limited)
+}
+
+/// returns a string limited by length, cut at the last word boundary that fits.
+///
+/// see [`at_word()`] for more information.
+pub(super) fn trim_to_length_at_word<E: Ellipsis>(value: &str, length: usize) -> Option<String> {
+    if value.len() <= length {
+        return value.to_owned().pipe(Some);
+    }
+
+    at_word::<E>(value, length, E::ellipsis().len(), str::len)
+}
+
+/// returns a string limited by width, cut at the last word boundary that fits.
+///
+/// see [`at_word()`] for more information.
+pub(crate) fn trim_to_width_at_word<E: Ellipsis>(value: &str, width: usize) -> Option<String> {
+    if str_width(value) <= width {
+        return value.to_owned().pipe(Some);
+    }
+
+    at_word::<E>(value, width, str_width(E::ellipsis()), cluster_width)
+}
@@ -0,0 +1,46 @@
+use std::borrow::Cow;
+
+/// the U+200C ZERO WIDTH NON-JOINER character.
+const ZWNJ: char = '\u{200C}';
+
+/// the U+200D ZERO WIDTH JOINER character.
+const ZWJ: char = '\u{200D}';
+
+/// returns true if `c` is an invisible zero-width character.
+///
+/// these are the zero width space, the zero width non-joiner and joiner, the word joiner, and the
+/// byte order mark, which is also known as the zero width no-break space.
+pub(crate) fn is_zero_width(c: char) -> bool {
+    matches!(c, '\u{200B}' | ZWNJ | ZWJ | '\u{2060}' | '\u{FEFF}')
+}
+
+/// removes invisible zero-width characters from `value`.
+///
+/// see [`Limited::strip_zero_width()`][super::Limited::strip_zero_width].
+pub(super) fn strip(value: &str) -> Cow<'_, str> {
+    if !value.contains(is_zero_width) {
+        return Cow::Borrowed(value);
+    }
+
+    let mut stripped = String::with_capacity(value.len());
+    let mut prev = None;
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        let next = chars.peek().copied();
+        if !is_zero_width(c) || joins(c, prev, next) {
+            stripped.push(c);
+        }
+        prev = Some(c);
+    }
+
+    Cow::Owned(stripped)
+}
+
+/// returns true if `c` is a joiner or non-joiner that affects the characters around it.
+///
+/// e.g. a zero width joiner combines two emoji into one, such as in `"👩‍💻"`.
+fn joins(c: char, prev: Option<char>, next: Option<char>) -> bool {
+    let visible = |c: Option<char>| c.is_some_and(|c| !c.is_whitespace() && !is_zero_width(c));
+
+    matches!(c, ZWNJ | ZWJ) && visible(prev) && visible(next)
+}
@@ -0,0 +1,274 @@
+use {
+    crate::str::{Align, Ellipsis, Limited},
+    std::marker::PhantomData,
+};
+
+/// a layout for the rows of a table.
+///
+/// each cell of a row is fit to the width of its column using
+/// [`Limited::fit_to_width()`], either trimmed with an [`Ellipsis`] or padded with spaces. cells
+/// are joined with a separator, which defaults to a single space.
+///
+/// # examples
+///
+/// ```
+/// use shear::{str::{ellipsis::Horizontal, Align}, table::Table};
+///
+/// let rows = [
+///     ["shear", "0.3.0", "a library for trimming excess contents from things"],
+///     ["tap", "1.0.1", "generic extensions for tapping values"],
+/// ];
+///
+/// let table = Table::<Horizontal>::new([6, 6, 24])
+///     .align(1, Align::Right)
+///     .separator(" │ ");
+///
+/// assert_eq!(
+///     table.rows(rows).collect::<Vec<_>>(),
+///     [
+///         "shear  │  0.3.0 │ a library for trimming …",
+///         "tap    │  1.0.1 │ generic extensions for …",
+///     ]
+/// );
+/// ```
+pub struct Table<'a, E> {
+    /// the width of each column.
+    widths: Vec<usize>,
+    /// the alignment of each column.
+    aligns: Vec<Align>,
+    /// the separator placed between cells.
+    separator: &'a str,
+    ellipsis: PhantomData<E>,
+}
+
+/// the sizes of a table's columns, measured from its contents.
+///
+/// this is a two-phase helper for sizing columns. first, [`Sizing::scan()`] measures the natural
+/// width of each column, i.e. the width of its widest cell. pass a sample of the rows (e.g. using
+/// [`Iterator::take()`]) to avoid scanning a large table in full. then, [`Sizing::distribute()`]
+/// shares a total width budget across the columns, clamping each to its minimum and maximum
+/// width. the resulting widths can be given to [`Table::new()`], or used with the trimming
+/// methods of [`Limited`].
+///
+/// # examples
+///
+/// ```
+/// use shear::table::Sizing;
+///
+/// let rows = [
+///     ["shear", "a library for trimming excess contents from things"],
+///     ["tap", "generic extensions for tapping values"],
+/// ];
+///
+/// let sizing = Sizing::scan(rows).min(1, 10);
+/// assert_eq!(sizing.natural(), [5, 50]);
+///
+/// // every column fits.
+/// assert_eq!(sizing.distribute(80), [5, 50]);
+/// // the narrow column keeps its natural width, the wide column is given the remainder.
+/// assert_eq!(sizing.distribute(30), [5, 25]);
+/// // columns are never narrower than their minimum width.
+/// assert_eq!(sizing.distribute(12), [1, 11]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Sizing {
+    /// the width of the widest cell in each column.
+    natural: Vec<usize>,
+    /// the minimum width of each column.
+    min: Vec<usize>,
+    /// the maximum width of each column.
+    max: Vec<usize>,
+}
+
+/// an iterator over the formatted rows of a [`Table`].
+///
+/// see [`Table::rows()`] for more information.
+pub struct Rows<'t, 'a, E, I> {
+    table: &'t Table<'a, E>,
+    rows: I,
+}
+
+// === impl table ===
+
+impl<'a, E: Ellipsis> Table<'a, E> {
+    /// returns a new [`Table`] layout, with the given column widths.
+    ///
+    /// each column is aligned to the left by default.
+    pub fn new(widths: impl IntoIterator<Item = usize>) -> Self {
+        let widths = widths.into_iter().collect::<Vec<_>>();
+        Self {
+            aligns: vec![Align::default(); widths.len()],
+            widths,
+            separator: " ",
+            ellipsis: PhantomData,
+        }
+    }
+
+    /// sets the alignment of a column.
+    ///
+    /// # panics
+    ///
+    /// this panics if `column` is out of bounds.
+    pub fn align(mut self, column: usize, align: Align) -> Self {
+        self.aligns[column] = align;
+        self
+    }
+
+    /// sets the separator placed between cells.
+    pub fn separator(self, separator: &'a str) -> Self {
+        Self { separator, ..self }
+    }
+
+    /// returns the width of each formatted row.
+    pub fn width(&self) -> usize {
+        use crate::measure::display_width;
+
+        let separators = self.widths.len().saturating_sub(1) * display_width(self.separator);
+        self.widths.iter().sum::<usize>() + separators
+    }
+
+    /// formats a row of cells.
+    ///
+    /// missing cells are treated as empty, and cells beyond the last column are ignored.
+    pub fn row<S: AsRef<str>>(&self, cells: impl IntoIterator<Item = S>) -> String {
+        let mut cells = cells.into_iter();
+        let mut row = String::new();
+
+        for (i, (&width, &align)) in self.widths.iter().zip(&self.aligns).enumerate() {
+            if i > 0 {
+                row.push_str(self.separator);
+            }
+            let cell = cells.next();
+            let cell = cell.as_ref().map(AsRef::as_ref).unwrap_or_default();
+            row.push_str(&cell.fit_to_width::<E>(width, align));
+        }
+
+        row
+    }
+
+    /// returns an iterator over formatted rows.
+    ///
+    /// see [`Table::row()`] for more information.
+    pub fn rows<I>(&self, rows: I) -> Rows<'_, 'a, E, I::IntoIter>
+    where
+        I: IntoIterator,
+        I::Item: IntoIterator,
+        <I::Item as IntoIterator>::Item: AsRef<str>,
+    {
+        Rows {
+            table: self,
+            rows: rows.into_iter(),
+        }
+    }
+}
+
+// === impl rows ===
+
+impl<E, I> Iterator for Rows<'_, '_, E, I>
+where
+    E: Ellipsis,
+    I: Iterator,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::Item: AsRef<str>,
+{
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rows.next().map(|cells| self.table.row(cells))
+    }
+}
+
+// === impl sizing ===
+
+impl Sizing {
+    /// returns the [`Sizing`] of the given rows, measuring the natural width of each column.
+    pub fn scan<I>(rows: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: IntoIterator,
+        <I::Item as IntoIterator>::Item: AsRef<str>,
+    {
+        use crate::measure::display_width;
+
+        let mut natural = Vec::<usize>::new();
+        for row in rows {
+            for (i, cell) in row.into_iter().enumerate() {
+                let width = display_width(cell.as_ref());
+                match natural.get_mut(i) {
+                    Some(natural) => *natural = width.max(*natural),
+                    None => natural.push(width),
+                }
+            }
+        }
+
+        Self {
+            min: vec![0; natural.len()],
+            max: vec![usize::MAX; natural.len()],
+            natural,
+        }
+    }
+
+    /// sets the minimum width of a column.
+    ///
+    /// # panics
+    ///
+    /// this panics if `column` is out of bounds.
+    pub fn min(mut self, column: usize, width: usize) -> Self {
+        self.min[column] = width;
+        self
+    }
+
+    /// sets the maximum width of a column.
+    ///
+    /// # panics
+    ///
+    /// this panics if `column` is out of bounds.
+    pub fn max(mut self, column: usize, width: usize) -> Self {
+        self.max[column] = width;
+        self
+    }
+
+    /// returns the natural width of each column, i.e. the width of its widest cell.
+    pub fn natural(&self) -> &[usize] {
+        &self.natural
+    }
+
+    /// shares `total` columns of width across the columns.
+    ///
+    /// each column is given its natural width, clamped to its minimum and maximum width. if these
+    /// do not fit within `total`, each column starts at its minimum width, and the remaining space
+    /// is shared evenly, so that narrow columns keep their natural width and wide columns are
+    /// trimmed. columns are never narrower than their minimum width, so the result may exceed
+    /// `total` if the minimums do not fit.
+    ///
+    /// `total` does not include any separators between the columns.
+    pub fn distribute(&self, total: usize) -> Vec<usize> {
+        let Self { natural, min, max } = self;
+
+        // the width each column would like to have.
+        let desired = natural
+            .iter()
+            .zip(min.iter().zip(max))
+            .map(|(&natural, (&min, &max))| natural.min(max).max(min))
+            .collect::<Vec<_>>();
+        if desired.iter().sum::<usize>() <= total {
+            return desired;
+        }
+
+        // start each column at its minimum, and share the remaining space evenly, satisfying
+        // the columns that want the least extra space first.
+        let mut widths = min.clone();
+        let mut remaining = total.saturating_sub(widths.iter().sum());
+        let mut columns = (0..widths.len()).collect::<Vec<_>>();
+        columns.sort_by_key(|&i| desired[i] - widths[i]);
+
+        // the last column is given whatever space remains, so none is lost to rounding.
+        for (shared, &i) in (1..=columns.len()).rev().zip(&columns) {
+            let extra = (desired[i] - widths[i]).min(remaining / shared);
+            widths[i] += extra;
+            remaining -= extra;
+        }
+
+        widths
+    }
+}
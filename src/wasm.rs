@@ -0,0 +1,45 @@
+use {
+    crate::str::{ellipsis, Limited},
+    wasm_bindgen::prelude::wasm_bindgen,
+};
+
+/// the ellipses available to javascript.
+///
+/// see [`crate::str::ellipsis`] for more information.
+#[wasm_bindgen(js_name = Ellipsis)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WasmEllipsis {
+    /// an [`Ascii`][ellipsis::Ascii] ellipsis, `"..."`.
+    Ascii = 0,
+    /// a [`Horizontal`][ellipsis::Horizontal] ellipsis, `"…"`.
+    Horizontal = 1,
+    /// a verbose [`Contd`][ellipsis::Contd] ellipsis, `"... (contd.)"`.
+    Contd = 2,
+}
+
+/// returns a string limited by length.
+///
+/// NB: the length is measured in utf-8 bytes, not utf-16 code units like javascript's
+/// `String.prototype.length`.
+///
+/// see [`Limited::trim_to_length()`] for more information.
+#[wasm_bindgen(js_name = trimToLength)]
+pub fn trim_to_length(value: &str, length: usize, ellipsis: WasmEllipsis) -> String {
+    match ellipsis {
+        WasmEllipsis::Ascii => value.trim_to_length::<ellipsis::Ascii>(length),
+        WasmEllipsis::Horizontal => value.trim_to_length::<ellipsis::Horizontal>(length),
+        WasmEllipsis::Contd => value.trim_to_length::<ellipsis::Contd>(length),
+    }
+}
+
+/// returns a string limited by width.
+///
+/// see [`Limited::trim_to_width()`] for more information.
+#[wasm_bindgen(js_name = trimToWidth)]
+pub fn trim_to_width(value: &str, width: usize, ellipsis: WasmEllipsis) -> String {
+    match ellipsis {
+        WasmEllipsis::Ascii => value.trim_to_width::<ellipsis::Ascii>(width),
+        WasmEllipsis::Horizontal => value.trim_to_width::<ellipsis::Horizontal>(width),
+        WasmEllipsis::Contd => value.trim_to_width::<ellipsis::Contd>(width),
+    }
+}
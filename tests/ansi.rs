@@ -0,0 +1,70 @@
+//! test cases for ansi escape sequence aware limiting in [`shear::str`].
+
+#![cfg(feature = "ansi")]
+
+use {
+    proptest::proptest,
+    shear::str::{ellipsis, Limited},
+    unicode_width::UnicodeWidthStr,
+};
+
+/// a red foreground color.
+const RED: &str = "\x1b[31m";
+
+/// a reset of all attributes.
+const RESET: &str = "\x1b[0m";
+
+proptest! {
+    #[test]
+    fn plain_strings_match_trim_to_width(value in "[a-z ]{0,64}", width in 0..64_usize) {
+        plain_strings_match_trim_to_width_(value, width)
+    }
+}
+
+fn plain_strings_match_trim_to_width_(value: String, width: usize) {
+    assert_eq!(
+        value.trim_to_width_ansi::<ellipsis::Ascii>(width),
+        value.trim_to_width::<ellipsis::Ascii>(width)
+    );
+}
+
+#[test]
+fn strings_that_fit_within_the_width_of_the_ellipsis_are_not_altered() {
+    for value in ["", "ab", "abc"] {
+        assert_eq!(value.trim_to_width_ansi::<ellipsis::Ascii>(3), value);
+        assert_eq!(value.trim_to_width::<ellipsis::Ascii>(3), value);
+    }
+    assert_eq!("abcd".trim_to_width_ansi::<ellipsis::Ascii>(3), "...");
+    assert_eq!("abcd".trim_to_width::<ellipsis::Ascii>(3), "...");
+}
+
+#[test]
+fn escape_sequences_do_not_count_towards_width() {
+    let value = format!("{RED}red{RESET}");
+    assert_eq!(value.trim_to_width_ansi::<ellipsis::Ascii>(3), value);
+}
+
+#[test]
+fn escape_sequences_are_kept_after_the_ellipsis() {
+    let value = format!("{RED}a very long value{RESET}");
+    let limited = value.trim_to_width_ansi::<ellipsis::Ascii>(8);
+    assert_eq!(limited, format!("{RED}a ver...{RESET}"));
+}
+
+#[test]
+fn escape_sequences_are_never_cut() {
+    let value = format!("ab{RED}cdefgh");
+    let limited = value.trim_to_width_ansi::<ellipsis::Horizontal>(3);
+    assert_eq!(limited, format!("ab{RED}…"));
+    assert_eq!(limited.replace(RED, "").width(), 3);
+}
+
+#[test]
+fn operating_system_commands_are_skipped() {
+    let link = "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07";
+    let value = format!("{link} and more text");
+    assert_eq!(
+        value.trim_to_width_ansi::<ellipsis::Ascii>(10),
+        format!("{link} an...")
+    );
+}
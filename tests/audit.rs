@@ -0,0 +1,39 @@
+//! test cases for the budget accounting audits enabled by the `audit` feature.
+
+#![cfg(feature = "audit")]
+
+use {
+    self::strategy::TestInput,
+    proptest::proptest,
+    shear::str::{ellipsis, Limited},
+};
+
+mod strategy;
+
+proptest! {
+    #[test]
+    fn trimmed_strings_pass_audits(input in strategy::input_strategy()) {
+        trimmed_strings_pass_audits_(input)
+    }
+
+    #[test]
+    fn truncated_strings_pass_audits(input in strategy::values_that_need_truncation()) {
+        trimmed_strings_pass_audits_(input)
+    }
+}
+
+fn trimmed_strings_pass_audits_(TestInput { value, length }: TestInput) {
+    // each of these methods panics if its output does not satisfy its budget.
+    value.trim_to_length::<ellipsis::Ascii>(length);
+    value.trim_to_width::<ellipsis::Horizontal>(length);
+    value.trim_to_height::<ellipsis::Ascii>(length);
+}
+
+#[test]
+fn wide_characters_pass_audits() {
+    let value = "Ｈｅｌｌｏ, ｗｏｒｌｄ!";
+    for width in 0..=value.len() {
+        value.trim_to_width::<ellipsis::Ascii>(width);
+        value.trim_to_width::<ellipsis::Horizontal>(width);
+    }
+}
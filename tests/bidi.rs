@@ -0,0 +1,78 @@
+//! test cases for bidirectional text facilities in [`shear::str::bidi`].
+
+#![cfg(feature = "str")]
+
+use shear::str::{
+    bidi::RLM,
+    ellipsis::{Ascii, Horizontal},
+    Limited,
+};
+
+#[test]
+fn ltr_text_is_trimmed_normally() {
+    let value = "a very long string value";
+    assert_eq!(
+        value.trim_to_width_bidi::<Ascii>(18),
+        value.trim_to_width::<Ascii>(18)
+    );
+    assert_eq!(
+        value.trim_to_length_bidi::<Ascii>(18),
+        value.trim_to_length::<Ascii>(18)
+    );
+}
+
+#[test]
+fn rtl_text_is_followed_by_a_rtl_mark() {
+    let limited = "שלום עולם".trim_to_width_bidi::<Horizontal>(5);
+    assert_eq!(limited, format!("שלום…{RLM}"));
+}
+
+#[test]
+fn rtl_text_that_fits_is_not_altered() {
+    let value = "שלום";
+    assert_eq!(value.trim_to_width_bidi::<Horizontal>(4), value);
+    assert_eq!(value.trim_to_length_bidi::<Horizontal>(8), value);
+}
+
+#[test]
+fn rtl_text_trimmed_by_length_fits_in_its_budget() {
+    let value = "שלום עולם";
+    let limited = value.trim_to_length_bidi::<Ascii>(12);
+    assert_eq!(limited, format!("שלו...{RLM}"));
+    assert!(limited.len() <= 12);
+}
+
+#[test]
+fn isolated_text_is_wrapped_in_an_isolate() {
+    use shear::str::bidi::{FSI, PDI};
+
+    let limited = "שלום עולם".trim_to_width_isolated::<Horizontal>(5);
+    assert_eq!(limited, format!("{FSI}שלום…{PDI}"));
+
+    let limited = "hello".trim_to_width_isolated::<Horizontal>(5);
+    assert_eq!(limited, format!("{FSI}hello{PDI}"));
+}
+
+#[test]
+fn unbalanced_directional_controls_are_removed() {
+    use shear::str::bidi::balance;
+
+    // an embedding whose closing control was trimmed away.
+    assert_eq!(balance("a\u{202B}bc…"), "abc…");
+    // a closing control with nothing to close.
+    assert_eq!(balance("a\u{202C}b\u{2069}c"), "abc");
+    // an isolate closes the embeddings within it.
+    assert_eq!(
+        balance("\u{2068}a\u{202A}b\u{2069}"),
+        "\u{2068}a\u{202A}b\u{2069}"
+    );
+    // an embedding cannot close an isolate.
+    assert_eq!(balance("\u{2066}a\u{202C}"), "a");
+}
+
+#[test]
+fn balanced_text_is_borrowed() {
+    use {shear::str::bidi::balance, std::borrow::Cow};
+
+    assert!(matches!(balance("a\u{202A}b\u{202C}c"), Cow::Borrowed(_)));
+}
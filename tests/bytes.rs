@@ -0,0 +1,138 @@
+//! test cases for byte slice limiting in [`shear::bytes`].
+
+#![cfg(feature = "str")]
+
+use {
+    proptest::{collection::vec, prelude::any, proptest},
+    shear::{
+        bytes::{Hex, Limited},
+        str::ellipsis::{Ascii, Guillemets, Horizontal},
+    },
+    std::borrow::Cow,
+};
+
+proptest! {
+    #[test]
+    fn limited_bytes_fit(value in vec(any::<u8>(), 0..64), length in 0..64_usize) {
+        let limited = value.trim_to_length::<Ascii>(length);
+        assert!(limited.len() <= length);
+        match value.len() <= length {
+            true => assert_eq!(limited, value),
+            false => {
+                assert!(limited.ends_with(&b"..."[..3.min(length)]));
+                assert!(value.starts_with(&limited[..length.saturating_sub(3)]));
+            }
+        }
+    }
+}
+
+#[test]
+fn bytes_that_fit_are_borrowed() {
+    let value = b"short".to_vec();
+    assert!(matches!(
+        value.trim_to_length_cow::<Ascii>(5),
+        Cow::Borrowed(_)
+    ));
+    assert!(matches!(
+        value.trim_to_length_cow::<Ascii>(4),
+        Cow::Owned(_)
+    ));
+}
+
+#[test]
+fn ellipses_are_appended_as_utf8() {
+    let value = &[0_u8; 8];
+    assert_eq!(
+        value.trim_to_length::<Horizontal>(5),
+        [0, 0, 0xe2, 0x80, 0xa6]
+    );
+    assert_eq!(
+        b"a long value".trim_to_length::<Guillemets>(9),
+        "«a …»".as_bytes()
+    );
+}
+
+#[test]
+fn markers_are_cut_to_the_length() {
+    assert_eq!(b"abcdef".trim_to_length::<Ascii>(2), b"..");
+    assert_eq!(b"abcdef".trim_to_length_with(b"[more]", 4), b"[mor");
+    assert_eq!(b"abcdef".trim_to_length_with(b"", 4), b"abcd");
+}
+
+#[test]
+fn hex_previews_count_omitted_bytes() {
+    let frame: &[u8] = &[0xde, 0xad, 0xbe, 0xef, 0x00];
+    assert_eq!(
+        frame.display_hex::<Horizontal>(4).to_string(),
+        "de ad be ef … (+1 byte)"
+    );
+    assert_eq!(
+        frame.display_hex::<Ascii>(2).to_string(),
+        "de ad ... (+3 bytes)"
+    );
+    assert_eq!(frame.display_hex::<Ascii>(0).to_string(), "... (+5 bytes)");
+}
+
+#[test]
+fn hex_previews_that_fit_have_no_ellipsis() {
+    let frame = b"ok";
+    assert_eq!(frame.display_hex::<Ascii>(8).to_string(), "6f 6b");
+    assert_eq!(
+        Hex::<Ascii>::new(frame, 8).ascii().to_string(),
+        "6f 6b |ok|"
+    );
+    assert_eq!(Hex::<Ascii>::new(b"", 8).ascii().to_string(), "");
+}
+
+#[test]
+fn hex_gutters_replace_unprintable_bytes() {
+    let preview = Hex::<Horizontal>::new(b"a\tb\x7f\xffc", 6).ascii();
+    assert_eq!(preview.to_string(), "61 09 62 7f ff 63 |a.b..c|");
+}
+
+#[cfg(feature = "bytes")]
+mod shared {
+    use {
+        super::*,
+        bytes::{Buf, Bytes, BytesMut},
+        shear::bytes::Shared,
+    };
+
+    /// collects a preview into contiguous bytes.
+    fn collect(mut preview: shear::bytes::Preview) -> Vec<u8> {
+        preview.copy_to_bytes(preview.remaining()).to_vec()
+    }
+
+    proptest! {
+        #[test]
+        fn shared_buffers_match_slices(value in vec(any::<u8>(), 0..64), length in 0..64_usize) {
+            let limited = Bytes::from(value.clone()).trim_to_length_shared::<Guillemets>(length);
+            assert_eq!(collect(limited), value.trim_to_length::<Guillemets>(length));
+        }
+    }
+
+    #[test]
+    fn retained_prefixes_share_storage() {
+        let payload = Bytes::from(b"abcdefghijkl".to_vec());
+        let preview = payload.clone().trim_to_length_shared::<Ascii>(8);
+        let prefix = preview.first_ref().last_ref();
+        assert_eq!(prefix.as_ref(), b"abcde");
+        assert_eq!(prefix.as_ptr(), payload.as_ptr());
+        assert_eq!(collect(preview), b"abcde...");
+    }
+
+    #[test]
+    fn mutable_buffers_can_be_limited() {
+        let payload = BytesMut::from(&b"abcdefghijkl"[..]);
+        let preview = payload.trim_to_length_shared::<Horizontal>(6);
+        assert_eq!(collect(preview), "abc…".as_bytes());
+    }
+
+    #[test]
+    fn buffers_that_fit_are_unaltered() {
+        let payload = Bytes::from_static(b"short");
+        assert_eq!(collect(payload.trim_to_length_shared::<Ascii>(5)), b"short");
+        let payload = Bytes::from_static(b"longer");
+        assert_eq!(collect(payload.trim_to_length_shared::<Ascii>(2)), b"..");
+    }
+}
@@ -0,0 +1,85 @@
+//! test cases for the C api bindings in [`shear::capi`].
+
+#![cfg(feature = "capi")]
+
+use shear::capi::{shear_trim_length, shear_trim_width, ShearEllipsis, ShearStatus};
+
+/// helper fn: calls [`shear_trim_length()`] with an output buffer of the given size.
+fn trim_length(input: &[u8], len: usize) -> (ShearStatus, Vec<u8>, usize) {
+    let mut output = vec![0; len];
+    let mut written = 0;
+    let status = unsafe {
+        shear_trim_length(
+            input.as_ptr(),
+            input.len(),
+            ShearEllipsis::Ascii,
+            output.as_mut_ptr(),
+            output.len(),
+            &mut written,
+        )
+    };
+    (status, output, written)
+}
+
+/// helper fn: calls [`shear_trim_width()`] with an output buffer of the given size.
+fn trim_width(input: &[u8], width: usize, len: usize) -> (ShearStatus, Vec<u8>, usize) {
+    let mut output = vec![0; len];
+    let mut written = 0;
+    let status = unsafe {
+        shear_trim_width(
+            input.as_ptr(),
+            input.len(),
+            width,
+            ShearEllipsis::Horizontal,
+            output.as_mut_ptr(),
+            output.len(),
+            &mut written,
+        )
+    };
+    (status, output, written)
+}
+
+#[test]
+fn strings_can_be_limited_by_length() {
+    let (status, output, written) = trim_length(b"a very long string value", 18);
+    assert_eq!(status, ShearStatus::Ok);
+    assert_eq!(&output[..written], b"a very long str...");
+}
+
+#[test]
+fn small_buffers_report_the_needed_size() {
+    let (status, _, written) = trim_length(b"a very long string value", 2);
+    assert_eq!(status, ShearStatus::BufferTooSmall);
+    assert_eq!(written, 3);
+}
+
+#[test]
+fn invalid_utf8_is_an_error() {
+    let (status, _, _) = trim_length(&[0xff, 0xfe], 8);
+    assert_eq!(status, ShearStatus::InvalidUtf8);
+}
+
+#[test]
+fn strings_can_be_limited_by_width() {
+    let input = "Ｈｅｌｌｏ, ｗｏｒｌｄ!".as_bytes();
+    let (status, output, written) = trim_width(input, 5, 64);
+    assert_eq!(status, ShearStatus::Ok);
+    assert_eq!(&output[..written], "Ｈｅ…".as_bytes());
+
+    let (status, _, needed) = trim_width(input, 5, 4);
+    assert_eq!(status, ShearStatus::BufferTooSmall);
+    assert_eq!(needed, written);
+}
+
+#[test]
+fn header_declares_the_c_api() {
+    let header = include_str!("../include/shear.h");
+    for declaration in [
+        "ShearStatus shear_trim_length(",
+        "ShearStatus shear_trim_width(",
+        "SHEAR_STATUS_BUFFER_TOO_SMALL = 3,",
+        "SHEAR_ELLIPSIS_CONTD = 2,",
+    ] {
+        assert!(header.contains(declaration), "missing `{declaration}`");
+    }
+}
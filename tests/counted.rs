@@ -0,0 +1,155 @@
+//! test cases for counting markers in [`shear::str::counted`].
+
+#![cfg(feature = "str")]
+
+use shear::str::{
+    counted::Counted,
+    ellipsis::{Ascii, Horizontal},
+};
+
+/// helper fn: returns a text of `n` lines.
+fn text(n: usize) -> String {
+    (1..=n)
+        .map(|i| format!("line {i}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn texts_that_fit_are_not_marked() {
+    assert_eq!(Counted::<Ascii>::new(3).trim(&text(3)), text(3));
+    assert_eq!(Counted::<Ascii>::new(3).trim(""), "");
+}
+
+#[test]
+fn omitted_lines_are_counted() {
+    assert_eq!(
+        Counted::<Ascii>::new(2).trim(&text(3)),
+        "line 1\n... 2 more lines"
+    );
+    assert_eq!(
+        Counted::<Ascii>::new(3).trim(&text(4)),
+        "line 1\nline 2\n... 2 more lines"
+    );
+    assert_eq!(Counted::<Ascii>::new(1).trim(&text(2)), "... 2 more lines");
+}
+
+#[test]
+fn windows_line_endings_are_preserved() {
+    let text = text(5).replace('\n', "\r\n");
+    assert_eq!(Counted::<Ascii>::new(5).trim(&text), text);
+    assert_eq!(
+        Counted::<Ascii>::new(4).tail(2).trim(&text),
+        "line 1\r\n... 2 lines omitted ...\r\nline 4\r\nline 5"
+    );
+}
+
+#[test]
+fn single_lines_are_counted() {
+    let text = "one\ntwo\nthree";
+    assert_eq!(Counted::<Ascii>::new(3).trim(text), text);
+    assert_eq!(Counted::<Horizontal>::new(2).trim("one\ntwo"), "one\ntwo");
+    assert_eq!(Counted::<Horizontal>::new(0).trim("one"), "… 1 more line");
+}
+
+#[test]
+fn last_lines_can_be_kept() {
+    let counted = Counted::<Ascii>::new(4).tail(2);
+    assert_eq!(counted.trim(&text(4)), text(4));
+    assert_eq!(
+        counted.trim(&text(5)),
+        "line 1\n... 2 lines omitted ...\nline 4\nline 5"
+    );
+    assert_eq!(
+        Counted::<Ascii>::new(3).tail(2).trim(&text(5)),
+        "... 3 lines omitted ...\nline 4\nline 5"
+    );
+    assert_eq!(
+        Counted::<Ascii>::new(3).tail(5).trim(&text(6)),
+        "... 4 lines omitted ...\nline 5\nline 6"
+    );
+    assert_eq!(
+        Counted::<Ascii>::new(1).tail(1).trim(&text(2)),
+        "... 2 more lines"
+    );
+}
+
+#[test]
+fn middle_markers_are_limited_to_the_width() {
+    let counted = Counted::<Horizontal>::new(3).tail(1).width(8);
+    assert_eq!(counted.trim(&text(4)), "line 1\n… 2 lin…\nline 4");
+}
+
+#[test]
+fn markers_can_be_indented() {
+    let code = "fn main() {\n    let a = 1;\n    let b = 2;\n\tlet c = 3;\n}";
+    assert_eq!(
+        Counted::<Ascii>::new(2).indent().trim(code),
+        "fn main() {\n    ... 4 more lines"
+    );
+    assert_eq!(
+        Counted::<Ascii>::new(3).tail(1).indent().trim(code),
+        "fn main() {\n    ... 3 lines omitted ...\n}"
+    );
+    assert_eq!(
+        Counted::<Ascii>::new(3)
+            .tail(1)
+            .indent()
+            .width(12)
+            .trim(code),
+        "fn main() {\n    ... 3...\n}"
+    );
+    assert_eq!(
+        Counted::<Ascii>::new(3).indent().trim(code),
+        "fn main() {\n    let a = 1;\n    ... 3 more lines"
+    );
+}
+
+#[test]
+fn counts_can_be_abbreviated() {
+    let counted = Counted::<Horizontal>::new(1).abbreviate();
+    assert_eq!(counted.trim(&text(999)), "… 999 more lines");
+    assert_eq!(counted.trim(&text(1000)), "… 1k more lines");
+    assert_eq!(counted.trim(&text(1999)), "… 1.9k more lines");
+    assert_eq!(counted.trim(&text(12_345)), "… 12k more lines");
+}
+
+#[test]
+fn counts_are_grouped() {
+    assert_eq!(
+        Counted::<Horizontal>::new(1).trim(&text(12_345)),
+        "… 12,345 more lines"
+    );
+}
+
+#[test]
+fn markers_are_limited_to_the_width() {
+    let counted = Counted::<Horizontal>::new(2).width(6);
+    assert_eq!(counted.trim(&text(1500)), "line 1\n… 1.4…");
+    assert_eq!(counted.trim("a long first line\nb\nc"), "a lon…\n… 2 m…");
+}
+
+#[test]
+fn counts_can_be_formatted_per_locale() {
+    use shear::str::counted::Separators;
+
+    let counted = Counted::<Horizontal>::new(1).numbers(Separators::new(".", ","));
+    assert_eq!(counted.trim(&text(12_346)), "… 12.346 more lines");
+
+    let counted = counted.abbreviate();
+    assert_eq!(counted.trim(&text(1_500)), "… 1,5k more lines");
+}
+
+#[test]
+fn counts_can_be_formatted_in_dynamic_ellipses() {
+    use shear::str::{
+        counted::{English, NumberFormat},
+        Limited,
+    };
+
+    let marker = |n| format!("… {} more lines", English.full(n));
+    assert_eq!(
+        text(1_206).trim_to_height_dynamic(3, &marker),
+        "line 1\nline 2\n… 1,204 more lines"
+    );
+}
@@ -0,0 +1,107 @@
+//! test cases for deserialization facilities in [`shear::de`].
+
+#![cfg(feature = "serde")]
+
+use {
+    serde::Deserialize,
+    shear::{de::Deserializer, str::ellipsis::Ascii},
+    std::collections::BTreeMap,
+};
+
+/// deserializes a value from json, limiting strings to `length` bytes.
+fn from_json<'de, T: Deserialize<'de>>(json: &'de str, length: usize) -> T {
+    let mut json = serde_json::Deserializer::from_str(json);
+    T::deserialize(Deserializer::<_, Ascii>::new(&mut json, length)).unwrap()
+}
+
+#[test]
+fn short_strings_are_untouched() {
+    let value: String = from_json(r#""hello""#, 8);
+    assert_eq!(value, "hello");
+}
+
+#[test]
+fn long_strings_are_trimmed() {
+    let value: String = from_json(r#""hello, world""#, 8);
+    assert_eq!(value, "hello...");
+}
+
+#[test]
+fn escaped_strings_are_trimmed() {
+    // escapes force the json deserializer to decode into a temporary buffer.
+    let value: String = from_json(r#""hello,\tworld""#, 8);
+    assert_eq!(value, "hello...");
+}
+
+#[test]
+fn borrowed_strings_are_borrowed_if_they_fit() {
+    #[derive(Deserialize)]
+    struct Borrowed<'a> {
+        #[serde(borrow)]
+        value: std::borrow::Cow<'a, str>,
+    }
+
+    let json = r#"{ "value": "hello" }"#;
+    let Borrowed { value } = from_json(json, 8);
+    assert!(matches!(value, std::borrow::Cow::Borrowed("hello")));
+}
+
+#[test]
+fn nested_strings_are_trimmed() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    enum Shape {
+        Label(String),
+        Named { name: String },
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Nested {
+        list: Vec<String>,
+        map: BTreeMap<String, Option<String>>,
+        shapes: Vec<Shape>,
+        number: u32,
+    }
+
+    let json = r#"{
+        "list": ["short", "a long string"],
+        "map": { "a long key": "a long value", "key": null },
+        "shapes": [{ "Label": "a long label" }, { "Named": { "name": "a long name" } }],
+        "number": 42
+    }"#;
+    let Nested {
+        list,
+        map,
+        shapes,
+        number,
+    } = from_json(json, 8);
+
+    assert_eq!(list, ["short", "a lon..."]);
+    assert_eq!(
+        map.into_iter().collect::<Vec<_>>(),
+        [
+            ("a lon...".to_owned(), Some("a lon...".to_owned())),
+            ("key".to_owned(), None)
+        ]
+    );
+    assert_eq!(
+        shapes,
+        [
+            Shape::Label("a lon...".to_owned()),
+            Shape::Named {
+                name: "a lon...".to_owned()
+            }
+        ]
+    );
+    assert_eq!(number, 42);
+}
+
+#[test]
+fn identifiers_are_not_trimmed() {
+    #[derive(Deserialize)]
+    struct Fields {
+        a_long_field_name: String,
+    }
+
+    let Fields { a_long_field_name } = from_json(r#"{ "a_long_field_name": "x" }"#, 4);
+    assert_eq!(a_long_field_name, "x");
+}
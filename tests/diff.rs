@@ -0,0 +1,73 @@
+//! test cases for unified diffs in [`shear::str::diff`].
+
+#![cfg(feature = "str")]
+
+use shear::str::{diff, ellipsis::Ascii};
+
+/// a patch with two hunks.
+const PATCH: &str = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 1c2d3e4..5f6a7b8 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,3 +1,3 @@
+ use std::fmt;
+-use std::io;
++use std::io::{self, Write};
+@@ -20,3 +20,4 @@ fn main() {
+     let a = 1;
++    let b = 2;
+     run(a);";
+
+#[test]
+fn diffs_that_fit_are_not_altered() {
+    assert_eq!(diff::trim_to_height::<Ascii>(PATCH, 6), PATCH);
+}
+
+#[test]
+fn hunk_headers_are_never_elided() {
+    let limited = diff::trim_to_height::<Ascii>(PATCH, 2);
+    let headers = limited.lines().filter(|line| diff::is_header(line));
+    assert_eq!(headers.count(), 6);
+    assert_eq!(
+        limited.lines().skip(4).collect::<Vec<_>>(),
+        [
+            "@@ -1,3 +1,3 @@",
+            " use std::fmt;",
+            "...",
+            "@@ -20,3 +20,4 @@ fn main() {"
+        ]
+    );
+}
+
+#[test]
+fn change_markers_are_kept() {
+    let limited = diff::trim_to_height::<Ascii>(PATCH, 4);
+    for line in limited.lines().filter(|line| !diff::is_header(line)) {
+        assert!(
+            line == "..." || line.starts_with([' ', '+', '-']),
+            "`{line}` should begin with a change marker"
+        );
+    }
+}
+
+#[test]
+fn headers_are_recognized() {
+    assert!(diff::is_header("@@ -1 +1 @@"));
+    assert!(diff::is_header("+++ b/file"));
+    assert!(!diff::is_header("+added line"));
+    assert!(!diff::is_header("--removed line"));
+}
+
+#[test]
+fn blank_lines_and_line_endings_are_preserved() {
+    let patch = "\n@@ -1,3 +1,3 @@\n one\n-two\n+three";
+    assert_eq!(
+        diff::trim_to_height::<Ascii>(patch, 2),
+        "\n@@ -1,3 +1,3 @@\n...",
+    );
+
+    let patch = PATCH.replace('\n', "\r\n");
+    assert_eq!(diff::trim_to_height::<Ascii>(&patch, 6), patch);
+    assert!(diff::trim_to_height::<Ascii>(&patch, 2).contains("\r\n...\r\n"));
+}
@@ -0,0 +1,198 @@
+//! test cases for formatting facilities in [`shear::fmt`].
+
+#![cfg(feature = "str")]
+
+use {
+    shear::{
+        fmt::{trim_display, write_display},
+        format_limited,
+        str::{
+            ellipsis::{Ascii, Contd},
+            Limited,
+        },
+    },
+    std::fmt,
+};
+
+/// a [`Display`][fmt::Display] value that counts how many times it was asked to write.
+struct Counter<'a>(&'a std::cell::Cell<usize>);
+
+impl fmt::Display for Counter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in 0..1024 {
+            self.0.set(self.0.get() + 1);
+            write!(f, "{i},")?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn short_values_are_displayed_in_full() {
+    assert_eq!(trim_display::<Ascii>(&12345, 8), "12345");
+    assert_eq!(trim_display::<Ascii>(&"abcdefgh", 8), "abcdefgh");
+}
+
+#[test]
+fn long_values_are_trimmed() {
+    assert_eq!(trim_display::<Ascii>(&"abcdefghi", 8), "abcde...");
+}
+
+#[test]
+fn trimmed_values_match_trim_to_length() {
+    for length in 0..32 {
+        let value = "ｈｅｌｌｏ, ｗｏｒｌｄ!";
+        assert_eq!(
+            trim_display::<Ascii>(&value, length),
+            value.trim_to_length::<Ascii>(length),
+            "length: {length}"
+        );
+    }
+}
+
+#[test]
+fn formatting_stops_once_the_budget_is_exhausted() {
+    let count = std::cell::Cell::new(0);
+    let limited = trim_display::<Ascii>(&Counter(&count), 10);
+
+    assert_eq!(limited, "0,1,2,3...");
+    assert!(count.get() < 8, "formatting should stop early");
+}
+
+#[test]
+fn values_can_be_written_to_an_existing_writer() {
+    let mut s = String::from("value: ");
+    write_display::<Ascii, _>(&mut s, &"abcdefghi", 8).unwrap();
+    assert_eq!(s, "value: abcde...");
+}
+
+#[test]
+fn format_limited_uses_an_ascii_ellipsis_by_default() {
+    let (a, b) = ("katelyn", "shear");
+    assert_eq!(
+        format_limited!(32, "user {} did {}", a, b),
+        "user katelyn did shear"
+    );
+    assert_eq!(format_limited!(16, "user {a} did {b}"), "user katelyn ...");
+}
+
+#[test]
+fn format_limited_accepts_an_ellipsis() {
+    let value = "a very long string value";
+    assert_eq!(
+        format_limited!(<Contd> 20, "{value}"),
+        "a very l... (contd.)"
+    );
+}
+
+#[test]
+fn trimmed_values_are_limited_when_displayed() {
+    let value = "a very long string value";
+    let trimmed = value.display_trimmed::<Ascii>(12);
+    assert_eq!(trimmed.to_string(), value.trim_to_length::<Ascii>(12));
+    assert_eq!(format!("<{trimmed}>"), "<a very lo...>");
+    assert_eq!(format!("{trimmed:?}"), "\"a very lo...\"");
+    assert_eq!(value.display_trimmed::<Ascii>(99).to_string(), value);
+}
+
+/// test that values can be rendered within a budget.
+mod fit_within {
+    use {
+        shear::fmt::{fit_within, FitWithin},
+        std::{borrow::Cow, fmt, rc::Rc},
+    };
+
+    /// a pair of values, sharing a budget evenly.
+    struct Pair<A, B>(A, B);
+
+    impl<A: FitWithin, B: FitWithin> FitWithin for Pair<A, B> {
+        fn fit(&self, budget: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let Self(a, b) = self;
+            a.fit(budget / 2, f)?;
+            b.fit(budget - budget / 2, f)
+        }
+    }
+
+    #[test]
+    fn strings_fit_within_a_budget() {
+        assert_eq!(fit_within("abcdef", 6).to_string(), "abcdef");
+        assert_eq!(fit_within("abcdef", 5).to_string(), "ab...");
+        assert_eq!(fit_within("ａｂｃｄ", 7).to_string(), "ａｂ...");
+        assert_eq!(fit_within(&String::from("abcdef"), 4).to_string(), "a...");
+        assert_eq!(fit_within(&Cow::Borrowed("abcdef"), 4).to_string(), "a...");
+    }
+
+    #[test]
+    fn wrappers_delegate_to_their_contents() {
+        assert_eq!(fit_within(&Some("abcdef"), 4).to_string(), "a...");
+        assert_eq!(fit_within(&None::<&str>, 4).to_string(), "");
+        assert_eq!(fit_within(&Box::new("abcdef"), 4).to_string(), "a...");
+        assert_eq!(
+            fit_within(&Rc::<str>::from("abcdef"), 4).to_string(),
+            "a..."
+        );
+    }
+
+    #[test]
+    fn composite_values_share_their_budget() {
+        let pair = Pair("abcdef", Pair("ghijkl", Some("mnopqr")));
+        assert_eq!(fit_within(&pair, 24).to_string(), "abcdefghijklmnopqr");
+        assert_eq!(fit_within(&pair, 16).to_string(), "abcdefg...m...");
+    }
+}
+
+/// test that styled segments can be rendered within a width.
+mod render_limited {
+    use shear::{render_limited, str::ellipsis::Horizontal};
+
+    const BOLD: (&str, &str) = ("[", "]");
+
+    #[test]
+    fn segments_that_fit_are_rendered_in_full() {
+        let mut cell = String::new();
+        let used = render_limited!(&mut cell, 16, ("key", BOLD), ("=", ()), ("value", BOLD));
+        assert_eq!(cell, "[key]=[value]");
+        assert_eq!(used, Ok(9));
+    }
+
+    #[test]
+    fn the_segment_that_overflows_is_trimmed() {
+        let mut cell = String::new();
+        let used = render_limited!(&mut cell, 8, ("key", BOLD), ("=", ()), ("value", BOLD));
+        assert_eq!(cell, "[key]=[v...]");
+        assert_eq!(used, Ok(8));
+    }
+
+    #[test]
+    fn segments_after_the_overflow_are_omitted() {
+        let mut cell = String::new();
+        let used =
+            render_limited!(<Horizontal> &mut cell, 4, ("key", BOLD), ("=", ()), ("value", BOLD));
+        assert_eq!(cell, "[key]…");
+        assert_eq!(used, Ok(4));
+    }
+
+    #[test]
+    fn wide_characters_are_measured_by_width() {
+        let mut cell = String::new();
+        let used = render_limited!(<Horizontal> &mut cell, 6, ("日本語", ()), ("text", BOLD));
+        assert_eq!(cell, "日本…");
+        assert_eq!(used, Ok(5));
+    }
+
+    #[test]
+    fn ellipses_wider_than_the_cell_are_omitted() {
+        let mut cell = String::new();
+        let used = render_limited!(&mut cell, 2, ("key", BOLD));
+        assert_eq!(cell, "[ke]");
+        assert_eq!(used, Ok(2));
+    }
+
+    #[test]
+    fn owned_strings_can_be_rendered() {
+        let (mut cell, value) = (String::new(), String::from("value"));
+        let used = render_limited!(&mut cell, 5, (value, ()));
+        assert_eq!(cell, "value");
+        assert_eq!(used, Ok(5));
+    }
+}
@@ -0,0 +1,90 @@
+//! test cases for grapheme cluster limiting in [`shear::str`].
+
+#![cfg(feature = "unicode-segmentation")]
+
+use {
+    proptest::proptest,
+    shear::str::{ellipsis, Limited},
+    unicode_segmentation::UnicodeSegmentation,
+};
+
+proptest! {
+    #[test]
+    fn limited_strings_fit(value: String, count in 1..64_usize) {
+        limited_strings_fit_(value, count)
+    }
+}
+
+fn limited_strings_fit_(value: String, count: usize) {
+    let limited = value.trim_to_graphemes::<ellipsis::Ascii>(count);
+    let graphemes = limited.graphemes(true).count();
+    if limited == value {
+        return;
+    }
+    assert!(
+        graphemes <= count.max(3),
+        "`{limited}` should have at most {count} graphemes"
+    );
+    let kept = limited
+        .strip_suffix("...")
+        .expect("should end with an ellipsis");
+    assert!(value.starts_with(kept), "`{kept}` should be a prefix");
+}
+
+#[test]
+fn emoji_sequences_are_not_split() {
+    let value = "👍🏽👍🏽👍🏽👍🏽";
+    assert_eq!(value.trim_to_graphemes::<ellipsis::Horizontal>(4), value);
+    assert_eq!(value.trim_to_graphemes::<ellipsis::Horizontal>(3), "👍🏽👍🏽…");
+}
+
+#[test]
+fn combining_marks_are_not_split() {
+    let value = "e\u{301}e\u{301}e\u{301}";
+    assert_eq!(
+        value.trim_to_graphemes::<ellipsis::Horizontal>(2),
+        "e\u{301}…"
+    );
+}
+
+proptest! {
+    #[test]
+    fn lengths_are_cut_at_grapheme_boundaries(value: String, length in 3..64_usize) {
+        lengths_are_cut_at_grapheme_boundaries_(value, length)
+    }
+}
+
+fn lengths_are_cut_at_grapheme_boundaries_(value: String, length: usize) {
+    let limited = value.trim_to_length_at_grapheme::<ellipsis::Ascii>(length);
+    assert!(
+        limited.len() <= length,
+        "`{limited}` should fit in {length} bytes"
+    );
+    if limited == value {
+        return;
+    }
+    let kept = limited
+        .strip_suffix("...")
+        .expect("should end with an ellipsis");
+    let boundaries = value
+        .grapheme_indices(true)
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    assert!(
+        kept.is_empty() || boundaries.contains(&kept.len()),
+        "`{kept}` should end at a grapheme boundary"
+    );
+}
+
+#[test]
+fn emoji_sequences_are_not_split_by_length() {
+    let value = "👍🏽 thumbs up";
+    assert_eq!(
+        value.trim_to_length_at_grapheme::<ellipsis::Horizontal>(8),
+        "…"
+    );
+    assert_eq!(
+        value.trim_to_length_at_grapheme::<ellipsis::Horizontal>(11),
+        "👍🏽…"
+    );
+}
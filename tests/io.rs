@@ -0,0 +1,146 @@
+//! test cases for i/o facilities in [`shear::io`].
+
+#![cfg(feature = "str")]
+
+use {
+    shear::{io::Tee, str::ellipsis::Ascii},
+    std::io::Write,
+};
+
+#[test]
+fn short_streams_are_previewed_in_full() {
+    let mut tee = Tee::<_, Ascii>::new(Vec::new(), 16);
+    tee.write_all(b"hello").unwrap();
+    tee.write_all(b", world").unwrap();
+
+    assert!(!tee.is_trimmed(), "stream should fit in the preview");
+    let (full, preview) = tee.into_parts();
+    assert_eq!(full, b"hello, world");
+    assert_eq!(preview, "hello, world");
+}
+
+#[test]
+fn long_streams_are_trimmed_in_the_preview() {
+    let mut tee = Tee::<_, Ascii>::new(Vec::new(), 10);
+    for _ in 0..64 {
+        tee.write_all(b"abc").unwrap();
+    }
+
+    assert!(tee.is_trimmed(), "stream should not fit in the preview");
+    let (full, preview) = tee.into_parts();
+    assert_eq!(full.len(), 192, "primary writer should see everything");
+    assert_eq!(preview, "abcabca...");
+}
+
+#[test]
+fn streams_that_exactly_fit_are_not_trimmed() {
+    let mut tee = Tee::<_, Ascii>::new(Vec::new(), 6);
+    tee.write_all(b"abcdef").unwrap();
+
+    assert_eq!(tee.preview(), "abcdef");
+}
+
+#[test]
+fn multibyte_characters_are_not_split() {
+    let mut tee = Tee::<_, Ascii>::new(Vec::new(), 8);
+    tee.write_all("ｈｅｌｌｏ".as_bytes()).unwrap();
+
+    assert_eq!(tee.preview(), "ｈ...");
+}
+
+mod trim_lines {
+    use {
+        shear::{io::TrimLines, str::ellipsis::Ascii},
+        std::io::{BufRead, Cursor},
+    };
+
+    /// helper fn: collects the limited lines of `input`.
+    fn lines(input: &str, height: usize, width: Option<usize>) -> Vec<String> {
+        let lines = TrimLines::<_, Ascii>::new(input.as_bytes(), height);
+        let lines = match width {
+            Some(width) => lines.width(width),
+            None => lines,
+        };
+        lines
+            .collect::<Result<_, _>>()
+            .expect("input should be valid utf-8")
+    }
+
+    #[test]
+    fn lines_match_trim_to_height() {
+        use shear::str::Limited;
+
+        let input = "one\ntwo\r\nthree\nfour\n";
+        for height in 0..6 {
+            assert_eq!(
+                lines(input, height, None).join("\n"),
+                input.trim_to_height::<Ascii>(height),
+                "lines should match `trim_to_height({height})`"
+            );
+        }
+    }
+
+    #[test]
+    fn lines_are_limited_by_width() {
+        let input = "short\na much longer line";
+        assert_eq!(lines(input, 2, Some(8)), ["short", "a muc..."]);
+    }
+
+    #[test]
+    fn input_after_the_budget_is_not_read() {
+        let mut reader = Cursor::new("one\ntwo\nthree\nfour\n");
+        let mut lines = TrimLines::<_, Ascii>::new(&mut reader, 2);
+        assert_eq!(lines.next_line().unwrap().unwrap(), "one");
+        assert_eq!(lines.next_line().unwrap().unwrap(), "...");
+        assert!(lines.next_line().is_none());
+        drop(lines);
+
+        let mut rest = String::new();
+        reader.read_line(&mut rest).unwrap();
+        assert_eq!(rest, "three\n");
+    }
+
+    #[test]
+    fn joined_lines_match_trim_to_height() {
+        use shear::{io::trim_lines, str::Limited};
+
+        for input in ["one\ntwo\r\nthree\nfour\n", "one\r\ntwo\r\nthree"] {
+            for height in 0..6 {
+                assert_eq!(
+                    trim_lines::<Ascii>(input.as_bytes(), height).unwrap(),
+                    input.trim_to_height::<Ascii>(height),
+                    "`{input:?}` should match `trim_to_height({height})`"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn joined_lines_stop_reading_after_the_budget() {
+        use shear::io::trim_lines;
+
+        let mut reader = Cursor::new("one\ntwo\nthree\nfour\n");
+        assert_eq!(trim_lines::<Ascii>(&mut reader, 2).unwrap(), "one\n...");
+        assert_eq!(trim_lines::<Ascii>(&b""[..], 0).unwrap(), "");
+
+        let mut rest = String::new();
+        reader.read_line(&mut rest).unwrap();
+        assert_eq!(rest, "three\n");
+    }
+
+    #[test]
+    fn joined_lines_only_end_at_line_feeds() {
+        use shear::{io::trim_lines, str::Limited};
+
+        let input = "a\u{2028}b\u{2028}c";
+        assert_eq!(trim_lines::<Ascii>(input.as_bytes(), 2).unwrap(), input);
+        assert_eq!(input.trim_to_height::<Ascii>(2), "a\n...");
+    }
+
+    #[test]
+    fn invalid_utf8_is_an_error() {
+        let mut lines = TrimLines::<_, Ascii>::new(&b"\xff\xfe\nok\n"[..], 4);
+        assert!(lines.next_line().unwrap().is_err());
+        assert_eq!(lines.next_line().unwrap().unwrap(), "ok");
+    }
+}
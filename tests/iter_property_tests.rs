@@ -149,3 +149,40 @@ fn a_size_equal_to_or_smaller_than_contd_procedes_directly_to_limiting_(
         )
     });
 }
+
+// === test split() ===
+
+proptest! {
+    /// a property test showing that the head and overflow of a split partition the input.
+    #[test]
+    fn split_head_and_overflow_partition_the_input(input in input_strategy()) {
+        split_head_and_overflow_partition_the_input_(input)
+    }
+}
+
+fn split_head_and_overflow_partition_the_input_(TestInput { value, length }: TestInput) {
+    let limited = value
+        .chars()
+        .conv::<TestIter>()
+        .limited(length)
+        .collect::<String>();
+    let (head, overflow) = value.chars().conv::<TestIter>().limited(length).split();
+    let (head, overflow) = (head.collect::<String>(), overflow.collect::<String>());
+
+    assert_eq!(
+        format!("{head}{overflow}"),
+        value,
+        "split should cover the input"
+    );
+    assert!(
+        limited.starts_with(&head),
+        "head should be a prefix of the limited output"
+    );
+    match overflow.is_empty() {
+        true => assert_eq!(
+            head, limited,
+            "head should be the output if nothing overflows"
+        ),
+        false => assert_eq!(format!("{head}..."), limited, "overflow should be elided"),
+    }
+}
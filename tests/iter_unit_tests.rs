@@ -36,3 +36,238 @@ fn input_that_exactly_fits_will_not_have_truncated_output() {
         .collect::<String>()
         .pipe(|s| assert_eq!(s, "123456", "if the string fits it should not be limited"));
 }
+
+#[test]
+fn peeking_does_not_advance_the_iterator() {
+    let mut iter = "123456".chars().conv::<TestIter>().limited(5);
+
+    assert_eq!(iter.peek(), Some(&'1'));
+    assert_eq!(iter.peek(), Some(&'1'));
+    assert_eq!(iter.next(), Some('1'));
+    assert_eq!(iter.next(), Some('2'));
+
+    assert!(!iter.is_truncated(), "iterator should not be truncated yet");
+    assert_eq!(iter.peek(), Some(&'.'));
+    assert!(iter.is_truncated(), "peeking should reveal the truncation");
+    assert_eq!(iter.collect::<String>(), "...");
+}
+
+#[test]
+fn peeking_at_input_that_fits_is_not_truncated() {
+    let mut iter = "12345".chars().conv::<TestIter>().limited(5);
+
+    while iter.peek().is_some() {
+        iter.next().pipe(drop);
+        assert!(!iter.is_truncated(), "iterator should not be truncated");
+    }
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn split_input_that_fits_has_no_overflow() {
+    let (head, overflow) = "12345".chars().conv::<TestIter>().limited(5).split();
+    assert_eq!(head.collect::<String>(), "12345");
+    assert_eq!(overflow.collect::<String>(), "");
+}
+
+#[test]
+fn split_overflow_can_be_advanced_before_the_head() {
+    let (mut head, overflow) = "123456".chars().conv::<TestIter>().limited(5).split();
+    assert_eq!(overflow.collect::<String>(), "3456");
+    assert!(head.is_truncated());
+    assert_eq!(head.by_ref().collect::<String>(), "12");
+}
+
+#[test]
+fn weighted_items_are_limited_by_their_weight() {
+    use shear::iter::Weighted;
+
+    /// a continuation sequence for weighted strings.
+    #[derive(Default)]
+    struct Contd;
+    impl IntoIterator for Contd {
+        type Item = (&'static str, usize);
+        type IntoIter = std::iter::Once<Self::Item>;
+        fn into_iter(self) -> Self::IntoIter {
+            std::iter::once(("…", 1))
+        }
+    }
+
+    let items = [("alpha", 5), ("beta", 4), ("gamma", 5), ("delta", 5)];
+    Weighted::<_, Contd>::new(items.into_iter())
+        .limited(12)
+        .map(|(item, _)| item)
+        .collect::<Vec<_>>()
+        .pipe(|items| assert_eq!(items, ["alpha", "beta", "…"]));
+    Weighted::<_, Contd>::new(items.into_iter())
+        .limited(19)
+        .map(|(item, _)| item)
+        .collect::<Vec<_>>()
+        .pipe(|items| assert_eq!(items, ["alpha", "beta", "gamma", "delta"]));
+}
+
+#[test]
+fn retention_keeps_the_most_recent_items() {
+    use shear::iter::Retention;
+
+    let mut retained = Retention::<TestIter>::new(3);
+    retained.extend("abcde".chars());
+    assert_eq!(retained.iter().collect::<String>(), "cde");
+    assert_eq!((retained.size(), retained.elided()), (3, 2));
+
+    retained.push_back('f');
+    assert_eq!(retained.into_inner(), ['d', 'e', 'f']);
+}
+
+#[test]
+fn retention_drops_items_larger_than_its_capacity() {
+    use shear::iter::{Retention, Weighted};
+
+    let mut retained = Retention::<Weighted<std::iter::Empty<_>, std::iter::Empty<_>>>::new(4);
+    retained.extend([("a", 1), ("b", 2), ("huge", 9)]);
+    assert_eq!(retained.iter().count(), 0);
+    assert_eq!((retained.size(), retained.elided()), (0, 3));
+
+    retained.push_back(("c", 4));
+    assert_eq!(retained.into_inner(), [("c", 4)]);
+}
+
+#[cfg(feature = "str")]
+#[test]
+fn multi_line_items_count_for_each_line() {
+    use shear::{iter::Height, str::ellipsis::Ascii};
+
+    let rows = ["one", "two\nthree", "", "four"];
+    Height::<_, Ascii>::new(rows.into_iter())
+        .limited(5)
+        .collect::<Vec<_>>()
+        .pipe(|rows| assert_eq!(rows, ["one", "two\nthree", "", "four"]));
+    Height::<_, Ascii>::new(rows.into_iter())
+        .limited(3)
+        .collect::<Vec<_>>()
+        .pipe(|rows| assert_eq!(rows, ["one", "..."]));
+}
+
+#[cfg(feature = "str")]
+#[test]
+fn omitted_lines_can_be_counted() {
+    use shear::iter::Height;
+
+    let rows = ["one", "two\nthree", "", "four"];
+    let marker = |n| format!("… (+{n} lines)");
+    Height::new(rows.into_iter())
+        .limited_dynamic(5, &marker)
+        .collect::<Vec<_>>()
+        .pipe(|rows| assert_eq!(rows, ["one", "two\nthree", "", "four"]));
+    Height::new(rows.into_iter())
+        .limited_dynamic(4, &marker)
+        .collect::<Vec<_>>()
+        .pipe(|rows| assert_eq!(rows, ["one", "two\nthree", "… (+2 lines)"]));
+    Height::new(rows.into_iter())
+        .limited_dynamic(2, &marker)
+        .collect::<Vec<_>>()
+        .pipe(|rows| assert_eq!(rows, ["one", "… (+4 lines)"]));
+}
+
+/// test that limiting consumes each item at most once, without cloning.
+mod single_pass {
+    use {
+        shear::iter::Limited,
+        std::{cell::Cell, sync::mpsc},
+    };
+
+    thread_local! {
+        /// the number of items pulled from the source.
+        static PULLED: Cell<usize> = const { Cell::new(0) };
+        /// the number of items from the source that were dropped.
+        static DROPPED: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// an item that counts how many times it is dropped, and cannot be cloned.
+    struct Item {
+        value: char,
+        /// false for items of the continuation sequence, which are not counted.
+        counted: bool,
+    }
+
+    impl Drop for Item {
+        fn drop(&mut self) {
+            if self.counted {
+                DROPPED.with(|d| d.set(d.get() + 1));
+            }
+        }
+    }
+
+    /// a source of items that can only be read once, like a channel receiver.
+    struct Source(mpsc::IntoIter<char>);
+
+    impl Iterator for Source {
+        type Item = Item;
+
+        fn next(&mut self) -> Option<Item> {
+            let value = self.0.next()?;
+            PULLED.with(|p| p.set(p.get() + 1));
+            Some(Item {
+                value,
+                counted: true,
+            })
+        }
+    }
+
+    impl Limited for Source {
+        type Contd = Vec<Item>;
+
+        fn contd() -> Self::Contd {
+            "..."
+                .chars()
+                .map(|value| Item {
+                    value,
+                    counted: false,
+                })
+                .collect()
+        }
+    }
+
+    /// helper fn: returns a source of the given characters.
+    fn source(s: &str) -> Source {
+        let (tx, rx) = mpsc::channel();
+        s.chars().for_each(|c| tx.send(c).unwrap());
+        Source(rx.into_iter())
+    }
+
+    /// helper fn: limits a source, returning the output and the number of items pulled/dropped.
+    fn limit(s: &str, size: usize) -> (String, usize, usize) {
+        PULLED.with(|p| p.set(0));
+        DROPPED.with(|d| d.set(0));
+
+        let output = source(s)
+            .limited(size)
+            .map(|item| item.value)
+            .collect::<String>();
+
+        (output, PULLED.with(Cell::get), DROPPED.with(Cell::get))
+    }
+
+    #[test]
+    fn each_item_is_dropped_exactly_once() {
+        for (input, size) in [("", 5), ("12345", 5), ("123456", 5), ("1234567890", 5)] {
+            let (_, pulled, dropped) = limit(input, size);
+            assert_eq!(pulled, dropped, "input: {input:?}, size: {size}");
+        }
+    }
+
+    #[test]
+    fn truncation_stops_pulling_from_the_source() {
+        let long = "x".repeat(1024);
+        let (output, pulled, _) = limit(&long, 8);
+        assert_eq!(output, "xxxxx...");
+        assert!(pulled <= 9, "pulled {pulled} items");
+    }
+
+    #[test]
+    fn sources_that_fit_are_consumed_once() {
+        let (output, pulled, dropped) = limit("12345", 5);
+        assert_eq!(output, "12345");
+        assert_eq!((pulled, dropped), (5, 5));
+    }
+}
@@ -0,0 +1,47 @@
+//! test cases for joining limited segments in [`shear::str::join`].
+
+#![cfg(feature = "str")]
+
+use shear::str::{
+    ellipsis::{Ascii, Horizontal},
+    join::join,
+    Limited,
+};
+
+#[test]
+fn segments_without_ellipses_are_joined() {
+    assert_eq!(
+        join::<Ascii, _>(["one", "two", "three"], ", "),
+        "one, two, three"
+    );
+    assert_eq!(join::<Ascii, _>(Vec::<String>::new(), ", "), "");
+}
+
+#[test]
+fn adjacent_ellipses_are_merged() {
+    assert_eq!(join::<Ascii, _>(["ab...", "...cd"], ""), "ab...cd");
+    assert_eq!(join::<Ascii, _>(["ab...", "...", "...cd"], ""), "ab...cd");
+}
+
+#[test]
+fn separated_ellipses_are_kept() {
+    assert_eq!(join::<Ascii, _>(["ab...", "...cd"], " "), "ab... ...cd");
+}
+
+#[test]
+fn lone_ellipses_are_suppressed() {
+    assert_eq!(
+        join::<Ascii, _>(["ab...", "...", "...", "ef"], " | "),
+        "ab... | ef"
+    );
+    assert_eq!(join::<Ascii, _>(["...", "..."], " | "), "...");
+}
+
+#[test]
+fn trimmed_columns_can_be_joined() {
+    let columns = [
+        "abcdef".trim_to_width::<Horizontal>(3),
+        "ghijkl".trim_to_width_keep_end::<Horizontal>(3),
+    ];
+    assert_eq!(join::<Horizontal, _>(&columns, ""), "ab…kl");
+}
@@ -0,0 +1,61 @@
+//! test cases for string measurement in [`shear::measure`].
+
+#![cfg(feature = "str")]
+
+use {
+    proptest::proptest,
+    shear::{
+        measure,
+        str::{ellipsis, Limited},
+    },
+};
+
+proptest! {
+    #[test]
+    fn strings_that_measure_within_the_budget_are_not_trimmed(value: String, budget in 0..64_usize) {
+        strings_that_measure_within_the_budget_are_not_trimmed_(value, budget)
+    }
+}
+
+fn strings_that_measure_within_the_budget_are_not_trimmed_(value: String, budget: usize) {
+    if measure::display_width(&value) <= budget {
+        assert_eq!(value.trim_to_width::<ellipsis::Ascii>(budget), value);
+    }
+    if measure::height(&value) <= budget {
+        let lines = value.trim_to_height::<ellipsis::Ascii>(budget);
+        assert_eq!(measure::height(&lines), measure::height(&value));
+    }
+}
+
+#[test]
+fn utf16_length_counts_surrogate_pairs() {
+    assert_eq!(measure::utf16_len(""), 0);
+    assert_eq!(measure::utf16_len("ａ🦀"), 3);
+}
+
+#[cfg(feature = "unicode-segmentation")]
+#[test]
+fn grapheme_count_matches_trim_to_graphemes() {
+    let value = "👩‍👩‍👧‍👦 family";
+    let count = measure::grapheme_count(value);
+    assert_eq!(count, 8);
+    assert_eq!(value.trim_to_graphemes::<ellipsis::Ascii>(count), value);
+}
+
+#[cfg(feature = "pinned-width")]
+#[test]
+fn pinned_widths_are_stable() {
+    assert_eq!(measure::UNICODE_VERSION, (15, 1, 0));
+    assert_eq!(shear::str::OUTPUT_VERSION, 3);
+    for (value, width) in [
+        ("abc", 3),
+        ("ａｂｃ", 6),
+        ("e\u{301}", 1),
+        ("\r\n", 0),
+        ("🦀", 2),
+        ("\u{1FAE8}", 2), // shaking face, added in unicode 15.0.
+        ("\u{FE0F}", 0),
+    ] {
+        assert_eq!(measure::display_width(value), width, "width of {value:?}");
+    }
+}
@@ -0,0 +1,60 @@
+//! test cases for unicode normalization in [`shear::str::normalize`].
+
+#![cfg(feature = "unicode-normalization")]
+
+use {
+    proptest::proptest,
+    shear::str::{ellipsis, normalize::Form, Limited},
+    std::borrow::Cow,
+};
+
+proptest! {
+    #[test]
+    fn normalization_is_idempotent(value: String) {
+        normalization_is_idempotent_(value)
+    }
+}
+
+fn normalization_is_idempotent_(value: String) {
+    for form in [Form::Nfc, Form::Nfkc] {
+        let normalized = value.normalized(form);
+        assert!(
+            matches!(normalized.normalized(form), Cow::Borrowed(_)),
+            "normalized strings should be borrowed"
+        );
+    }
+}
+
+#[test]
+fn equivalent_strings_are_trimmed_identically() {
+    let (composed, decomposed) = ("r\u{E9}sum\u{E9} of work", "re\u{301}sume\u{301} of work");
+    for width in 0..16 {
+        assert_eq!(
+            composed
+                .normalized(Form::Nfc)
+                .trim_to_width::<ellipsis::Horizontal>(width),
+            decomposed
+                .normalized(Form::Nfc)
+                .trim_to_width::<ellipsis::Horizontal>(width),
+        );
+    }
+}
+
+#[test]
+fn compatibility_characters_are_replaced() {
+    let value = "\u{FF21}\u{FB01}";
+    assert_eq!(value.normalized(Form::Nfc), value);
+    assert_eq!(value.normalized(Form::Nfkc), "Afi");
+}
+
+#[test]
+fn normalized_strings_are_borrowed() {
+    assert!(matches!(
+        "plain".normalized(Form::Nfc),
+        Cow::Borrowed("plain")
+    ));
+    assert!(matches!(
+        "e\u{301}".normalized(Form::Nfc),
+        Cow::Owned(s) if s == "\u{E9}"
+    ));
+}
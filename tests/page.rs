@@ -0,0 +1,76 @@
+//! test cases for pagination facilities in [`shear::page`].
+
+#![cfg(feature = "str")]
+
+use shear::{page::Pages, str::ellipsis::Ascii};
+
+#[test]
+fn empty_strings_have_no_pages() {
+    let mut pages = Pages::<Ascii>::new("", 4).footer();
+    assert_eq!(pages.count_pages(), 0);
+    assert_eq!(pages.next(), None);
+}
+
+#[test]
+fn zero_height_has_no_pages() {
+    for footer in [false, true] {
+        let pages = Pages::<Ascii>::new("one\ntwo", 0);
+        let mut pages = if footer { pages.footer() } else { pages };
+        assert_eq!(pages.count_pages(), 0);
+        assert_eq!(pages.next(), None);
+    }
+}
+
+#[test]
+fn lines_can_be_limited_by_width() {
+    let text = "a short line\na much longer line\nok";
+    let pages = Pages::<Ascii>::new(text, 2).width(10).collect::<Vec<_>>();
+    assert_eq!(pages, ["a short...\na much ...", "ok"]);
+}
+
+#[test]
+fn footers_are_limited_by_width() {
+    let pages = Pages::<Ascii>::new("one\ntwo", 2)
+        .width(8)
+        .footer()
+        .collect::<Vec<_>>();
+    assert_eq!(pages, ["one\n— pag...", "two\n— pag..."]);
+}
+
+#[test]
+fn each_page_holds_a_line_even_with_a_footer() {
+    let pages = Pages::<Ascii>::new("one\ntwo", 1)
+        .footer()
+        .collect::<Vec<_>>();
+    assert_eq!(pages, ["one\n— page 1 of 2 —", "two\n— page 2 of 2 —"]);
+}
+
+#[test]
+fn pages_partition_the_text() {
+    let text = (0..100)
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let pages = Pages::<Ascii>::new(&text, 7).collect::<Vec<_>>();
+    assert_eq!(pages.len(), 15);
+    assert_eq!(pages.join("\n"), text);
+}
+
+#[test]
+fn blank_lines_are_kept() {
+    let pages = Pages::<Ascii>::new("\nx\ny\nz", 2).collect::<Vec<_>>();
+    assert_eq!(pages, ["\nx", "y\nz"]);
+
+    let pages = Pages::<Ascii>::new("a\n\n\nb", 2)
+        .footer()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        pages,
+        [
+            "a\n— page 1 of 4 —",
+            "\n— page 2 of 4 —",
+            "\n— page 3 of 4 —",
+            "b\n— page 4 of 4 —"
+        ]
+    );
+}
@@ -0,0 +1,104 @@
+//! test cases for path shortening in [`shear::path`].
+
+#![cfg(feature = "str")]
+
+use {
+    proptest::proptest,
+    shear::{
+        path,
+        str::ellipsis::{Ascii, Horizontal},
+    },
+    std::path::PathBuf,
+};
+
+proptest! {
+    #[test]
+    fn shortened_paths_fit(
+        components in proptest::collection::vec("[a-zé]{1,8}", 1..8),
+        budget in 0..48_usize,
+    ) {
+        let p = components.join("/");
+        let shortened = path::trim_to_length::<Ascii>(&p, budget);
+        assert!(shortened.len() <= budget.max(3), "`{shortened}` should fit in {budget} bytes");
+        if p.len() <= budget {
+            assert_eq!(shortened, p);
+        }
+    }
+}
+
+#[test]
+fn first_and_last_components_are_kept() {
+    let p = "/usr/local/share/doc/shear/README.md";
+    assert_eq!(
+        path::trim_to_width::<Ascii>(p, 30),
+        "/usr/.../doc/shear/README.md"
+    );
+    assert_eq!(path::trim_to_width::<Ascii>(p, 20), "/usr/.../README.md");
+    assert_eq!(
+        path::trim_to_length::<Horizontal>(p, 20),
+        "/usr/…/README.md"
+    );
+}
+
+#[test]
+fn paths_that_fit_are_not_altered() {
+    let p = PathBuf::from("src/lib.rs");
+    assert_eq!(path::trim_to_width::<Ascii>(&p, 10), "src/lib.rs");
+    assert_eq!(path::trim_to_width::<Ascii>(p, 8), "...ib.rs");
+}
+
+#[test]
+fn relative_paths_are_shortened() {
+    let p = "src/str/trim_to_height.rs";
+    assert_eq!(
+        path::trim_to_width::<Horizontal>(p, 24),
+        "src/…/trim_to_height.rs"
+    );
+}
+
+#[test]
+fn long_last_components_keep_their_end() {
+    let p = "/tmp/cache/a-very-long-file-name.txt";
+    assert_eq!(path::trim_to_width::<Ascii>(p, 16), "...file-name.txt");
+}
+
+#[test]
+fn file_names_keep_their_extension() {
+    let name = "extremely-long-report-name.pdf";
+    assert_eq!(
+        path::trim_file_name_to_width::<Horizontal>(name, 12),
+        "extreme….pdf"
+    );
+    assert_eq!(
+        path::trim_file_name_to_length::<Ascii>(name, 12),
+        "extre....pdf"
+    );
+    assert_eq!(
+        path::trim_file_name_to_width::<Ascii>("archive.tar.gz", 10),
+        "arch....gz"
+    );
+}
+
+#[test]
+fn file_names_without_extensions_are_trimmed() {
+    assert_eq!(
+        path::trim_file_name_to_width::<Ascii>("Makefile.in", 11),
+        "Makefile.in"
+    );
+    assert_eq!(
+        path::trim_file_name_to_width::<Ascii>("README_FIRST", 8),
+        "READM..."
+    );
+    assert_eq!(
+        path::trim_file_name_to_width::<Ascii>(".gitattributes", 8),
+        ".gita..."
+    );
+}
+
+#[test]
+fn long_extensions_are_trimmed() {
+    assert_eq!(
+        path::trim_file_name_to_width::<Ascii>("notes.markdown", 8),
+        "notes..."
+    );
+}
@@ -0,0 +1,80 @@
+//! test cases for source code snippets in [`shear::str::snippet`].
+
+#![cfg(feature = "str")]
+
+use shear::str::{ellipsis::Ascii, snippet::Snippet};
+
+/// helper fn: returns a text of `n` lines.
+fn code(n: usize) -> String {
+    (1..=n)
+        .map(|i| format!("line {i}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn code_that_fits_is_numbered() {
+    assert_eq!(
+        Snippet::<Ascii>::new(3).trim(&code(3)),
+        "1 | line 1\n2 | line 2\n3 | line 3"
+    );
+    assert_eq!(Snippet::<Ascii>::new(3).trim(""), "");
+}
+
+#[test]
+fn omitted_ranges_are_marked() {
+    assert_eq!(
+        Snippet::<Ascii>::new(2).trim(&code(3)),
+        "1 | line 1\n  | ... lines 2-3 omitted"
+    );
+    assert_eq!(
+        Snippet::<Ascii>::new(3).tail(1).trim(&code(4)),
+        "1 | line 1\n  | ... lines 2-3 omitted\n4 | line 4"
+    );
+    assert_eq!(
+        Snippet::<Ascii>::new(3).tail(1).trim(&code(3)),
+        "1 | line 1\n2 | line 2\n3 | line 3"
+    );
+    assert_eq!(
+        Snippet::<Ascii>::new(3).tail(2).trim(&code(3)),
+        "1 | line 1\n2 | line 2\n3 | line 3"
+    );
+}
+
+#[test]
+fn single_omitted_lines_are_marked() {
+    assert_eq!(Snippet::<Ascii>::new(1).trim("only"), "1 | only");
+    assert_eq!(
+        Snippet::<Ascii>::new(1).trim(&code(2)),
+        "  | ... lines 1-2 omitted"
+    );
+}
+
+#[test]
+fn zero_height_is_empty() {
+    assert_eq!(Snippet::<Ascii>::new(0).trim("only"), "");
+    assert_eq!(Snippet::<Ascii>::new(0).tail(1).trim(&code(3)), "");
+    assert_eq!(Snippet::<Ascii>::new(0).trim(""), "");
+}
+
+#[test]
+fn line_numbers_are_aligned() {
+    let limited = Snippet::<Ascii>::new(4).tail(2).start(98).trim(&code(5));
+    assert_eq!(
+        limited,
+        " 98 | line 1\n    | ... lines 99-100 omitted\n101 | line 4\n102 | line 5"
+    );
+}
+
+#[test]
+fn windows_line_endings_are_preserved() {
+    let code = code(3).replace('\n', "\r\n");
+    assert_eq!(
+        Snippet::<Ascii>::new(3).trim(&code),
+        "1 | line 1\r\n2 | line 2\r\n3 | line 3"
+    );
+    assert_eq!(
+        Snippet::<Ascii>::new(2).trim(&code),
+        "1 | line 1\r\n  | ... lines 2-3 omitted"
+    );
+}
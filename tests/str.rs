@@ -17,7 +17,7 @@ mod strategy;
 /// confirm that we can limit smart pointers like e.g. [`std::borrow::Cow`].
 /// compile the project using `--tests` or `all-targets` to exercise these definitions.
 mod relevant_types_can_be_limited {
-    use std::borrow::Cow;
+    use std::{borrow::Cow, rc::Rc, sync::Arc};
 
     use super::*;
 
@@ -32,6 +32,11 @@ mod relevant_types_can_be_limited {
         can_be_limited::<String>();
         can_be_limited::<Cow<str>>();
         can_be_limited::<Box<str>>();
+        can_be_limited::<Arc<String>>();
+        can_be_limited::<Arc<str>>();
+        can_be_limited::<Rc<String>>();
+        can_be_limited::<&&str>();
+        can_be_limited::<Cow<String>>();
     }
 }
 
@@ -189,3 +194,2408 @@ mod strs_can_be_truncated {
         });
     }
 }
+
+/// test that strings can be limited into a fixed-size buffer.
+mod strs_can_be_limited_into_a_buffer {
+    use {super::*, shear::str::BufferTooSmall};
+
+    proptest! {
+        #[test]
+        fn buffer_contents_match_trim_to_length(input in strategy::input_strategy())
+        {
+            buffer_contents_match_trim_to_length_(input)
+        }
+    }
+
+    fn buffer_contents_match_trim_to_length_(TestInput { value, length }: TestInput) {
+        let mut buf = vec![0; length];
+        let n = value
+            .trim_to_length_into::<ellipsis::Ascii>(&mut buf)
+            .expect("buffer should be large enough");
+        assert_eq!(
+            &buf[..n],
+            value.trim_to_length::<ellipsis::Ascii>(length).as_bytes()
+        );
+    }
+
+    #[test]
+    fn buffers_smaller_than_the_ellipsis_are_an_error() {
+        let mut buf = [0; 2];
+        "hello"
+            .trim_to_length_into::<ellipsis::Ascii>(&mut buf)
+            .pipe(|r| assert_eq!(r, Err(BufferTooSmall { needed: 3 })));
+    }
+
+    #[test]
+    fn short_values_fit_in_small_buffers() {
+        let mut buf = [0; 2];
+        "hi".trim_to_length_into::<ellipsis::Ascii>(&mut buf)
+            .pipe(|r| assert_eq!(r, Ok(2)));
+        assert_eq!(&buf, b"hi");
+    }
+}
+
+/// test that strings can be limited into fixed-capacity strings.
+#[cfg(any(feature = "heapless", feature = "arrayvec"))]
+mod strs_can_be_limited_into_fixed_capacity_strings {
+    use {super::*, shear::str::BufferTooSmall};
+
+    proptest! {
+        #[test]
+        #[cfg(feature = "heapless")]
+        fn heapless_strings_match_buffers(input in strategy::input_strategy())
+        {
+            heapless_strings_match_buffers_(input)
+        }
+
+        #[test]
+        #[cfg(feature = "arrayvec")]
+        fn array_strings_match_buffers(input in strategy::input_strategy())
+        {
+            array_strings_match_buffers_(input)
+        }
+    }
+
+    /// returns the contents of a buffer limited to `length` bytes.
+    fn buffer(value: &str, length: usize) -> Result<String, BufferTooSmall> {
+        let mut buf = vec![0; length];
+        let n = value.trim_to_length_into::<ellipsis::Ascii>(&mut buf)?;
+        Ok(String::from_utf8(buf[..n].to_vec()).unwrap())
+    }
+
+    #[cfg(feature = "heapless")]
+    fn heapless_strings_match_buffers_(TestInput { value, length }: TestInput) {
+        let limited = value.trim_to_length_heapless::<ellipsis::Ascii, 16>(length);
+        assert_eq!(
+            limited.map(|s| s.as_str().to_owned()),
+            buffer(&value, length.min(16))
+        );
+    }
+
+    #[cfg(feature = "arrayvec")]
+    fn array_strings_match_buffers_(TestInput { value, length }: TestInput) {
+        let limited = value.trim_to_length_array_string::<ellipsis::Ascii, 16>(length);
+        assert_eq!(
+            limited.map(|s| s.as_str().to_owned()),
+            buffer(&value, length.min(16))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "heapless")]
+    fn heapless_capacity_limits_the_length() {
+        let limited = "a very long string value".trim_to_length_heapless::<ellipsis::Ascii, 10>(64);
+        assert_eq!(limited.as_deref(), Ok("a very ..."));
+
+        let limited = "hello".trim_to_length_heapless::<ellipsis::Ascii, 2>(64);
+        assert_eq!(limited, Err(BufferTooSmall { needed: 3 }));
+    }
+
+    #[test]
+    #[cfg(feature = "arrayvec")]
+    fn array_string_capacity_limits_the_length() {
+        let limited = "a very long string value"
+            .trim_to_length_array_string::<ellipsis::Ascii, 10>(64)
+            .unwrap();
+        assert_eq!(limited.as_str(), "a very ...");
+
+        let limited = "hi".trim_to_length_array_string::<ellipsis::Ascii, 2>(64);
+        assert_eq!(limited.unwrap().as_str(), "hi");
+    }
+}
+
+/// test that strings can be limited by height.
+mod strs_can_be_limited_by_height {
+    use shear::str::{ellipsis::Ascii, Limited};
+
+    #[test]
+    fn short_strings_are_not_altered() {
+        assert_eq!("".trim_to_height::<Ascii>(2), "");
+        assert_eq!("one\ntwo".trim_to_height::<Ascii>(2), "one\ntwo");
+    }
+
+    #[test]
+    fn strings_that_exactly_fit_have_no_ellipsis() {
+        assert_eq!("".trim_to_height::<Ascii>(0), "");
+        assert_eq!("one".trim_to_height::<Ascii>(1), "one");
+        assert_eq!("one\ntwo".trim_to_height::<Ascii>(2), "one\ntwo");
+        assert_eq!("one\ntwo".trim_to_height::<Ascii>(1), "...");
+    }
+
+    #[test]
+    fn tall_strings_are_limited() {
+        assert_eq!("one\ntwo\nthree".trim_to_height::<Ascii>(2), "one\n...");
+        assert_eq!("one\ntwo\r\nthree".trim_to_height::<Ascii>(2), "one\n...");
+    }
+
+    #[test]
+    fn line_terminators_can_be_configured() {
+        use shear::str::Terminators;
+
+        let value = "one\rtwo\r\nthree\u{2028}four\nfive";
+        let trim =
+            |height, terminators| value.trim_to_height_terminated::<Ascii>(height, terminators);
+        assert_eq!(
+            trim(3, Terminators::Ascii),
+            "one\rtwo\r\nthree\u{2028}four\r\nfive"
+        );
+        assert_eq!(trim(2, Terminators::Ascii), "one\rtwo\r\n...");
+        assert_eq!(
+            trim(3, Terminators::Unicode),
+            value.trim_to_height::<Ascii>(3)
+        );
+        assert_eq!(trim(3, Terminators::All), "one\ntwo\n...");
+        assert_eq!(
+            "a\r\rb".trim_to_height_terminated::<Ascii>(3, Terminators::All),
+            "a\n\nb"
+        );
+        assert_eq!(
+            "a".trim_to_height_terminated::<Ascii>(1, Terminators::All),
+            "a"
+        );
+        assert_eq!(
+            "".trim_to_height_terminated::<Ascii>(0, Terminators::Ascii),
+            ""
+        );
+    }
+
+    #[test]
+    fn windows_line_endings_are_preserved() {
+        let value = "one\r\ntwo\r\nthree";
+        assert_eq!(value.trim_to_height::<Ascii>(3), value);
+        assert_eq!(value.trim_to_height::<Ascii>(2), "one\r\n...");
+        assert_eq!(value.trim_to_height_keep_end::<Ascii>(2), "...\r\nthree");
+    }
+
+    #[test]
+    fn windows_line_endings_are_preserved_by_every_variant() {
+        let value = "one.\r\ntwo\r\nthree";
+        assert_eq!(value.trim_to_height_weighted::<Ascii>(3, |_| 1), value);
+        assert_eq!(
+            value.trim_to_height_weighted::<Ascii>(2, |_| 1),
+            "one.\r\n..."
+        );
+        assert_eq!(value.trim_to_height_wrapped::<Ascii>(2, 8), "one.\r\n...");
+        assert_eq!(value.trim_to_height_inline::<Ascii>(2), "one.\r\ntwo...");
+        assert_eq!(value.trim_to_height_at_sentence::<Ascii>(3), value);
+        assert_eq!(
+            "one.\r\ntwo\r\nthree\r\nfour".trim_to_height_at_sentence::<Ascii>(3),
+            "one.\r\n..."
+        );
+
+        let mut pieces = Vec::new();
+        value.extend_trimmed_to_height::<Ascii>(&mut pieces, 2);
+        assert_eq!(pieces.concat(), value.trim_to_height::<Ascii>(2));
+    }
+
+    #[test]
+    fn unicode_line_separators_are_line_breaks() {
+        for separator in ["\u{0085}", "\u{2028}", "\u{2029}"] {
+            let value = ["one", "two", "three"].join(separator);
+            assert_eq!(value.trim_to_height::<Ascii>(3), "one\ntwo\nthree");
+            assert_eq!(value.trim_to_height::<Ascii>(2), "one\n...");
+        }
+    }
+
+    #[test]
+    fn last_lines_can_be_kept() {
+        assert_eq!("one\ntwo".trim_to_height_keep_end::<Ascii>(2), "one\ntwo");
+        assert_eq!(
+            "one\ntwo\nthree".trim_to_height_keep_end::<Ascii>(2),
+            "...\nthree"
+        );
+        assert_eq!(
+            "one\ntwo\nthree\nfour".trim_to_height_keep_end::<Ascii>(3),
+            "...\nthree\nfour"
+        );
+        assert_eq!("one\ntwo".trim_to_height_keep_end::<Ascii>(1), "...");
+        assert_eq!("one".trim_to_height_keep_end::<Ascii>(1), "one");
+        assert_eq!("".trim_to_height_keep_end::<Ascii>(0), "");
+    }
+
+    #[test]
+    fn ellipsis_can_be_appended_to_the_last_line() {
+        assert_eq!("one\ntwo".trim_to_height_inline::<Ascii>(2), "one\ntwo");
+        assert_eq!(
+            "one\ntwo\nthree".trim_to_height_inline::<Ascii>(2),
+            "one\ntwo..."
+        );
+        assert_eq!("one\ntwo\nthree".trim_to_height_inline::<Ascii>(0), "...");
+    }
+
+    #[test]
+    fn prose_is_cut_at_sentence_ends() {
+        let value = "one. two\nthree\nfour\nfive";
+        assert_eq!(value.trim_to_height_at_sentence::<Ascii>(3), "one.\n...");
+        assert_eq!(value.trim_to_height_at_sentence::<Ascii>(4), value);
+        assert_eq!(value.trim_to_height_at_sentence::<Ascii>(0), "...");
+    }
+
+    #[test]
+    fn prose_is_cut_at_paragraph_breaks() {
+        let value = "one\n\nthree\nfour\nfive";
+        assert_eq!(value.trim_to_height_at_sentence::<Ascii>(4), "one\n...");
+        let value = "one\ntwo\n\nfour";
+        assert_eq!(
+            value.trim_to_height_at_sentence::<Ascii>(3),
+            "one\ntwo\n..."
+        );
+    }
+
+    #[test]
+    fn leading_blank_lines_are_kept() {
+        assert_eq!("\nx\ny".trim_to_height_at_sentence::<Ascii>(2), "\n...");
+        assert_eq!("\nx\ny".trim_to_height_at_sentence::<Ascii>(3), "\nx\ny");
+        assert_eq!("\n\nx".trim_to_height_at_sentence::<Ascii>(2), "\n...");
+    }
+
+    #[test]
+    fn prose_without_sentence_ends_is_cut_at_lines() {
+        let value = "one\ntwo\nthree\nfour";
+        assert_eq!(
+            value.trim_to_height_at_sentence::<Ascii>(3),
+            value.trim_to_height::<Ascii>(3)
+        );
+    }
+
+    #[test]
+    fn lines_can_be_weighted() {
+        let value = "one\ntwo\nthree\nfour";
+        let unweighted = value.trim_to_height_weighted::<Ascii>(3, |_| 1);
+        assert_eq!(unweighted, value.trim_to_height::<Ascii>(3));
+
+        let wrapped = |line: &str| line.len().div_ceil(3);
+        assert_eq!(
+            value.trim_to_height_weighted::<Ascii>(4, wrapped),
+            "one\ntwo\n..."
+        );
+        assert_eq!(value.trim_to_height_weighted::<Ascii>(0, |_| 0), value);
+        assert_eq!("one".trim_to_height_weighted::<Ascii>(1, |_| 1), "one");
+        assert_eq!("".trim_to_height_weighted::<Ascii>(0, |_| 1), "");
+    }
+
+    #[test]
+    fn heavy_lines_do_not_overflow_the_weight() {
+        let value = "one\ntwo\nthree";
+        let limited = value.trim_to_height_weighted::<Ascii>(2, |_| usize::MAX);
+        assert_eq!(limited, "...");
+    }
+
+    #[test]
+    fn wrapped_lines_count_for_each_row() {
+        let value = "一二三四\n\nabcdef\nend";
+        assert_eq!(value.trim_to_height_wrapped::<Ascii>(5, 3), "一二三四\n...");
+        assert_eq!(
+            value.trim_to_height_wrapped::<Ascii>(7, 3),
+            "一二三四\n\n..."
+        );
+        assert_eq!(value.trim_to_height_wrapped::<Ascii>(8, 3), value);
+        assert_eq!(
+            value.trim_to_height_wrapped::<Ascii>(4, 80),
+            value.trim_to_height::<Ascii>(4)
+        );
+    }
+
+    #[test]
+    fn matching_lines_are_always_kept() {
+        let value = "# a\none\ntwo\n# b\nthree";
+        let keep = |line: &str| line.starts_with('#');
+        assert_eq!(value.trim_to_height_keeping::<Ascii>(3, keep), value);
+        assert_eq!(
+            value.trim_to_height_keeping::<Ascii>(2, keep),
+            "# a\none\n...\n# b"
+        );
+        assert_eq!(
+            value.trim_to_height_keeping::<Ascii>(0, keep),
+            "# a\n...\n# b"
+        );
+    }
+
+    #[test]
+    fn keeping_no_lines_matches_trim_to_height() {
+        for value in [
+            "one\ntwo\nthree\nfour",
+            "\n\nhello\nworld\nmore",
+            "one\r\ntwo\r\nthree",
+        ] {
+            for height in 0..6 {
+                assert_eq!(
+                    value.trim_to_height_keeping::<Ascii>(height, |_| false),
+                    value.trim_to_height::<Ascii>(height)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn kept_lines_preserve_blank_lines_and_line_endings() {
+        let keep = |line: &str| line.starts_with('#');
+        assert_eq!(
+            "\n# a\none\ntwo".trim_to_height_keeping::<Ascii>(2, keep),
+            "\n# a\n..."
+        );
+        assert_eq!(
+            "# a\r\none\r\ntwo".trim_to_height_keeping::<Ascii>(1, keep),
+            "# a\r\n..."
+        );
+    }
+
+    #[test]
+    fn trailing_line_breaks_do_not_count_as_a_line() {
+        assert_eq!("one\ntwo\n".trim_to_height::<Ascii>(2), "one\ntwo");
+        assert_eq!("one\ntwo\u{2029}".trim_to_height::<Ascii>(2), "one\ntwo");
+    }
+}
+
+/// test that strings can be split into segments limited by width.
+mod strs_can_be_split_by_width {
+    use {super::*, shear::str::Limited, unicode_width::UnicodeWidthStr};
+
+    proptest! {
+        #[test]
+        fn segments_fit_and_cover_the_string(
+            value in strategy::value_strategy_non_empty(),
+            width in 2..64_usize,
+        )
+        {
+            segments_fit_and_cover_the_string_(value, width)
+        }
+    }
+
+    fn segments_fit_and_cover_the_string_(value: String, width: usize) {
+        let segments = value.split_to_width(width).collect::<Vec<_>>();
+        for segment in &segments {
+            assert!(
+                segment.width() <= width,
+                "`{segment}` should fit in {width} columns"
+            );
+        }
+        assert_eq!(segments.concat(), value, "segments should cover the string");
+    }
+
+    #[test]
+    fn combining_marks_are_not_split_from_their_base() {
+        let value = "e\u{301}e\u{301}e\u{301}";
+        let segments = value.split_to_width(1).collect::<Vec<_>>();
+        assert_eq!(segments, ["e\u{301}", "e\u{301}", "e\u{301}"]);
+    }
+
+    #[test]
+    fn wide_characters_are_yielded_alone() {
+        let segments = "ａｂ".split_to_width(1).collect::<Vec<_>>();
+        assert_eq!(segments, ["ａ", "ｂ"]);
+    }
+
+    #[test]
+    fn empty_strings_have_no_segments() {
+        assert_eq!("".split_to_width(4).next(), None);
+    }
+}
+
+/// test that strings can be split into pieces limited by length.
+mod strs_can_be_split_by_length {
+    use {super::*, shear::str::Limited};
+
+    proptest! {
+        #[test]
+        fn pieces_fit_and_cover_the_string(
+            value in strategy::value_strategy_non_empty(),
+            length in 3..64_usize,
+            words: bool,
+        )
+        {
+            pieces_fit_and_cover_the_string_(value, length, words)
+        }
+    }
+
+    fn pieces_fit_and_cover_the_string_(value: String, length: usize, words: bool) {
+        let pieces = match words {
+            true => value
+                .split_to_length(length)
+                .prefer_words()
+                .collect::<Vec<_>>(),
+            false => value.split_to_length(length).collect::<Vec<_>>(),
+        };
+        for piece in &pieces {
+            assert!(
+                piece.len() <= length,
+                "`{piece}` should fit in {length} bytes"
+            );
+            assert!(!piece.is_empty(), "pieces should not be empty");
+        }
+        assert_eq!(pieces.concat(), value, "pieces should cover the string");
+    }
+
+    #[test]
+    fn characters_are_not_split() {
+        let pieces = "ａｂｃ".split_to_length(4).collect::<Vec<_>>();
+        assert_eq!(pieces, ["ａ", "ｂ", "ｃ"]);
+        let pieces = "ａｂｃ".split_to_length(1).collect::<Vec<_>>();
+        assert_eq!(pieces, ["ａ", "ｂ", "ｃ"]);
+    }
+
+    #[test]
+    fn long_words_are_split_when_preferring_words() {
+        let pieces = "supercalifragilistic expialidocious"
+            .split_to_length(8)
+            .prefer_words()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pieces,
+            ["supercal", "ifragili", "stic ", "expialid", "ocious"]
+        );
+    }
+}
+
+/// test that strings can be horizontally scrolled.
+mod strs_can_be_scrolled {
+    use {
+        super::*,
+        shear::str::{ellipsis::Horizontal, Limited},
+        unicode_width::UnicodeWidthStr,
+    };
+
+    proptest! {
+        #[test]
+        fn windows_fit_in_their_width(
+            value in strategy::value_strategy_non_empty(),
+            offset in 0..2048_usize,
+            width in 4..64_usize,
+        )
+        {
+            windows_fit_in_their_width_(value, offset, width)
+        }
+    }
+
+    fn windows_fit_in_their_width_(value: String, offset: usize, width: usize) {
+        let (window, clamped) = value.scroll_to_width::<ellipsis::Ascii>(offset, width);
+        assert!(
+            window.width() <= width,
+            "`{window}` should fit in {width} columns"
+        );
+        assert!(clamped <= offset, "offset should only be clamped downwards");
+    }
+
+    #[test]
+    fn centered_strings_keep_the_middle() {
+        assert_eq!(
+            "abcdefghij".trim_to_width_centered::<Horizontal>(6),
+            "…defg…"
+        );
+        assert_eq!(
+            "abcdefghij".trim_to_width_centered::<Horizontal>(10),
+            "abcdefghij"
+        );
+        assert_eq!(
+            "ａｂｃｄｅｆ".trim_to_width_centered::<Horizontal>(6),
+            "…ｃｄ…"
+        );
+    }
+
+    #[test]
+    fn short_strings_do_not_scroll() {
+        let (window, offset) = "short".scroll_to_width::<Horizontal>(3, 8);
+        assert_eq!((window.as_str(), offset), ("short", 0));
+    }
+
+    #[test]
+    fn wide_characters_are_not_split() {
+        let value = "ａｂｃｄｅｆ";
+        let (window, offset) = value.scroll_to_width::<Horizontal>(1, 6);
+        assert_eq!((window.as_str(), offset), ("…ｂｃ…", 1));
+        let (window, offset) = value.scroll_to_width::<Horizontal>(2, 6);
+        assert_eq!((window.as_str(), offset), ("…ｃ…", 2));
+    }
+}
+
+/// test that the built-in units match the corresponding trim methods.
+mod built_in_units_match_trim_methods {
+    use {
+        super::*,
+        shear::str::units::{Chars, Height, Length, Utf16, Width},
+    };
+
+    proptest! {
+        #[test]
+        fn length_matches_trim_to_length(input in strategy::input_strategy())
+        {
+            let TestInput { value, length } = input;
+            assert_eq!(
+                value.trim_to_units::<Length, ellipsis::Ascii>(length),
+                value.trim_to_length::<ellipsis::Ascii>(length)
+            );
+        }
+
+        #[test]
+        fn width_matches_trim_to_width(
+            value in strategy::value_strategy_non_empty(),
+            width in 0..256_usize,
+        )
+        {
+            assert_eq!(
+                value.trim_to_units::<Width, ellipsis::Horizontal>(width),
+                value.trim_to_width::<ellipsis::Horizontal>(width)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn chars_matches_trim_to_chars(
+            value in strategy::value_strategy_non_empty(),
+            // the ellipsis is kept even if it does not fit, so the budget must have room for it.
+            count in 1..256_usize,
+        )
+        {
+            let limited = value.trim_to_chars::<ellipsis::Horizontal>(count);
+            assert!(limited.chars().count() <= count);
+            if value.chars().count() > count {
+                assert_eq!(limited, value.trim_to_units::<Chars, ellipsis::Horizontal>(count));
+            } else {
+                assert_eq!(limited, value);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn utf16_matches_trim_to_utf16_units(
+            value in strategy::value_strategy_non_empty(),
+            // the ellipsis is kept even if it does not fit, so the budget must have room for it.
+            count in 1..256_usize,
+        )
+        {
+            let limited = value.trim_to_utf16_units::<ellipsis::Horizontal>(count);
+            assert!(limited.encode_utf16().count() <= count);
+            if value.encode_utf16().count() > count {
+                assert_eq!(limited, value.trim_to_units::<Utf16, ellipsis::Horizontal>(count));
+            } else {
+                assert_eq!(limited, value);
+            }
+        }
+    }
+
+    #[test]
+    fn surrogate_pairs_are_not_split() {
+        let value = "a😀😀";
+        assert_eq!(value.trim_to_utf16_units::<ellipsis::Horizontal>(5), value);
+        assert_eq!(value.trim_to_utf16_units::<ellipsis::Horizontal>(4), "a😀…");
+        assert_eq!(value.trim_to_utf16_units::<ellipsis::Horizontal>(3), "a…");
+    }
+
+    #[test]
+    fn chars_are_counted_rather_than_bytes() {
+        let value = "日本語のテキスト";
+        assert_eq!(value.trim_to_chars::<ellipsis::Ascii>(8), value);
+        assert_eq!(value.trim_to_chars::<ellipsis::Ascii>(7), "日本語の...");
+        assert_eq!(
+            value.trim_to_chars::<ellipsis::Horizontal>(7),
+            "日本語のテキ…"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn measured_length_matches_trim_to_length(input in strategy::input_strategy())
+        {
+            let TestInput { value, length } = input;
+            assert_eq!(
+                value.trim_with::<ellipsis::Ascii>(&Length, length),
+                value.trim_to_length::<ellipsis::Ascii>(length)
+            );
+        }
+
+        #[test]
+        fn measured_width_matches_trim_to_width(
+            value in strategy::value_strategy_non_empty(),
+            width in 0..256_usize,
+        )
+        {
+            assert_eq!(
+                value.trim_with::<ellipsis::Horizontal>(&Width, width),
+                value.trim_to_width::<ellipsis::Horizontal>(width)
+            );
+        }
+    }
+
+    #[test]
+    fn measured_height_matches_trim_to_height() {
+        let value = "one\ntwo\r\nthree\u{2028}four";
+        for height in 1..6 {
+            assert_eq!(
+                value.trim_with::<ellipsis::Ascii>(&Height, height),
+                value.trim_to_height::<ellipsis::Ascii>(height)
+            );
+        }
+    }
+
+    #[test]
+    fn stateful_measures_can_be_used() {
+        use shear::str::units::{Measure, Units};
+
+        /// counts vowels as heavier than other characters.
+        struct Weighted(usize);
+
+        impl Measure for Weighted {
+            type Iter<'a> = <Chars as Units>::Iter<'a>;
+
+            fn split<'a>(&self, s: &'a str) -> Self::Iter<'a> {
+                Chars::units(s)
+            }
+
+            fn measure(&self, unit: &str) -> usize {
+                unit.chars()
+                    .map(|c| if "aeiou".contains(c) { self.0 } else { 1 })
+                    .sum()
+            }
+        }
+
+        assert_eq!(
+            "banana".trim_with::<ellipsis::Ascii>(&Weighted(1), 6),
+            "banana"
+        );
+        assert_eq!(
+            "banana".trim_with::<ellipsis::Ascii>(&Weighted(2), 9),
+            "banana"
+        );
+        assert_eq!(
+            "banana".trim_with::<ellipsis::Ascii>(&Weighted(3), 9),
+            "ban..."
+        );
+    }
+
+    #[test]
+    fn units_and_measures_agree_when_only_the_ellipsis_fits() {
+        for value in ["", "ab", "abc", "abcd"] {
+            let expected = value.trim_to_width::<ellipsis::Ascii>(3);
+            assert_eq!(value.trim_to_units::<Width, ellipsis::Ascii>(3), expected);
+            assert_eq!(value.trim_with::<ellipsis::Ascii>(&Width, 3), expected);
+        }
+        assert_eq!("ab".trim_to_units::<Width, ellipsis::Ascii>(3), "ab");
+    }
+
+    #[test]
+    fn height_matches_trim_to_height() {
+        let value = "one\ntwo\r\nthree\u{2028}four";
+        for height in 0..6 {
+            assert_eq!(
+                value.trim_to_units::<Height, ellipsis::Ascii>(height),
+                value.trim_to_height::<ellipsis::Ascii>(height)
+            );
+        }
+    }
+}
+
+/// test that the provided ellipses have the documented sizes.
+mod ellipses_have_documented_sizes {
+    use {
+        shear::str::{ellipsis::*, Ellipsis, Limited},
+        unicode_width::UnicodeWidthStr,
+    };
+
+    /// helper fn: returns the width and length of an ellipsis.
+    fn size<E: Ellipsis>() -> (usize, usize) {
+        let e = E::ellipsis();
+        (e.width(), e.len())
+    }
+
+    #[test]
+    fn preset_ellipses() {
+        assert_eq!(size::<Midline>(), (1, 3));
+        assert_eq!(size::<Vertical>(), (1, 3));
+        assert_eq!(size::<Bullets>(), (3, 9));
+        assert_eq!(size::<Arrow>(), (1, 3));
+        assert_eq!(size::<Tilde>(), (1, 1));
+    }
+
+    #[test]
+    fn paired_ellipses() {
+        let e = [
+            Guillemets::leading(),
+            Guillemets::ellipsis(),
+            Guillemets::trailing(),
+        ]
+        .concat();
+        assert_eq!((e.width(), e.len()), (3, 7));
+    }
+
+    #[test]
+    fn french_ellipsis() {
+        assert_eq!(size::<French>(), (2, 6));
+
+        let value = "une très longue chaîne";
+        assert_eq!(value.trim_to_width::<French>(12), "une très l\u{202F}…");
+        assert_eq!(value.trim_to_length::<French>(12), "une tr\u{202F}…");
+    }
+}
+
+/// test that pre-measured characters can be limited.
+mod pre_measured_chars_can_be_limited {
+    use {super::*, shear::str::trim_measured, unicode_width::UnicodeWidthChar};
+
+    proptest! {
+        #[test]
+        fn measured_chars_match_trim_to_width(
+            value in strategy::value_strategy_non_empty(),
+            width in 0..256_usize,
+        )
+        {
+            let chars = value.chars().map(|c| (c, c.width().unwrap_or_default()));
+            assert_eq!(
+                trim_measured::<ellipsis::Horizontal>(chars, width),
+                value.trim_to_width::<ellipsis::Horizontal>(width)
+            );
+        }
+    }
+}
+
+/// test that strings can be progressively shortened.
+mod strs_can_be_shortened {
+    use shear::str::{ellipsis::Ascii, shorten::Shorten};
+
+    #[test]
+    fn nested_parentheticals_are_dropped() {
+        let shorten = Shorten::<Ascii>::new().drop_parentheticals();
+        let value = "report (final (really)) v2";
+        assert_eq!(shorten.trim_to_length(value, 10), "report v2");
+    }
+
+    #[test]
+    fn steps_stop_once_the_value_fits() {
+        let abbreviations = [("report", "rpt")];
+        let shorten = Shorten::<Ascii>::new()
+            .collapse_whitespace()
+            .abbreviate(&abbreviations);
+        assert_eq!(shorten.trim_to_length("report   2024", 11), "report 2024");
+        assert_eq!(shorten.trim_to_length("report   2024", 8), "rpt 2024");
+    }
+
+    #[test]
+    fn values_that_do_not_fit_are_truncated() {
+        let shorten = Shorten::<Ascii>::new().collapse_whitespace();
+        assert_eq!(
+            shorten.trim_to_length("a   very long value", 10),
+            "a very ..."
+        );
+    }
+}
+
+/// test that fallback strategies are tried in order.
+mod fallback_strategies_are_tried_in_order {
+    use shear::str::{ellipsis::Ascii, fallback::Fallback};
+
+    #[test]
+    fn every_strategy_can_decline() {
+        let fallback = Fallback::new().or_word_boundary::<Ascii>(0);
+        assert_eq!(fallback.trim_to_width("unbreakable", 8), None);
+        assert_eq!(Fallback::new().trim_to_width("value", 8), None);
+    }
+
+    #[test]
+    fn custom_strategies_can_be_added() {
+        let fallback = Fallback::new()
+            .or(|_, width| (width < 4).then(|| "?".repeat(width)))
+            .or_hard::<Ascii>();
+        assert_eq!(
+            fallback.trim_to_width("a long value", 2).as_deref(),
+            Some("??")
+        );
+        assert_eq!(
+            fallback.trim_to_width("a long value", 8).as_deref(),
+            Some("a lon...")
+        );
+    }
+
+    #[test]
+    fn word_boundaries_respect_the_minimum_width() {
+        let fallback = Fallback::new()
+            .or_word_boundary::<Ascii>(4)
+            .or_hard::<Ascii>();
+        assert_eq!(
+            fallback.trim_to_width("ab cdefgh", 8).as_deref(),
+            Some("ab cd...")
+        );
+        assert_eq!(
+            fallback.trim_to_width("abcd efgh", 8).as_deref(),
+            Some("abcd...")
+        );
+    }
+}
+
+/// test that limiting can be resumed with a continuation token.
+mod strs_can_be_resumed {
+    use {super::*, shear::str::resume::Token, unicode_width::UnicodeWidthStr};
+
+    proptest! {
+        #[test]
+        fn pieces_fit_and_cover_the_string(
+            value in strategy::value_strategy_non_empty(),
+            budget in 4..64_usize,
+        )
+        {
+            pieces_fit_and_cover_the_string_(value, budget)
+        }
+    }
+
+    fn pieces_fit_and_cover_the_string_(value: String, budget: usize) {
+        let (mut token, mut rest) = (Token::START, value.as_str());
+        loop {
+            let (piece, next) = value.trim_to_width_resumable::<ellipsis::Ascii>(token, budget);
+            let Some(next) = next else {
+                assert_eq!(
+                    piece, rest,
+                    "the last piece should be the rest of the string"
+                );
+                break;
+            };
+            let kept = piece
+                .strip_suffix("...")
+                .expect("piece should end with an ellipsis");
+            assert!(!kept.is_empty(), "each piece should make progress");
+            assert!(piece.width() <= budget || kept.chars().count() == 1);
+            rest = rest
+                .strip_prefix(kept)
+                .expect("pieces should cover the string");
+            token = next;
+        }
+    }
+
+    #[test]
+    fn tokens_round_trip_through_strings() {
+        let token = Token::from_offset(42);
+        assert_eq!(token.to_string().parse::<Token>(), Ok(token));
+        assert!("forty-two".parse::<Token>().is_err());
+    }
+
+    #[test]
+    fn tokens_inside_characters_are_moved_back() {
+        let (piece, token) =
+            "ａｂｃ".trim_to_length_resumable::<ellipsis::Ascii>(Token::from_offset(4), 99);
+        assert_eq!((piece.as_str(), token), ("ｂｃ", None));
+    }
+
+    #[test]
+    fn tiny_budgets_still_make_progress() {
+        let (piece, token) = "abc".trim_to_length_resumable::<ellipsis::Ascii>(Token::START, 2);
+        assert_eq!(
+            (piece.as_str(), token),
+            ("a...", Some(Token::from_offset(1)))
+        );
+    }
+}
+
+/// test that limited strings can be pushed into existing collections.
+mod strs_can_extend_sinks {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn sinks_match_trim_methods(input in strategy::input_strategy()) {
+            sinks_match_trim_methods_(input)
+        }
+    }
+
+    fn sinks_match_trim_methods_(TestInput { value, length }: TestInput) {
+        let mut sink = String::new();
+        value.extend_trimmed_to_length::<ellipsis::Ascii>(&mut sink, length);
+        assert_eq!(sink, value.trim_to_length::<ellipsis::Ascii>(length));
+
+        let mut sink = String::new();
+        value.extend_trimmed_to_width::<ellipsis::Ascii>(&mut sink, length);
+        assert_eq!(sink, value.trim_to_width::<ellipsis::Ascii>(length));
+    }
+
+    #[test]
+    fn lines_are_pushed_into_sinks() {
+        let value = "one\ntwo\nthree\nfour";
+        let mut sink = String::new();
+        value.extend_trimmed_to_height::<ellipsis::Ascii>(&mut sink, 3);
+        assert_eq!(sink, value.trim_to_height::<ellipsis::Ascii>(3));
+    }
+}
+
+/// test that strings can be limited with markers that carry runtime state.
+mod strs_can_be_limited_with_markers {
+    use {super::*, shear::str::Marker, std::cell::Cell};
+
+    /// a marker that counts how many times it has been used.
+    struct Counting {
+        uses: Cell<usize>,
+    }
+
+    impl Marker for Counting {
+        fn marker(&self) -> &str {
+            self.uses.set(self.uses.get() + 1);
+            "[cut]"
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn static_ellipses_match_trim_methods(input in strategy::input_strategy()) {
+            static_ellipses_match_trim_methods_(input)
+        }
+    }
+
+    fn static_ellipses_match_trim_methods_(TestInput { value, length }: TestInput) {
+        assert_eq!(
+            value.trim_to_length_with(&ellipsis::Ascii, length),
+            value.trim_to_length::<ellipsis::Ascii>(length)
+        );
+        assert_eq!(
+            value.trim_to_width_with(&ellipsis::Horizontal, length),
+            value.trim_to_width::<ellipsis::Horizontal>(length)
+        );
+    }
+
+    #[test]
+    fn markers_can_carry_state() {
+        let marker = Counting { uses: Cell::new(0) };
+        assert_eq!("a long value".trim_to_width_with(&marker, 8), "a l[cut]");
+        assert_eq!("a long value".trim_to_length_with(&marker, 9), "a lo[cut]");
+        assert_eq!(marker.uses.get(), 2);
+    }
+
+    #[test]
+    fn strings_can_be_used_as_markers() {
+        let configured = String::from("…");
+        assert_eq!("a long value".trim_to_width_with(&configured, 4), "a l…");
+        assert_eq!("a long value".trim_to_length_with("~~", 5), "a l~~");
+        assert_eq!("a long value".trim_to_length_with("", 3), "a l");
+    }
+}
+
+/// test that strings can be limited to a budget given as a range.
+mod strs_can_be_limited_within_a_range {
+    use {super::*, unicode_width::UnicodeWidthStr};
+
+    proptest! {
+        #[test]
+        fn limited_strings_fall_within_the_range(
+            value in strategy::value_strategy_non_empty(),
+            min in 3..32_usize,
+            extra in 0..32_usize,
+        )
+        {
+            limited_strings_fall_within_the_range_(value, min, extra)
+        }
+    }
+
+    fn limited_strings_fall_within_the_range_(value: String, min: usize, extra: usize) {
+        let max = min + extra;
+        let limited = value.trim_to_width_within::<ellipsis::Ascii>(min..=max);
+        assert!(
+            limited.width() <= max,
+            "`{limited}` should fit in {max} columns"
+        );
+
+        let limited = value.trim_to_length_within::<ellipsis::Ascii>(min..=max);
+        assert!(
+            limited.len() <= max,
+            "`{limited}` should fit in {max} bytes"
+        );
+    }
+
+    #[test]
+    fn clauses_end_after_punctuation() {
+        let s = "first, second; and a third";
+        assert_eq!(
+            s.trim_to_width_within::<ellipsis::Horizontal>(10..=18),
+            "first, second;…"
+        );
+    }
+
+    #[test]
+    fn values_that_fit_are_not_cut() {
+        let s = "short value";
+        assert_eq!(s.trim_to_width_within::<ellipsis::Ascii>(4..=11), s);
+    }
+}
+
+/// test that strings can be cut at word boundaries.
+mod strs_can_be_cut_at_words {
+    use {super::*, unicode_width::UnicodeWidthStr};
+
+    proptest! {
+        #[test]
+        fn limited_strings_fit(input in strategy::input_strategy()) {
+            limited_strings_fit_(input)
+        }
+    }
+
+    fn limited_strings_fit_(TestInput { value, length }: TestInput) {
+        let limited = value.trim_to_length_at_word::<ellipsis::Ascii>(length);
+        assert!(
+            limited.len() <= length,
+            "`{limited}` should fit in {length} bytes"
+        );
+
+        let limited = value.trim_to_width_at_word::<ellipsis::Ascii>(length);
+        assert!(
+            limited.width() <= length,
+            "`{limited}` should fit in {length} columns"
+        );
+    }
+
+    #[test]
+    fn trailing_whitespace_is_dropped() {
+        let value = "a very   long string";
+        assert_eq!(
+            value.trim_to_width_at_word::<ellipsis::Ascii>(12),
+            "a very..."
+        );
+        assert_eq!(
+            value.trim_to_width_at_word::<ellipsis::Ascii>(9),
+            "a very..."
+        );
+    }
+
+    #[test]
+    fn wide_characters_are_measured_by_width() {
+        let value = "ａｂ ｃｄ ｅｆ";
+        assert_eq!(
+            value.trim_to_width_at_word::<ellipsis::Horizontal>(10),
+            "ａｂ ｃｄ…"
+        );
+        assert_eq!(
+            value.trim_to_length_at_word::<ellipsis::Horizontal>(16),
+            "ａｂ ｃｄ…"
+        );
+    }
+}
+
+/// test that the ellipsis can be placed at the start, middle, or end of a string.
+mod ellipses_can_be_placed {
+    use {super::*, shear::str::ellipsis::Placement, unicode_width::UnicodeWidthStr};
+
+    proptest! {
+        #[test]
+        fn placed_strings_fit(input in strategy::input_strategy(), placement in 0..3_u8) {
+            placed_strings_fit_(input, placement)
+        }
+    }
+
+    fn placed_strings_fit_(TestInput { value, length }: TestInput, placement: u8) {
+        let placement = match placement {
+            0 => Placement::Start,
+            1 => Placement::Middle,
+            _ => Placement::End,
+        };
+        let limited = value.trim_to_length_placed::<ellipsis::Ascii>(length, placement);
+        assert!(
+            limited.len() <= length,
+            "`{limited}` should fit in {length} bytes"
+        );
+        let limited = value.trim_to_width_placed::<ellipsis::Ascii>(length, placement);
+        assert!(
+            limited.width() <= length,
+            "`{limited}` should fit in {length} columns"
+        );
+    }
+
+    #[test]
+    fn short_strings_are_not_altered() {
+        for placement in [Placement::Start, Placement::Middle, Placement::End] {
+            assert_eq!(
+                "value".trim_to_length_placed::<ellipsis::Ascii>(5, placement),
+                "value"
+            );
+        }
+    }
+
+    #[test]
+    fn leftover_room_goes_to_the_prefix() {
+        let limited = "abcdefghij".trim_to_length_placed::<ellipsis::Ascii>(8, Placement::Middle);
+        assert_eq!(limited, "abc...ij");
+    }
+
+    #[test]
+    fn the_end_of_a_path_can_be_kept() {
+        let path = "~/code/shear/src/str.rs";
+        assert_eq!(
+            path.trim_to_width_keep_end::<ellipsis::Ascii>(14),
+            ".../src/str.rs"
+        );
+        assert_eq!(path.trim_to_length_keep_end::<ellipsis::Ascii>(99), path);
+    }
+
+    #[test]
+    fn multibyte_characters_are_not_split() {
+        let limited = "ａｂｃｄ".trim_to_length_placed::<ellipsis::Ascii>(10, Placement::Start);
+        assert_eq!(limited, "...ｃｄ");
+    }
+}
+
+/// test that strings that fit are borrowed, rather than copied.
+mod strs_that_fit_are_borrowed {
+    use {super::*, std::borrow::Cow};
+
+    proptest! {
+        #[test]
+        fn cows_match_trim_methods(input in strategy::input_strategy()) {
+            cows_match_trim_methods_(input)
+        }
+    }
+
+    fn cows_match_trim_methods_(TestInput { value, length }: TestInput) {
+        let limited = value.trim_to_length_cow::<ellipsis::Ascii>(length);
+        assert_eq!(limited, value.trim_to_length::<ellipsis::Ascii>(length));
+        assert_eq!(matches!(limited, Cow::Borrowed(_)), limited == value);
+
+        let limited = value.trim_to_width_cow::<ellipsis::Ascii>(length);
+        assert_eq!(limited, value.trim_to_width::<ellipsis::Ascii>(length));
+        assert_eq!(matches!(limited, Cow::Borrowed(_)), limited == value);
+    }
+
+    #[test]
+    fn wide_strings_that_fit_are_borrowed() {
+        let value = "ａｂｃ";
+        assert!(matches!(
+            value.trim_to_width_cow::<ellipsis::Ascii>(6),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(value.trim_to_width_cow::<ellipsis::Ascii>(5), "ａ...");
+    }
+}
+
+/// test that limited characters carry their original byte offsets.
+mod char_indices_can_be_limited {
+    use {
+        super::*,
+        shear::{
+            iter::Limited as _,
+            str::indices::{LengthIndices, WidthIndices},
+        },
+    };
+
+    proptest! {
+        #[test]
+        fn indices_match_trim_methods(input in strategy::input_strategy()) {
+            indices_match_trim_methods_(input)
+        }
+    }
+
+    fn indices_match_trim_methods_(TestInput { value, length }: TestInput) {
+        let by_length = LengthIndices::<ellipsis::Ascii>::new(&value)
+            .limited(length)
+            .collect::<Vec<_>>();
+        let by_width = WidthIndices::<ellipsis::Ascii>::new(&value)
+            .limited(length)
+            .collect::<Vec<_>>();
+
+        for (indexed, limited) in [
+            (by_length, value.trim_to_length::<ellipsis::Ascii>(length)),
+            (by_width, value.trim_to_width::<ellipsis::Ascii>(length)),
+        ] {
+            let chars = indexed.iter().map(|(_, c)| c).collect::<String>();
+            assert_eq!(chars, limited);
+            for (i, c) in indexed {
+                if let Some(i) = i {
+                    assert!(value[i..].starts_with(c), "offset {i} should hold {c:?}");
+                }
+            }
+        }
+    }
+}
+
+/// test that limited strings can be written to formatters.
+mod strs_can_be_written_to_formatters {
+    use {super::*, std::fmt::Write};
+
+    proptest! {
+        #[test]
+        fn written_strings_match_trim_to_length(input in strategy::input_strategy()) {
+            written_strings_match_trim_to_length_(input)
+        }
+    }
+
+    fn written_strings_match_trim_to_length_(TestInput { value, length }: TestInput) {
+        let mut out = String::new();
+        value
+            .write_limited::<ellipsis::Ascii, _>(length, &mut out)
+            .expect("writing to a string should not fail");
+        assert_eq!(out, value.trim_to_length::<ellipsis::Ascii>(length));
+    }
+
+    #[test]
+    fn tiny_budgets_write_the_ellipsis() {
+        let mut out = String::new();
+        write!(out, "[").unwrap();
+        "value"
+            .write_limited::<ellipsis::Ascii, _>(2, &mut out)
+            .unwrap();
+        write!(out, "]").unwrap();
+        assert_eq!(
+            out,
+            format!("[{}]", "value".trim_to_length::<ellipsis::Ascii>(2))
+        );
+    }
+}
+
+/// test that trimming reports whether a string was truncated.
+mod truncation_can_be_checked {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn checked_trims_match_trim_methods(input in strategy::input_strategy()) {
+            checked_trims_match_trim_methods_(input)
+        }
+    }
+
+    fn checked_trims_match_trim_methods_(TestInput { value, length }: TestInput) {
+        let (limited, truncated) = value.trim_to_length_checked::<ellipsis::Ascii>(length);
+        assert_eq!(limited, value.trim_to_length::<ellipsis::Ascii>(length));
+        assert_eq!(truncated, value.len() > length);
+
+        let (limited, truncated) = value.trim_to_width_checked::<ellipsis::Ascii>(length);
+        assert_eq!(limited, value.trim_to_width::<ellipsis::Ascii>(length));
+        assert_eq!(truncated, limited != value);
+    }
+
+    #[test]
+    fn normalized_line_breaks_are_not_truncation() {
+        let value = "one\r\ntwo\u{2028}three";
+        let (limited, truncated) = value.trim_to_height_checked::<ellipsis::Ascii>(3);
+        assert_eq!(
+            (limited.as_str(), truncated),
+            ("one\r\ntwo\r\nthree", false)
+        );
+        let (limited, truncated) = value.trim_to_height_checked::<ellipsis::Ascii>(2);
+        assert_eq!((limited.as_str(), truncated), ("one\r\n...", true));
+    }
+}
+
+/// test that trimming can report what was discarded.
+mod reports {
+    use {super::*, unicode_width::UnicodeWidthStr};
+
+    proptest! {
+        #[test]
+        fn reports_account_for_every_byte(input in strategy::input_strategy()) {
+            reports_account_for_every_byte_(input)
+        }
+    }
+
+    fn reports_account_for_every_byte_(TestInput { value, length }: TestInput) {
+        let report = value.trim_to_length_report::<ellipsis::Ascii>(length);
+        assert_eq!(
+            report.as_str(),
+            value.trim_to_length::<ellipsis::Ascii>(length)
+        );
+        assert_eq!(report.is_truncated(), value.len() > length);
+        if report.is_truncated() {
+            let kept = report.as_str().len() - "...".len();
+            assert_eq!(kept + report.dropped_bytes(), value.len());
+        }
+
+        let report = value.trim_to_width_report::<ellipsis::Ascii>(length);
+        assert_eq!(
+            report.as_str(),
+            value.trim_to_width::<ellipsis::Ascii>(length)
+        );
+        assert_eq!(report.width(), report.as_str().width());
+    }
+
+    proptest! {
+        #[test]
+        fn reports_account_for_paired_ellipses(input in strategy::input_strategy()) {
+            reports_account_for_paired_ellipses_(input)
+        }
+    }
+
+    fn reports_account_for_paired_ellipses_(TestInput { value, length }: TestInput) {
+        let report = value.trim_to_length_report::<ellipsis::Guillemets>(length);
+        assert_eq!(
+            report.as_str(),
+            value.trim_to_length::<ellipsis::Guillemets>(length)
+        );
+        if report.is_truncated() && length >= "«…»".len() {
+            let kept = report.as_str().len() - "«…»".len();
+            assert_eq!(&report.as_str()["«".len()..][..kept], &value[..kept]);
+            assert_eq!(kept + report.dropped_bytes(), value.len());
+            assert_eq!(report.dropped_chars(), value[kept..].chars().count());
+        }
+    }
+
+    #[test]
+    fn paired_ellipses_are_not_counted_as_kept() {
+        let report = "abcdefghijklmnop".trim_to_length_report::<ellipsis::Guillemets>(10);
+        assert_eq!(report.as_str(), "«abc…»");
+        assert_eq!(report.dropped_bytes(), 13);
+        assert_eq!(report.dropped_chars(), 13);
+    }
+
+    #[test]
+    fn multibyte_characters_are_not_split() {
+        let value = "ｈｅｌｌｏｗｏｒｌｄ";
+
+        let report = value.trim_to_length_report::<ellipsis::Guillemets>(13);
+        assert_eq!(report.as_str(), "«ｈｅ…»");
+        assert_eq!(report.dropped_bytes(), "ｌｌｏｗｏｒｌｄ".len());
+        assert_eq!(report.dropped_chars(), 8);
+
+        let report = value.trim_to_width_report::<ellipsis::Horizontal>(5);
+        assert_eq!(report.as_str(), "ｈｅ…");
+        assert_eq!(report.dropped_bytes(), "ｌｌｏｗｏｒｌｄ".len());
+        assert_eq!(report.dropped_chars(), 8);
+
+        let report = value.trim_to_width_report::<ellipsis::Guillemets>(7);
+        assert_eq!(report.as_str(), "«ｈｅ…»");
+        assert_eq!(report.dropped_chars(), 8);
+    }
+
+    #[test]
+    fn untouched_strings_report_nothing() {
+        let report = "one\ntwo".trim_to_height_report::<ellipsis::Ascii>(2);
+        assert_eq!(report.as_str(), "one\ntwo");
+        assert!(!report.is_truncated());
+        assert_eq!(
+            (
+                report.dropped_bytes(),
+                report.dropped_chars(),
+                report.dropped_lines()
+            ),
+            (0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn height_reports_count_dropped_lines() {
+        let report = "one\ntwo\nthree\nfour".trim_to_height_report::<ellipsis::Ascii>(2);
+        assert_eq!(report.as_str(), "one\n...");
+        assert_eq!(report.dropped_lines(), 3);
+        assert_eq!(report.dropped_bytes(), "two\nthree\nfour".len());
+        assert_eq!(report.into_string(), "one\n...");
+    }
+
+    #[test]
+    fn length_reports_count_whole_dropped_lines() {
+        let report = "one\ntwo\nthree".trim_to_length_report::<ellipsis::Horizontal>(6);
+        assert_eq!(report.as_str(), "one…");
+        assert_eq!(report.dropped_lines(), 2);
+        assert_eq!(report.dropped_chars(), "\ntwo\nthree".chars().count());
+    }
+}
+
+/// test that the point at which a string would be cut can be found without trimming it.
+mod trim_points {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn trim_points_match_trimmed_strings(input in strategy::input_strategy()) {
+            trim_points_match_trimmed_strings_(input)
+        }
+    }
+
+    fn trim_points_match_trimmed_strings_(TestInput { value, length }: TestInput) {
+        /// helper fn: reassembles a trimmed string from its trim point.
+        fn reassemble(value: &str, cut: Option<usize>) -> String {
+            match cut {
+                Some(cut) => format!("{}...", &value[..cut]),
+                None => value.to_owned(),
+            }
+        }
+
+        let cut = value.trim_point_for_length::<ellipsis::Ascii>(length);
+        assert_eq!(
+            reassemble(&value, cut),
+            value.trim_to_length::<ellipsis::Ascii>(length)
+        );
+
+        let cut = value.trim_point_for_width::<ellipsis::Ascii>(length);
+        assert_eq!(
+            reassemble(&value, cut),
+            value.trim_to_width::<ellipsis::Ascii>(length)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn strings_have_a_trim_point_only_if_they_are_trimmed(
+            value in "[a-zａ-ｚ]{1,16}",
+        ) {
+            strings_have_a_trim_point_only_if_they_are_trimmed_(value)
+        }
+    }
+
+    fn strings_have_a_trim_point_only_if_they_are_trimmed_(value: String) {
+        // check every width, including those with room for nothing but the ellipsis.
+        for width in 0..=shear::measure::display_width(&value) + 1 {
+            let cut = value.trim_point_for_width::<ellipsis::Ascii>(width);
+            let limited = value.trim_to_width::<ellipsis::Ascii>(width);
+            assert_eq!(cut.is_none(), limited == value, "width {width}");
+        }
+        for length in 0..=value.len() + 1 {
+            let cut = value.trim_point_for_length::<ellipsis::Ascii>(length);
+            let limited = value.trim_to_length::<ellipsis::Ascii>(length);
+            assert_eq!(cut.is_none(), limited == value, "length {length}");
+        }
+    }
+
+    #[test]
+    fn strings_that_fit_within_the_ellipsis_have_no_trim_point() {
+        assert_eq!("abc".trim_point_for_width::<ellipsis::Ascii>(3), None);
+        assert_eq!("abc".trim_point_for_length::<ellipsis::Ascii>(3), None);
+        assert_eq!("abcd".trim_point_for_width::<ellipsis::Ascii>(3), Some(0));
+        assert_eq!("ｈ".trim_point_for_width::<ellipsis::Ascii>(2), None);
+        assert_eq!("ｈ".trim_point_for_width::<ellipsis::Ascii>(1), Some(0));
+    }
+
+    #[test]
+    fn trim_points_fall_on_character_boundaries() {
+        let value = "Ｈｅｌｌｏ, ｗｏｒｌｄ!";
+        assert_eq!(value.trim_point_for_length::<ellipsis::Ascii>(8), Some(3));
+        assert_eq!(value.trim_point_for_width::<ellipsis::Ascii>(8), Some(6));
+        assert_eq!(value.trim_point_for_width::<ellipsis::Ascii>(23), None);
+    }
+}
+
+/// test that kept units can be inspected while trimming.
+mod inspected {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn inspected_units_cover_the_kept_prefix(input in strategy::input_strategy()) {
+            inspected_units_cover_the_kept_prefix_(input)
+        }
+    }
+
+    fn inspected_units_cover_the_kept_prefix_(TestInput { value, length }: TestInput) {
+        let mut kept = String::new();
+        let limited = value.trim_to_width_inspect::<ellipsis::Ascii>(length, |unit| {
+            assert_eq!(&value[unit.offset..][..unit.unit.len()], unit.unit);
+            kept.push_str(unit.unit);
+        });
+        assert_eq!(limited, value.trim_to_width::<ellipsis::Ascii>(length));
+        assert!(limited.starts_with(&kept));
+
+        let mut position = 0;
+        let limited = value.trim_to_length_inspect::<ellipsis::Ascii>(length, |unit| {
+            assert_eq!(unit.position, position);
+            position += unit.size;
+        });
+        assert_eq!(limited, value.trim_to_length::<ellipsis::Ascii>(length));
+    }
+
+    #[test]
+    fn wide_characters_are_measured() {
+        let mut units = Vec::new();
+        let limited =
+            "Ｈｅｌｌｏ".trim_to_width_inspect::<ellipsis::Ascii>(8, |unit| units.push(unit));
+        assert_eq!(limited, "Ｈｅ...");
+        assert_eq!(
+            units
+                .iter()
+                .map(|u| (u.offset, u.position, u.size))
+                .collect::<Vec<_>>(),
+            [(0, 0, 2), (3, 2, 2)]
+        );
+    }
+
+    #[test]
+    fn lines_are_reported() {
+        let mut units = Vec::new();
+        let limited = "one\r\ntwo\nthree".trim_to_height_inspect::<ellipsis::Ascii>(2, |unit| {
+            units.push((unit.unit, unit.offset, unit.position))
+        });
+        assert_eq!(limited, "one\r\n...");
+        assert_eq!(units, [("one", 0, 0)]);
+    }
+}
+
+/// test that strings can be split into a kept prefix and its overflow.
+mod strs_can_be_split_at_a_limit {
+    use {super::*, unicode_width::UnicodeWidthStr};
+
+    proptest! {
+        #[test]
+        fn prefixes_fit_and_overflows_do_not(input in strategy::input_strategy()) {
+            prefixes_fit_and_overflows_do_not_(input)
+        }
+    }
+
+    fn prefixes_fit_and_overflows_do_not_(TestInput { value, length }: TestInput) {
+        let (kept, overflow) = value.split_at_length(length);
+        assert_eq!(format!("{kept}{overflow}"), value);
+        assert!(kept.len() <= length);
+        if let Some(c) = overflow.chars().next() {
+            assert!(kept.len() + c.len_utf8() > length);
+        }
+
+        let (kept, overflow) = value.split_at_width(length);
+        assert_eq!(format!("{kept}{overflow}"), value);
+        assert!(kept.width() <= length);
+    }
+
+    #[test]
+    fn combining_marks_stay_with_their_base() {
+        assert_eq!(
+            "e\u{301}e\u{301}".split_at_width(1),
+            ("e\u{301}", "e\u{301}")
+        );
+        assert_eq!(
+            "e\u{301}e\u{301}".split_at_length(2),
+            ("e", "\u{301}e\u{301}")
+        );
+    }
+
+    #[test]
+    fn wide_characters_are_not_split() {
+        assert_eq!("ａｂ".split_at_width(3), ("ａ", "ｂ"));
+        assert_eq!("ａｂ".split_at_length(4), ("ａ", "ｂ"));
+        assert_eq!("ａｂ".split_at_width(0), ("", "ａｂ"));
+    }
+}
+
+/// test that strings can be limited with a paired ellipsis.
+mod paired_ellipses {
+    use {
+        super::*,
+        shear::str::ellipsis::{Guillemets, Placement},
+        unicode_width::UnicodeWidthStr,
+    };
+
+    /// a paired ellipsis that wraps a truncated string in square brackets.
+    struct Bracketed;
+
+    impl ellipsis::Ellipsis for Bracketed {
+        fn ellipsis() -> &'static str {
+            " […]"
+        }
+
+        fn leading() -> &'static str {
+            "["
+        }
+
+        fn trailing() -> &'static str {
+            "]"
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn paired_ellipses_fit_the_budget(input in strategy::input_strategy()) {
+            paired_ellipses_fit_the_budget_(input)
+        }
+    }
+
+    fn paired_ellipses_fit_the_budget_(TestInput { value, length }: TestInput) {
+        // each part of the paired ellipsis must fit, along with at least one character.
+        if length < 8 {
+            return;
+        }
+
+        let limited = value.trim_to_length::<Guillemets>(length);
+        assert!(limited.len() <= length);
+        if value.len() > length {
+            assert!(limited.starts_with('«') && limited.ends_with("…»"));
+        } else {
+            assert_eq!(limited, value);
+        }
+
+        let limited = value.trim_to_width_placed::<Guillemets>(length, Placement::Start);
+        assert!(limited.width() <= length);
+    }
+
+    #[test]
+    fn paired_ellipses_wrap_truncated_strings() {
+        let s = "a very long string value";
+        assert_eq!(s.trim_to_length::<Bracketed>(14), "[a very […]]");
+        assert_eq!(
+            s.trim_to_length_placed::<Bracketed>(16, Placement::Start),
+            "[ […]ng value]"
+        );
+    }
+}
+
+/// test that strings can be limited with an ellipsis generated from the omitted content.
+mod dynamic_ellipses {
+    use {super::*, unicode_width::UnicodeWidthStr};
+
+    /// helper fn: returns a marker counting the dropped characters.
+    fn dropped(n: usize) -> String {
+        format!("[+{n}]")
+    }
+
+    proptest! {
+        #[test]
+        fn dynamic_ellipses_count_dropped_characters(input in strategy::input_strategy()) {
+            dynamic_ellipses_count_dropped_characters_(input)
+        }
+    }
+
+    fn dynamic_ellipses_count_dropped_characters_(TestInput { value, length }: TestInput) {
+        let limited = value.trim_to_length_dynamic(length, &dropped);
+        if value.len() <= length {
+            assert_eq!(limited, value);
+            return;
+        }
+
+        let (kept, marker) = limited.split_at(limited.rfind('[').unwrap());
+        assert!(value.starts_with(kept));
+        assert_eq!(marker, dropped(value[kept.len()..].chars().count()));
+        if !kept.is_empty() {
+            assert!(limited.len() <= length);
+        }
+
+        let limited = value.trim_to_width_dynamic(length, &dropped);
+        if !limited.starts_with('[') {
+            assert!(limited.width() <= length);
+        }
+    }
+
+    #[test]
+    fn markers_that_grow_are_given_room() {
+        // dropping 9 characters needs a 4 byte marker, but dropping 10 needs a 5 byte marker.
+        let value = "abcdefghijklmnop";
+        assert_eq!(value.trim_to_length_dynamic(11, &dropped), "abcdefg[+9]");
+        assert_eq!(value.trim_to_length_dynamic(10, &dropped), "abcde[+11]");
+    }
+
+    #[test]
+    fn markers_may_count_lines() {
+        let marker = |n| format!("({n} more)");
+        assert_eq!("a\nb\nc".trim_to_height_dynamic(3, &marker), "a\nb\nc");
+        assert_eq!("a\nb\nc".trim_to_height_dynamic(2, &marker), "a\n(2 more)");
+        assert_eq!("a\nb\nc".trim_to_height_dynamic(0, &marker), "(3 more)");
+        assert_eq!(
+            "a\r\nb\r\nc".trim_to_height_dynamic(2, &marker),
+            "a\r\n(2 more)"
+        );
+    }
+}
+
+/// test that the content of a string can be limited, with the ellipsis appended on top.
+mod content_budgets {
+    use {super::*, unicode_width::UnicodeWidthStr};
+
+    proptest! {
+        #[test]
+        fn content_fits_the_budget(input in strategy::input_strategy()) {
+            content_fits_the_budget_(input)
+        }
+    }
+
+    fn content_fits_the_budget_(TestInput { value, length }: TestInput) {
+        let limited = value.trim_content_to_length::<ellipsis::Ascii>(length);
+        match limited.strip_suffix("...") {
+            Some(content) if value.len() > length => {
+                assert!(content.len() <= length);
+                assert!(value.starts_with(content));
+            }
+            _ => assert_eq!(limited, value),
+        }
+
+        let limited = value.trim_content_to_width::<ellipsis::Ascii>(length);
+        match limited.strip_suffix("...") {
+            Some(content) if value.width() > length => assert!(content.width() <= length),
+            _ => assert_eq!(limited, value),
+        }
+    }
+
+    #[test]
+    fn ellipses_are_appended_on_top() {
+        assert_eq!("abcdef".trim_content_to_length::<ellipsis::Ascii>(0), "...");
+        assert_eq!(
+            "abcdef".trim_content_to_length::<ellipsis::Ascii>(5),
+            "abcde..."
+        );
+        assert_eq!(
+            "abcdef".trim_content_to_length::<ellipsis::Ascii>(6),
+            "abcdef"
+        );
+        assert_eq!(
+            "ａｂｃ".trim_content_to_width::<ellipsis::Horizontal>(3),
+            "ａ…"
+        );
+        assert_eq!(
+            "ａｂｃ".trim_content_to_length::<ellipsis::Horizontal>(4),
+            "ａ…"
+        );
+    }
+}
+
+/// test that measured strings can be limited repeatedly.
+mod measured_strs {
+    use {super::*, shear::str::MeasuredStr, unicode_width::UnicodeWidthStr};
+
+    proptest! {
+        #[test]
+        fn measured_strs_match_trim_methods(input in strategy::input_strategy()) {
+            measured_strs_match_trim_methods_(input)
+        }
+    }
+
+    fn measured_strs_match_trim_methods_(TestInput { value, length }: TestInput) {
+        let measured = MeasuredStr::new(&value);
+        assert_eq!(measured.width(), value.width());
+        assert_eq!(
+            measured.split_at_width(length),
+            value.split_at_width(length)
+        );
+
+        // budgets no wider than the ellipsis always yield the ellipsis, see `trim_to_width()`.
+        if length > 3 {
+            assert_eq!(
+                measured.trim_to_width::<ellipsis::Ascii>(length),
+                value.trim_to_width::<ellipsis::Ascii>(length)
+            );
+        }
+    }
+
+    #[test]
+    fn wide_characters_are_measured() {
+        let measured = MeasuredStr::new("Ｈｅｌｌｏ, ｗｏｒｌｄ!");
+        assert_eq!(measured.width(), 23);
+        assert_eq!(measured.split_at_width(5), ("Ｈｅ", "ｌｌｏ, ｗｏｒｌｄ!"));
+        assert_eq!(measured.trim_to_width::<ellipsis::Horizontal>(6), "Ｈｅ…");
+        assert_eq!(measured.trim_to_width::<ellipsis::Horizontal>(0), "…");
+    }
+
+    #[test]
+    fn empty_strs_are_measured() {
+        let measured = MeasuredStr::new("");
+        assert_eq!(measured.width(), 0);
+        assert_eq!(measured.split_at_width(0), ("", ""));
+        assert_eq!(measured.as_str(), "");
+    }
+}
+
+/// test that strings can be limited by length, width, and height at once.
+mod limits {
+    use {
+        super::*,
+        shear::str::{ellipsis::Horizontal, Limits},
+        unicode_width::UnicodeWidthStr,
+    };
+
+    proptest! {
+        #[test]
+        fn single_limits_match_trim_methods(input in strategy::input_strategy()) {
+            single_limits_match_trim_methods_(input)
+        }
+
+        #[test]
+        fn combined_limits_are_satisfied(
+            value in strategy::value_strategy_non_empty(),
+            len in 4..64_usize,
+            width in 4..16_usize,
+            height in 1..8_usize,
+        )
+        {
+            combined_limits_are_satisfied_(value, len, width, height)
+        }
+    }
+
+    fn single_limits_match_trim_methods_(TestInput { value, length }: TestInput) {
+        assert_eq!(
+            Limits::new().max_len(length).apply(&value),
+            value.trim_to_length::<ellipsis::Ascii>(length)
+        );
+        assert_eq!(
+            Limits::new().max_height(length).apply(&value),
+            value.trim_to_height::<ellipsis::Ascii>(length)
+        );
+    }
+
+    fn combined_limits_are_satisfied_(value: String, len: usize, width: usize, height: usize) {
+        let limits = Limits::new()
+            .max_len(len)
+            .max_width(width)
+            .max_height(height);
+        let limited = limits.apply(&value);
+        assert!(limited.len() <= len);
+        assert!(limited.lines().count() <= height);
+        for line in limited.lines() {
+            assert!(
+                line.width() <= width,
+                "`{line}` should fit in {width} columns"
+            );
+        }
+        assert!(!limited.contains("......"), "ellipses should not stack");
+    }
+
+    #[test]
+    fn lines_are_limited_by_width() {
+        let limits = Limits::new().max_width(5).ellipsis::<Horizontal>();
+        assert_eq!(
+            limits.apply("a short\r\nline\nanother"),
+            "a sh…\r\nline\r\nanot…"
+        );
+    }
+
+    #[test]
+    fn only_one_ellipsis_is_placed() {
+        let limits = Limits::new().max_len(11).max_height(2);
+        assert_eq!(limits.apply("one\ntwo\nthree"), "one\n...");
+        assert_eq!(limits.apply("one two three\nfour"), "one two ...");
+    }
+
+    #[test]
+    fn ellipses_can_be_appended_to_the_last_line() {
+        let limits = Limits::new().max_height(2).inline();
+        assert_eq!(limits.apply("one\ntwo"), "one\ntwo");
+        assert_eq!(limits.apply("one\ntwo\nthree"), "one\ntwo...");
+
+        let limits = limits.max_width(6);
+        assert_eq!(limits.apply("one\ntwo\nthree"), "one\ntwo...");
+        assert_eq!(limits.apply("one\nthree\nfour"), "one\nthr...");
+        assert_eq!(limits.max_height(0).apply("one"), "...");
+    }
+
+    #[test]
+    fn omitted_lines_can_use_another_ellipsis() {
+        use shear::str::ellipsis::Vertical;
+
+        let limits = Limits::new()
+            .max_width(5)
+            .max_height(2)
+            .vertical::<Vertical>();
+        assert_eq!(limits.apply("one\ntwo\nthree"), "one\n⋮");
+        assert_eq!(limits.apply("a long line\ntwo"), "a ...\ntwo");
+        assert_eq!(limits.inline().apply("one\nlonger\nthree"), "one\nlong⋮");
+
+        let limits = Limits::new()
+            .max_len(7)
+            .max_height(2)
+            .vertical::<Vertical>();
+        assert_eq!(limits.apply("one\ntwo\nthree"), "one\n⋮");
+        assert_eq!(limits.apply("one two three"), "one ...");
+    }
+
+    #[test]
+    fn no_limits_leave_strings_unaltered() {
+        assert_eq!(Limits::default().apply("one\r\ntwo"), "one\r\ntwo");
+        assert_eq!(Limits::new().max_height(0).apply("one"), "...");
+    }
+}
+
+/// test that strings can be limited to a box.
+mod boxes {
+    use {super::*, unicode_width::UnicodeWidthStr};
+
+    proptest! {
+        #[test]
+        fn boxed_strings_fit(
+            value in strategy::value_strategy_non_empty(),
+            width in 4..32_usize,
+            height in 1..8_usize,
+        )
+        {
+            boxed_strings_fit_(value, width, height)
+        }
+    }
+
+    fn boxed_strings_fit_(value: String, width: usize, height: usize) {
+        let limited = value.trim_to_box::<ellipsis::Ascii>(width, height);
+        assert!(limited.lines().count() <= height);
+        for line in limited.lines() {
+            assert!(
+                line.width() <= width,
+                "`{line}` should fit in {width} columns"
+            );
+        }
+    }
+
+    #[test]
+    fn each_line_is_limited() {
+        let s = "ａｂｃｄｅ\nfghij\nk";
+        assert_eq!(s.trim_to_box::<ellipsis::Ascii>(5, 3), "ａ...\nfghij\nk");
+        assert_eq!(s.trim_to_box::<ellipsis::Ascii>(5, 2), "ａ...\n...");
+    }
+}
+
+/// test that possibly invalid utf-8 can be limited without a lossy intermediate string.
+mod lossy_bytes {
+    use {
+        super::*,
+        proptest::{collection::vec, prelude::any},
+        shear::str::{ellipsis::Horizontal, lossy},
+    };
+
+    proptest! {
+        #[test]
+        fn lossy_length_matches_from_utf8_lossy(
+            bytes in vec(any::<u8>(), 0..64),
+            length in 0..64_usize,
+        )
+        {
+            let lossy = String::from_utf8_lossy(&bytes);
+            assert_eq!(
+                lossy::trim_to_length::<ellipsis::Ascii>(&bytes, length),
+                lossy.trim_to_length::<ellipsis::Ascii>(length)
+            );
+        }
+
+        #[test]
+        fn lossy_width_matches_from_utf8_lossy(
+            bytes in vec(any::<u8>(), 0..64),
+            width in 0..64_usize,
+        )
+        {
+            let lossy = String::from_utf8_lossy(&bytes);
+            assert_eq!(
+                lossy::trim_to_width::<Horizontal>(&bytes, width),
+                lossy.trim_to_width::<Horizontal>(width)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_sequences_are_replaced() {
+        let bytes = b"ab\xC3cd\xE2\x82ef";
+        assert_eq!(
+            lossy::trim_to_length::<ellipsis::Ascii>(bytes, 32),
+            "ab\u{FFFD}cd\u{FFFD}ef"
+        );
+        assert_eq!(lossy::trim_to_width::<Horizontal>(bytes, 4), "ab\u{FFFD}…");
+    }
+}
+
+/// test that emoji sequences are never split when limiting by width.
+mod emoji_sequences {
+    use {
+        super::*,
+        shear::{measure::display_width, str::ellipsis::Horizontal},
+    };
+
+    /// a family, built from four emoji joined by zero-width joiners.
+    const FAMILY: &str = "👨\u{200D}👩\u{200D}👧\u{200D}👦";
+
+    #[test]
+    fn zwj_sequences_are_not_split() {
+        let value = format!("ab{FAMILY}cd");
+        for width in 0..12 {
+            let limited = value.trim_to_width::<Horizontal>(width);
+            let kept = limited.strip_suffix('…').unwrap_or(&limited);
+            assert!(
+                !kept.contains('👨') || kept.contains(FAMILY),
+                "`{limited}` should not split the family at width {width}"
+            );
+            assert!(!kept.ends_with('\u{200D}'));
+        }
+        assert_eq!(value.trim_to_width::<Horizontal>(5), "ab…");
+    }
+
+    #[test]
+    fn variation_selectors_stay_with_their_emoji() {
+        let value = "a\u{2764}\u{FE0F}b\u{2764}\u{FE0F}c";
+        let limited = value.trim_to_width::<Horizontal>(3);
+        assert_eq!(limited, "a\u{2764}\u{FE0F}…");
+        assert!(display_width(&limited) <= 3);
+    }
+
+    #[test]
+    fn trim_points_fall_on_cluster_boundaries() {
+        let value = format!("ab{FAMILY}cd");
+        let cut = value.trim_point_for_width::<Horizontal>(5);
+        assert_eq!(cut, Some(2));
+    }
+
+    #[test]
+    fn ellipses_placed_before_zwj_sequences_stay_within_the_width() {
+        let value = "ab👨\u{200D}👩\u{200D}👧";
+        assert_eq!(value.trim_to_width_keep_end::<Horizontal>(4), "…");
+        assert_eq!(value.split_at_width(4), ("ab", "👨\u{200D}👩\u{200D}👧"));
+    }
+
+    proptest! {
+        #[test]
+        fn zwj_sequences_are_measured_alike_by_every_width_method(
+            value in "[ab]{0,3}(👨\u{200D}👩\u{200D}👧)?[cd]{0,3}",
+            width in 1..12_usize,
+        ) {
+            zwj_sequences_are_measured_alike_by_every_width_method_(value, width)
+        }
+    }
+
+    fn zwj_sequences_are_measured_alike_by_every_width_method_(value: String, width: usize) {
+        use shear::str::ellipsis::Placement;
+
+        let limited = [
+            value.trim_to_width::<Horizontal>(width),
+            value.trim_to_width_keep_end::<Horizontal>(width),
+            value.trim_to_width_placed::<Horizontal>(width, Placement::Start),
+            value.trim_to_width_placed::<Horizontal>(width, Placement::Middle),
+            value.trim_to_width_centered::<Horizontal>(width),
+            value.scroll_to_width::<Horizontal>(1, width).0,
+            value.trim_to_width_within::<Horizontal>(1..=width),
+            value.split_at_width(width).0.to_owned(),
+        ];
+        for limited in limited {
+            assert!(
+                display_width(&limited) <= width,
+                "`{limited}` should fit within {width} columns"
+            );
+        }
+    }
+}
+
+/// test that combining marks are never separated from their base character.
+mod combining_marks {
+    use {super::*, shear::str::ellipsis::Horizontal};
+
+    #[test]
+    fn combining_marks_are_not_dropped_when_limiting_by_length() {
+        // "e" and U+0301 COMBINING ACUTE ACCENT are 3 bytes long together.
+        let value = "cafe\u{301} au lait";
+        assert_eq!(value.trim_to_length::<ellipsis::Ascii>(8), "caf...");
+        assert_eq!(value.trim_to_length::<ellipsis::Ascii>(9), "cafe\u{301}...");
+        assert_eq!(value.trim_point_for_length::<ellipsis::Ascii>(8), Some(3));
+    }
+
+    #[test]
+    fn combining_marks_are_not_dropped_when_limiting_by_width() {
+        let value = "cafe\u{301} au lait";
+        assert_eq!(value.trim_to_width::<Horizontal>(5), "cafe\u{301}…");
+        assert_eq!(value.trim_to_width::<Horizontal>(4), "caf…");
+    }
+
+    #[test]
+    fn stacked_combining_marks_are_kept_together() {
+        let value = "a\u{300}\u{316}\u{35c}bc";
+        for length in 0..value.len() {
+            let limited = value.trim_to_length::<Horizontal>(length);
+            let kept = limited.strip_suffix('…').unwrap_or(&limited);
+            assert!(
+                kept.is_empty() || kept.starts_with("a\u{300}\u{316}\u{35c}"),
+                "`{limited}` should not strand a combining mark at length {length}"
+            );
+        }
+    }
+}
+
+/// test that invisible zero-width characters can be stripped, and never precede an ellipsis.
+mod zero_width_characters {
+    use {super::*, shear::str::ellipsis::Horizontal};
+
+    #[test]
+    fn zero_width_characters_can_be_stripped() {
+        assert_eq!("a\u{200B}b\u{2060}c\u{FEFF}".strip_zero_width(), "abc");
+        assert_eq!("\u{200D}a\u{200D} b\u{200C}".strip_zero_width(), "a b");
+        assert!(matches!(
+            "plain".strip_zero_width(),
+            std::borrow::Cow::Borrowed("plain")
+        ));
+    }
+
+    #[test]
+    fn joiners_between_visible_characters_are_kept() {
+        let coder = "👩\u{200D}💻";
+        assert_eq!(coder.strip_zero_width(), coder);
+        assert_eq!("ab\u{200C}cd".strip_zero_width(), "ab\u{200C}cd");
+    }
+
+    #[test]
+    fn zero_width_characters_never_precede_an_ellipsis() {
+        let value = "ab\u{200B}\u{FEFF}\u{2060}cd ef\u{200B}gh";
+        for budget in 0..value.len() {
+            for limited in [
+                value.trim_to_length::<Horizontal>(budget),
+                value.trim_to_width::<Horizontal>(budget),
+            ] {
+                let kept = limited.strip_suffix('…').unwrap_or(&limited);
+                assert!(
+                    !kept.ends_with(['\u{200B}', '\u{FEFF}', '\u{2060}']),
+                    "`{limited:?}` should not end with a zero-width character"
+                );
+            }
+        }
+    }
+}
+
+/// test that punctuation is not orphaned before an ellipsis.
+mod orphaned_punctuation {
+    use {
+        super::*,
+        shear::str::ellipsis::{Guillemets, Horizontal},
+    };
+
+    proptest! {
+        #[test]
+        fn strings_without_orphans_fit(input in strategy::input_strategy()) {
+            let TestInput { value, length } = input;
+            let limited = value.trim_to_length_without_orphans::<ellipsis::Ascii>(length);
+            assert!(limited.len() <= length);
+            assert_eq!(limited, value.trim_to_length::<ellipsis::Ascii>(length));
+        }
+    }
+
+    #[test]
+    fn opening_brackets_and_dashes_are_not_orphaned() {
+        let cases = [
+            ("call foo(bar, baz)", 10, "call foo…"),
+            ("before — after", 9, "before…"),
+            ("quote “this”", 8, "quote…"),
+            ("path/to/file", 9, "path/to…"),
+        ];
+        for (value, width, expected) in cases {
+            assert_eq!(
+                value.trim_to_width_without_orphans::<Horizontal>(width),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn strings_that_fit_are_not_altered() {
+        let value = "ends with (";
+        assert_eq!(
+            value.trim_to_length_without_orphans::<Horizontal>(16),
+            value
+        );
+    }
+
+    #[test]
+    fn paired_ellipses_are_preserved() {
+        let limited = "see (the appendix)".trim_to_width_without_orphans::<Guillemets>(8);
+        assert_eq!(limited, "«see…»");
+    }
+}
+
+/// test that trimmed strings do not end inside of a bracket or quote.
+mod balanced_brackets {
+    use {super::*, shear::str::ellipsis::Horizontal};
+
+    proptest! {
+        #[test]
+        fn balanced_strings_fit(input in strategy::input_strategy()) {
+            let TestInput { value, length } = input;
+            let limited = value.trim_to_length_balanced::<ellipsis::Ascii>(length);
+            assert!(limited.len() <= length);
+        }
+    }
+
+    #[test]
+    fn cuts_inside_brackets_are_moved_back() {
+        let value = "f(a) g(b, c)";
+        assert_eq!(value.trim_to_width_balanced::<Horizontal>(10), "f(a) g…");
+        assert_eq!(value.trim_to_width_balanced::<Horizontal>(5), "f(a)…");
+    }
+
+    #[test]
+    fn cuts_inside_quotes_are_moved_back() {
+        let value = r#"say "hello, world" twice"#;
+        assert_eq!(value.trim_to_width_balanced::<Horizontal>(14), "say …");
+    }
+
+    #[test]
+    fn escaped_quotes_do_not_end_a_quote() {
+        let value = r#"a "b\"c" d e f"#;
+        assert_eq!(
+            value.trim_to_width_balanced::<Horizontal>(12),
+            r#"a "b\"c" d …"#
+        );
+        assert_eq!(value.trim_to_width_balanced::<Horizontal>(7), "a …");
+    }
+
+    #[test]
+    fn apostrophes_are_not_quotes() {
+        let value = "don't stop (me) now";
+        assert_eq!(
+            value.trim_to_width_balanced::<Horizontal>(14),
+            "don't stop …"
+        );
+    }
+
+    #[test]
+    fn unbalanced_strings_fall_back_to_the_last_unquoted_position() {
+        let value = r#"{"key": "a long value"}"#;
+        assert_eq!(
+            value.trim_to_width_balanced::<Horizontal>(16),
+            r#"{"key": …"#
+        );
+    }
+}
+
+/// test that strings can be cut at character boundaries without allocating.
+mod floor_to_boundary {
+    use {super::*, shear::str::floor_to_boundary};
+
+    proptest! {
+        #[test]
+        fn prefixes_are_the_longest_that_fit(value in ".{0,32}", length in 0..128_usize)
+        {
+            let prefix = floor_to_boundary(&value, length);
+            assert!(prefix.len() <= length);
+            assert!(value.starts_with(prefix));
+            if let Some(c) = value[prefix.len()..].chars().next() {
+                assert!(prefix.len() + c.len_utf8() > length);
+            }
+        }
+    }
+
+    #[test]
+    fn multibyte_characters_are_not_split() {
+        assert_eq!(floor_to_boundary("Ｈｅｌｌｏ", 7), "Ｈｅ");
+        assert_eq!(floor_to_boundary("Ｈｅｌｌｏ", 2), "");
+        assert_eq!(floor_to_boundary("", 4), "");
+    }
+
+    #[test]
+    fn prefixes_are_borrowed() {
+        let value = "hello, world";
+        let prefix = floor_to_boundary(value, 5);
+        assert_eq!(prefix.as_ptr(), value.as_ptr());
+    }
+}
+
+/// test that every method limiting strings by width agrees with `trim_to_width()`.
+mod width_methods_agree {
+    use {
+        super::*,
+        shear::str::{lossy, trim_measured},
+    };
+
+    proptest! {
+        #[test]
+        fn width_methods_match_trim_to_width(
+            value in "([a-zａ-ｚ\u{301}]|👨\u{200D}👩){0,8}",
+            width in 0..12_usize,
+        ) {
+            width_methods_match_trim_to_width_(value, width)
+        }
+    }
+
+    fn width_methods_match_trim_to_width_(value: String, width: usize) {
+        use shear::measure::display_width;
+
+        let limited = value.trim_to_width::<ellipsis::Ascii>(width);
+
+        let mut extended = String::new();
+        value.extend_trimmed_to_width::<ellipsis::Ascii>(&mut extended, width);
+        assert_eq!(extended, limited, "extend_trimmed_to_width()");
+
+        let marked = value.trim_to_width_with(&ellipsis::Ascii, width);
+        assert_eq!(marked, limited, "trim_to_width_with()");
+
+        let chars = value
+            .chars()
+            .map(|c| (c, display_width(c.encode_utf8(&mut [0; 4]))));
+        let measured = trim_measured::<ellipsis::Ascii>(chars, width);
+        assert_eq!(measured, limited, "trim_measured()");
+
+        let inspected = value.trim_to_width_inspect::<ellipsis::Ascii>(width, drop);
+        assert_eq!(inspected, limited, "trim_to_width_inspect()");
+
+        let lossy = lossy::trim_to_width::<ellipsis::Ascii>(value.as_bytes(), width);
+        assert_eq!(lossy, limited, "lossy::trim_to_width()");
+
+        let lines = shear::io::TrimLines::<_, ellipsis::Ascii>::new(value.as_bytes(), 1)
+            .width(width)
+            .map(|line| line.expect("reading from a slice cannot fail"))
+            .collect::<Vec<_>>();
+        let expected = (!value.is_empty()).then_some(&limited);
+        assert_eq!(lines.first(), expected, "io::TrimLines::width()");
+
+        let point = value.trim_point_for_width::<ellipsis::Ascii>(width);
+        assert_eq!(point.is_none(), limited == value, "trim_point_for_width()");
+    }
+
+    #[test]
+    fn strings_that_fit_within_the_ellipsis_are_not_altered() {
+        let value = "abc";
+        assert_eq!(value.trim_to_width::<ellipsis::Ascii>(3), "abc");
+        assert_eq!(value.trim_to_width_with(&ellipsis::Ascii, 3), "abc");
+        assert_eq!(value.trim_point_for_width::<ellipsis::Ascii>(3), None);
+        assert_eq!(value.trim_point_for_width::<ellipsis::Ascii>(2), Some(0));
+
+        let mut extended = String::new();
+        value.extend_trimmed_to_width::<ellipsis::Ascii>(&mut extended, 3);
+        assert_eq!(extended, "abc");
+
+        let lines = shear::io::TrimLines::<_, ellipsis::Ascii>::new("abc\nabcd".as_bytes(), 2)
+            .width(3)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(lines, ["abc", "..."]);
+    }
+}
+
+/// test that every method limiting by length cuts a string at the same cluster boundary.
+mod length_methods_agree {
+    use {super::*, shear::str::Limits, std::fmt::Write};
+
+    proptest! {
+        #[test]
+        fn length_methods_match_trim_to_length(
+            value in "[a-e\u{301}\u{200B}\u{200D}]{0,12}",
+            length in 0..16_usize,
+        ) {
+            length_methods_match_trim_to_length_(value, length)
+        }
+    }
+
+    fn length_methods_match_trim_to_length_(value: String, length: usize) {
+        type E = ellipsis::Horizontal;
+
+        let limited = value.trim_to_length::<E>(length);
+
+        let mut written = String::new();
+        value.write_limited::<E, _>(length, &mut written).unwrap();
+        assert_eq!(written, limited, "write_limited()");
+
+        let displayed = value.display_trimmed::<E>(length).to_string();
+        assert_eq!(displayed, limited, "display_trimmed()");
+
+        let formatted = shear::fmt::trim_display::<E>(&value, length);
+        assert_eq!(formatted, limited, "fmt::trim_display()");
+
+        // write each character separately, so that clusters span several writes.
+        let chunked = shear::fmt::trim_display::<E>(&Chunked(&value), length);
+        assert_eq!(chunked, limited, "fmt::trim_display() with chunked writes");
+
+        let mut extended = String::new();
+        value.extend_trimmed_to_length::<E>(&mut extended, length);
+        assert_eq!(extended, limited, "extend_trimmed_to_length()");
+
+        let mut buf = [0; 32];
+        if let Ok(end) = value.trim_to_length_into::<E>(&mut buf[..length]) {
+            let into = std::str::from_utf8(&buf[..end]).unwrap();
+            assert_eq!(into, limited, "trim_to_length_into()");
+        }
+
+        #[cfg(feature = "heapless")]
+        if let Ok(heapless) = value.trim_to_length_heapless::<E, 32>(length) {
+            assert_eq!(heapless, limited.as_str(), "trim_to_length_heapless()");
+        }
+
+        #[cfg(feature = "arrayvec")]
+        if let Ok(array) = value.trim_to_length_array_string::<E, 32>(length) {
+            assert_eq!(array.as_str(), limited, "trim_to_length_array_string()");
+        }
+
+        let limits = Limits::new().max_len(length).ellipsis::<E>().apply(&value);
+        assert_eq!(limits, limited, "Limits::max_len()");
+
+        let marked = value.trim_to_length_with(&ellipsis::Horizontal, length);
+        assert_eq!(marked, limited, "trim_to_length_with()");
+
+        let inspected = value.trim_to_length_inspect::<E>(length, drop);
+        assert_eq!(inspected, limited, "trim_to_length_inspect()");
+
+        let dynamic = value.trim_to_length_dynamic(length, &|_| "…".to_owned());
+        assert_eq!(dynamic, limited, "trim_to_length_dynamic()");
+    }
+
+    /// a [`Display`][std::fmt::Display] value that writes one character at a time.
+    struct Chunked<'a>(&'a str);
+
+    impl std::fmt::Display for Chunked<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.0.chars().try_for_each(|c| f.write_char(c))
+        }
+    }
+
+    #[test]
+    fn combining_marks_are_not_separated_from_their_character() {
+        type E = ellipsis::Horizontal;
+
+        let value = "e\u{301}xyz";
+        assert_eq!(value.trim_to_length::<E>(4), "…");
+        length_methods_match_trim_to_length_(value.to_owned(), 4);
+    }
+}
@@ -0,0 +1,107 @@
+//! test cases for tables of limited cells in [`shear::table`].
+
+#![cfg(feature = "str")]
+
+use shear::{
+    str::{
+        ellipsis::{Ascii, Horizontal},
+        Align, Limited,
+    },
+    table::Table,
+};
+
+#[test]
+fn strings_are_fit_to_width() {
+    assert_eq!("ab".fit_to_width::<Ascii>(2, Align::Left), "ab");
+    assert_eq!("ab".fit_to_width::<Ascii>(3, Align::Center), "ab ");
+    assert_eq!("ab".fit_to_width::<Ascii>(4, Align::Center), " ab ");
+    assert_eq!("abcdef".fit_to_width::<Ascii>(5, Align::Right), "ab...");
+    assert_eq!("".fit_to_width::<Ascii>(2, Align::Right), "  ");
+}
+
+#[test]
+fn short_strings_are_not_replaced_by_an_ellipsis() {
+    assert_eq!("ab".fit_to_width::<Ascii>(2, Align::Left), "ab");
+    assert_eq!("a".fit_to_width::<Ascii>(2, Align::Left), "a ");
+}
+
+#[test]
+fn wide_characters_are_padded_after_trimming() {
+    assert_eq!("ａｂｃ".fit_to_width::<Horizontal>(4, Align::Left), "ａ… ");
+}
+
+#[test]
+fn rows_are_formatted() {
+    let table = Table::<Ascii>::new([4, 3]).separator("|");
+    assert_eq!(table.row(["a", "b"]), "a   |b  ");
+    assert_eq!(table.row(["abcdef", "b"]), "a...|b  ");
+    assert_eq!(table.width(), 8);
+}
+
+#[test]
+fn missing_and_extra_cells_are_handled() {
+    let table = Table::<Ascii>::new([2, 2]).align(1, Align::Right);
+    assert_eq!(table.row(["a"]), "a    ");
+    assert_eq!(table.row(["a", "b", "c"]), "a   b");
+}
+
+#[test]
+fn rows_can_be_iterated() {
+    let rows = vec![vec!["one".to_owned()], vec!["three".to_owned()]];
+    let rows = Table::<Horizontal>::new([4]).rows(rows).collect::<Vec<_>>();
+    assert_eq!(rows, ["one ", "thr…"]);
+}
+
+/// test that column widths can be sized from a table's contents.
+mod sizing {
+    use {proptest::proptest, shear::table::Sizing};
+
+    proptest! {
+        #[test]
+        fn distributed_widths_fit_the_budget(
+            natural in proptest::collection::vec(0..64_usize, 0..8),
+            total: u8,
+        )
+        {
+            distributed_widths_fit_the_budget_(natural, total.into())
+        }
+    }
+
+    fn distributed_widths_fit_the_budget_(natural: Vec<usize>, total: usize) {
+        let rows = [natural.iter().map(|&w| "x".repeat(w)).collect::<Vec<_>>()];
+        let sizing = Sizing::scan(rows);
+        assert_eq!(sizing.natural(), natural);
+
+        let widths = sizing.distribute(total);
+        assert_eq!(widths.len(), natural.len());
+        assert_eq!(
+            widths.iter().sum::<usize>(),
+            natural.iter().sum::<usize>().min(total)
+        );
+        for (width, natural) in widths.iter().zip(&natural) {
+            assert!(
+                width <= natural,
+                "columns should not grow past their natural width"
+            );
+        }
+    }
+
+    #[test]
+    fn natural_widths_are_the_widest_cells() {
+        let rows = [vec!["a", "bb"], vec!["ccc"], vec!["dd", "e", "ｆ"]];
+        assert_eq!(Sizing::scan(rows).natural(), [3, 2, 2]);
+    }
+
+    #[test]
+    fn widths_are_clamped() {
+        let sizing = Sizing::scan([["a", "a long cell"]]).min(0, 4).max(1, 6);
+        assert_eq!(sizing.distribute(80), [4, 6]);
+        assert_eq!(sizing.distribute(2), [4, 0]);
+    }
+
+    #[test]
+    fn space_is_shared_evenly() {
+        let sizing = Sizing::scan([["x".repeat(20), "x".repeat(20), "x".repeat(3)]]);
+        assert_eq!(sizing.distribute(24), [10, 11, 3]);
+    }
+}
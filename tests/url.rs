@@ -0,0 +1,59 @@
+//! test cases for url shortening in [`shear::str::url`].
+
+#![cfg(feature = "str")]
+
+use {
+    proptest::proptest,
+    shear::str::{
+        ellipsis::{Ascii, Horizontal},
+        url,
+    },
+};
+
+proptest! {
+    #[test]
+    fn shortened_urls_fit(
+        segments in proptest::collection::vec("[a-z0-9é-]{1,8}", 0..6),
+        query in "(\\?[a-z=&]{1,12})?",
+        budget in 0..64_usize,
+    ) {
+        let u = format!("https://example.com/{}{query}", segments.join("/"));
+        let shortened = url::trim_to_length::<Ascii>(&u, budget);
+        assert!(shortened.len() <= budget.max(3), "`{shortened}` should fit in {budget} bytes");
+        if u.len() <= budget {
+            assert_eq!(shortened, u);
+        }
+    }
+}
+
+#[test]
+fn scheme_and_host_are_kept() {
+    let u = "https://docs.rs/shear/latest/shear/str/trait.Limited.html#method.trim";
+    assert_eq!(
+        url::trim_to_width::<Horizontal>(u, 56),
+        "https://docs.rs/…/str/trait.Limited.html#method.trim"
+    );
+    assert_eq!(
+        url::trim_to_width::<Ascii>(u, 52),
+        "https://docs.rs/.../trait.Limited.html#method.trim"
+    );
+}
+
+#[test]
+fn long_queries_are_truncated() {
+    let u = "https://example.com/a/b/search?q=a+very+long+query";
+    assert_eq!(
+        url::trim_to_width::<Ascii>(u, 36),
+        "https://example.com/.../search?q=..."
+    );
+}
+
+#[test]
+fn urls_without_a_path_are_truncated() {
+    let u = "https://a-very-long-subdomain.example.com";
+    assert_eq!(url::trim_to_width::<Ascii>(u, 20), "https://a-very-lo...");
+    assert_eq!(
+        url::trim_to_width::<Ascii>("not a url, but long", 10),
+        "not a u..."
+    );
+}
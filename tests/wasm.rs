@@ -0,0 +1,17 @@
+//! test cases for the javascript bindings in [`shear::wasm`].
+
+#![cfg(feature = "wasm")]
+
+use shear::wasm::{trim_to_length, trim_to_width, WasmEllipsis};
+
+#[test]
+fn strings_can_be_limited_by_length() {
+    let limited = trim_to_length("a very long string value", 18, WasmEllipsis::Ascii);
+    assert_eq!(limited, "a very long str...");
+}
+
+#[test]
+fn strings_can_be_limited_by_width() {
+    let limited = trim_to_width("Ｈｅｌｌｏ, ｗｏｒｌｄ!", 5, WasmEllipsis::Horizontal);
+    assert_eq!(limited, "Ｈｅ…");
+}